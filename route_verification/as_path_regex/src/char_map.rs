@@ -2,21 +2,21 @@ use common_regex::set::*;
 
 use super::*;
 
-pub fn as_replace_all<R>(s: &str, replacer: R) -> Cow<str>
+pub fn as_replace_all<R>(s: &str, replacer: R) -> Cow<'_, str>
 where
     R: Replacer,
 {
     regex!(ASN).replace_all(s, replacer)
 }
 
-pub fn as_set_replace_all<R>(s: &str, replacer: R) -> Cow<str>
+pub fn as_set_replace_all<R>(s: &str, replacer: R) -> Cow<'_, str>
 where
     R: Replacer,
 {
     regex!(AS_SET).replace_all(s, replacer)
 }
 
-pub fn peer_as_replace_all<R>(s: &str, replacer: R) -> Cow<str>
+pub fn peer_as_replace_all<R>(s: &str, replacer: R) -> Cow<'_, str>
 where
     R: Replacer,
 {
@@ -95,11 +95,12 @@ impl Replacer for CharMap<String> {
 
 impl Replacer for CharMap<u32> {
     fn replace_append(&mut self, caps: &Captures<'_>, dst: &mut String) {
-        let capture: u32 = caps[0][2..]
-            .parse()
-            .expect(r"expecting `caps[0]` to be `AS\d+`");
-        let c = self.find_char_or_push(capture);
-        dst.push(c);
+        // Out-of-range AS numbers are left as is;
+        // [`Interpreter::run`] rejects them beforehand.
+        match parse_asn(&caps[0]) {
+            Some(num) => dst.push(self.find_char_or_push(num)),
+            None => dst.push_str(&caps[0]),
+        }
     }
 }
 
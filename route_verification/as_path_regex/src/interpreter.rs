@@ -22,6 +22,12 @@ impl Interpreter {
         }
         self.sets.next = self.next();
         let s = as_set_replace_all(s, self.sets.by_ref());
+        if let Some(asn) = regex!(set::ASN)
+            .find_iter(&s)
+            .find(|asn| parse_asn(asn.as_str()).is_none())
+        {
+            return Err(InterpretErr::InvalidAsn(asn.as_str().into()));
+        }
         self.ans.next = self.next();
         let s = as_replace_all(&s, self.ans.by_ref());
         let replacer = self.peer_as_char.to_string();
@@ -60,7 +66,7 @@ impl Interpreter {
     }
 
     /// ASN or AS set corresponding to `c`.
-    pub fn get_char(&self, c: char) -> Res<AsOrSet> {
+    pub fn get_char(&self, c: char) -> Res<AsOrSet<'_>> {
         if let Some(s) = self.sets.get(c) {
             return Ok(AsOrSet::AsSet(s));
         }
@@ -107,6 +113,8 @@ pub enum InterpretErr {
     InvalidRegex,
     #[error("encountered unknown character")]
     UnknownChar,
+    #[error("AS number `{0}` out of range")]
+    InvalidAsn(String),
 }
 
 pub const PEER_AS_CHAR: char = 'Å';
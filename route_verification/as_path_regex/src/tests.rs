@@ -14,7 +14,7 @@ fn replace_as() -> Result<()> {
     Ok(())
 }
 
-const AS_REGEXES: [(&str, &str, &[u32]); 3] = [
+const AS_REGEXES: [(&str, &str, &[u32]); 4] = [
     ("^AS20485 AS15774$", "^Α Β$", &[20485, 15774]),
     ("^AS611+AS6509.*$", "^Α+Β.*$", &[611, 6509]),
     (
@@ -22,6 +22,7 @@ const AS_REGEXES: [(&str, &str, &[u32]); 3] = [
         "^Α.*(Β|Γ)?$",
         &[24167, 1659, 9916],
     ),
+    ("^AS1.10 AS65546.*$", "^Α Α.*$", &[65546]),
 ];
 
 #[test]
@@ -56,3 +57,16 @@ fn interpret_w_tilde() {
     let actual = interpreter.run(AS_SET_REGEXES[2].0).unwrap_err();
     assert_eq!(actual, InterpretErr::HasTilde);
 }
+
+#[test]
+fn interpret_out_of_range_asdot() {
+    for (s, asn) in [
+        ("^AS1.70000$", "AS1.70000"),
+        ("^AS70000.1 AS3$", "AS70000.1"),
+        ("^AS4294967296$", "AS4294967296"),
+    ] {
+        let mut interpreter = Interpreter::new();
+        let actual = interpreter.run(s).unwrap_err();
+        assert_eq!(actual, InterpretErr::InvalidAsn(asn.into()));
+    }
+}
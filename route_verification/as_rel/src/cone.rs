@@ -0,0 +1,100 @@
+use std::sync::{Arc, OnceLock, RwLock};
+
+use super::*;
+
+/// Lazily computed customers and customer cones of an [`AsRelDb`].
+#[derive(Default)]
+pub(crate) struct ConeCache {
+    /// Direct customers of each AS.
+    customers: OnceLock<HashMap<u32, Vec<u32>>>,
+    cones: RwLock<HashMap<u32, Arc<HashSet<u32>>>>,
+}
+
+impl Clone for ConeCache {
+    fn clone(&self) -> Self {
+        Self {
+            customers: self.customers.clone(),
+            cones: RwLock::new(self.cones.read().expect("poisoned cone cache").clone()),
+        }
+    }
+}
+
+impl std::fmt::Debug for ConeCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let n_cones = self.cones.read().map_or(0, |cones| cones.len());
+        f.debug_struct("ConeCache")
+            .field("n_cones", &n_cones)
+            .finish()
+    }
+}
+
+impl AsRelDb {
+    /// Direct customers of `asn`.
+    pub fn customers(&self, asn: u32) -> &[u32] {
+        self.cone_cache
+            .customers
+            .get_or_init(|| direct_customers(&self.source2dest))
+            .get(&asn)
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Customer cone of `asn`: itself and all ASes reachable from it
+    /// following provider-to-customer links, as in CAIDA's AS Rank.
+    /// Cycles of P2C links are tolerated.
+    /// Cones are cached; the relationships cannot change afterwards except
+    /// through [`overlay`](#method.overlay), which clears the cache.
+    pub fn customer_cone(&self, asn: u32) -> Arc<HashSet<u32>> {
+        if let Some(cone) = self.cached_cone(asn) {
+            return cone;
+        }
+        let mut cone = HashSet::from([asn]);
+        let mut stack = vec![asn];
+        while let Some(provider) = stack.pop() {
+            for &customer in self.customers(provider) {
+                if cone.contains(&customer) {
+                    continue;
+                }
+                match self.cached_cone(customer) {
+                    Some(customer_cone) => cone.extend(customer_cone.iter()),
+                    None => {
+                        cone.insert(customer);
+                        stack.push(customer);
+                    }
+                }
+            }
+        }
+        let cone = Arc::new(cone);
+        self.cone_cache
+            .cones
+            .write()
+            .expect("poisoned cone cache")
+            .insert(asn, Arc::clone(&cone));
+        cone
+    }
+
+    fn cached_cone(&self, asn: u32) -> Option<Arc<HashSet<u32>>> {
+        let cones = self.cone_cache.cones.read().expect("poisoned cone cache");
+        cones.get(&asn).cloned()
+    }
+
+    /// Forget cached customers and customer cones.
+    pub(crate) fn clear_cone_cache(&mut self) {
+        self.cone_cache = ConeCache::default();
+    }
+}
+
+fn direct_customers(source2dest: &HashMap<(u32, u32), Relationship>) -> HashMap<u32, Vec<u32>> {
+    let mut customers: HashMap<u32, Vec<u32>> = HashMap::new();
+    for (&(as1, as2), relationship) in source2dest {
+        match relationship {
+            P2C => customers.entry(as1).or_default().push(as2),
+            P2P | S2S => (),
+            C2P => customers.entry(as2).or_default().push(as1),
+        }
+    }
+    for customers in customers.values_mut() {
+        customers.sort_unstable();
+        customers.dedup();
+    }
+    customers
+}
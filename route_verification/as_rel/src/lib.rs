@@ -11,6 +11,8 @@
 //! >
 //! > [here]: http://data.caida.org/datasets/2013-asrank-data-supplement/
 //!
+//! Sibling links are loaded separately from AS-to-organization mappings.
+//!
 //! See [`AsRelDb`] for usage.
 use std::{
     error::Error,
@@ -22,9 +24,11 @@ use std::{
 
 use anyhow::{bail, Context, Result};
 use bzip2::read::BzDecoder;
+use flate2::read::GzDecoder;
 use hashbrown::{HashMap, HashSet};
 use log::debug;
 
+mod cone;
 mod relationship;
 #[cfg(test)]
 mod tests;
@@ -32,21 +36,38 @@ mod tests;
 #[doc(inline)]
 pub use relationship::*;
 
+use cone::ConeCache;
+
 use Relationship::*;
 
 /// # Autonomous System relationship database
 /// **Query** [`Relationship`] between two ASes using [`get`](#method.get).
+/// Query the customer cone of an AS using
+/// [`customer_cone`](#method.customer_cone).
 ///
-/// **Construct** from `.bz2` file using [`load_bz`](#method.load_bz).
+/// **Construct** from `.bz2` file using [`load_bz`](#method.load_bz),
+/// from `.gz` file using [`load_gz`](#method.load_gz).
 /// Construct from plain text file using [`load`](#method.load).
+/// Construct sibling relationships from an AS-to-organization mapping file
+/// using [`load_as2org`](#method.load_as2org).
+///
+/// **Combine** datasets with precedence using [`overlay`](#method.overlay).
 ///
 /// See the **expected format** at [`from_lines`](#method.from_lines).
 #[derive(Clone, Debug, Default)]
 pub struct AsRelDb {
     /// Tier 1 ASes.
     pub clique: HashSet<u32>,
-    /// Relationships between AS pairs.
-    pub source2dest: HashMap<(u32, u32), Relationship>,
+    /// Relationships between AS pairs, private so that they cannot change
+    /// after customer cones are cached.
+    source2dest: HashMap<(u32, u32), Relationship>,
+    /// Source column of AS pairs from serial-2 lines, e.g., `bgp` or `mlp`.
+    pub sources: HashMap<(u32, u32), String>,
+    /// Organization ID of each AS from AS-to-organization mappings.
+    /// ASes of the same organization are siblings unless `source2dest`
+    /// records otherwise.
+    orgs: HashMap<u32, String>,
+    cone_cache: ConeCache,
 }
 
 impl AsRelDb {
@@ -82,6 +103,44 @@ impl AsRelDb {
         Self::from_maybe_lines(reader.lines())
     }
 
+    /// Load from `.gz` file `path`.
+    pub fn load_gz<P>(path: P) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        debug!("Loading AS relationship database `{path:?}`.");
+        Self::do_load_gz(path).with_context(|| format!("loading `{path:?}` for AsRelDB"))
+    }
+
+    fn do_load_gz(path: &Path) -> Result<Self> {
+        let file = File::open(path)?;
+        let reader = BufReader::new(GzDecoder::new(file));
+        Self::from_maybe_lines(reader.lines())
+    }
+
+    /// Load sibling relationships from AS-to-organization mapping file
+    /// `path`, decompressed if the file name ends in `.gz` or `.bz2`.
+    pub fn load_as2org<P>(path: P) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        debug!("Loading AS-to-organization mapping `{path:?}`.");
+        Self::do_load_as2org(path).with_context(|| format!("loading `{path:?}` for AsRelDB"))
+    }
+
+    fn do_load_as2org(path: &Path) -> Result<Self> {
+        let file = File::open(path)?;
+        let reader: Box<dyn BufRead> =
+            match path.extension().and_then(|extension| extension.to_str()) {
+                Some("gz") => Box::new(BufReader::new(GzDecoder::new(file))),
+                Some("bz2") => Box::new(BufReader::new(BzDecoder::new(file))),
+                _ => Box::new(BufReader::new(file)),
+            };
+        Self::from_maybe_as2org_lines(reader.lines())
+    }
+
     /// Construct from `lines` of a plain text file.
     /// # Errors
     /// One of the lines is not in the expected format.
@@ -91,17 +150,19 @@ impl AsRelDb {
     /// Each line is `as1|as2|relationship` where `relationship` is among
     /// `0`, `-1`, or `1`.
     /// Though, `1` is not used in practice.
+    ///
+    /// Lines in CAIDA's serial-2 format have a fourth `source` column,
+    /// which is kept in `sources`.
     pub fn from_lines<I, S>(lines: I) -> Result<Self>
     where
         I: IntoIterator<Item = S>,
         S: AsRef<str>,
     {
-        let mut clique = HashSet::new();
-        let mut source2dest = HashMap::new();
+        let mut db = Self::default();
         for line in lines {
-            add_line(line.as_ref(), &mut clique, &mut source2dest)?;
+            db.add_line(line.as_ref())?;
         }
-        Ok(Self::new(clique, source2dest))
+        Ok(db.loaded())
     }
 
     /// Same as [`from_lines`](#method.from_lines) but `maybe_lines` can contain
@@ -112,58 +173,178 @@ impl AsRelDb {
         S: AsRef<str>,
         E: Error + Sync + Send + 'static,
     {
-        let mut clique = HashSet::new();
-        let mut source2dest = HashMap::new();
+        let mut db = Self::default();
         for maybe_line in maybe_lines {
             let line = maybe_line?;
-            add_line(line.as_ref(), &mut clique, &mut source2dest)?;
+            db.add_line(line.as_ref())?;
+        }
+        Ok(db.loaded())
+    }
+
+    /// Construct [`S2S`] relationships between ASes of the same organization,
+    /// looked up by organization ID, from `lines` of CAIDA's AS-to-organization mapping file.
+    /// # Expected format
+    /// Lines starting with `# format:` switch between the organization
+    /// section and the AS section, whose lines are
+    /// `aut|changed|aut_name|org_id|opaque_id|source`.
+    /// Other lines prefixed with `#` and organization lines are ignored.
+    /// Without a format line, lines starting with an AS number are taken as
+    /// AS lines.
+    pub fn from_as2org_lines<I, S>(lines: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut as2org = As2Org::default();
+        for line in lines {
+            as2org.add_line(line.as_ref())?;
         }
-        Ok(Self::new(clique, source2dest))
+        Ok(as2org.into_db())
     }
 
-    fn new(clique: HashSet<u32>, source2dest: HashMap<(u32, u32), Relationship>) -> Self {
+    /// Same as [`from_as2org_lines`](#method.from_as2org_lines) but
+    /// `maybe_lines` can contain errors, in which case the error is returned.
+    pub fn from_maybe_as2org_lines<I, S, E>(maybe_lines: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = Result<S, E>>,
+        S: AsRef<str>,
+        E: Error + Sync + Send + 'static,
+    {
+        let mut as2org = As2Org::default();
+        for maybe_line in maybe_lines {
+            as2org.add_line(maybe_line?.as_ref())?;
+        }
+        Ok(as2org.into_db())
+    }
+
+    fn loaded(self) -> Self {
         debug!(
-            "Loaded AS relationship database with {} cliques & {} links.",
-            clique.len(),
-            source2dest.len()
+            "Loaded AS relationship database with {} cliques, {} links & {} ASes with organizations.",
+            self.clique.len(),
+            self.source2dest.len(),
+            self.orgs.len()
         );
-        Self {
-            clique,
-            source2dest,
+        self
+    }
+
+    fn add_line(&mut self, line: &str) -> Result<()> {
+        if line.starts_with('#') {
+            if self.clique.is_empty() && line[2..].starts_with("input clique: ") {
+                self.clique = line[16..]
+                    .split_whitespace()
+                    .map(|s| s.parse())
+                    .collect::<Result<_, _>>()
+                    .with_context(|| format!("adding {line} into AsRelDb"))?;
+            }
+        } else {
+            let (key, relationship) = try_parse_as_rel(line)?;
+            self.source2dest.insert(key, relationship);
+            if let Some(source) = line.split('|').nth(3) {
+                self.sources.insert(key, source.into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Combine with `top`, whose relationships, siblings, sources, and
+    /// clique, if non-empty, take precedence over those in `self`.
+    /// To overlay several datasets in increasing precedence, use
+    /// `dbs.into_iter().reduce(AsRelDb::overlay)`.
+    pub fn overlay(mut self, top: Self) -> Self {
+        if !top.orgs.is_empty() {
+            let siblings_in_top = |&(as1, as2): &(u32, u32)| matches!((top.orgs.get(&as1), top.orgs.get(&as2)), (Some(org1), Some(org2)) if org1 == org2);
+            self.source2dest.retain(|key, _| !siblings_in_top(key));
+            self.sources.retain(|key, _| !siblings_in_top(key));
+            self.orgs.extend(top.orgs);
+        }
+        for ((as1, as2), relationship) in top.source2dest {
+            for key in [(as1, as2), (as2, as1)] {
+                self.source2dest.remove(&key);
+                self.sources.remove(&key);
+            }
+            self.source2dest.insert((as1, as2), relationship);
+        }
+        self.sources.extend(top.sources);
+        if !top.clique.is_empty() {
+            self.clique = top.clique;
         }
+        self.clear_cone_cache();
+        self
     }
 
-    /// Get [`Relationship`] between `as1` and `as2`, if recorded.
+    /// Relationships between AS pairs, as recorded.
+    pub fn source2dest(&self) -> &HashMap<(u32, u32), Relationship> {
+        &self.source2dest
+    }
+
+    /// Get [`Relationship`] between `as1` and `as2`, if recorded,
+    /// or [`S2S`] if they belong to the same organization.
     pub fn get(&self, as1: u32, as2: u32) -> Option<Relationship> {
         match self.source2dest.get(&(as1, as2)) {
             Some(rel) => Some(*rel),
-            None => self.source2dest.get(&(as2, as1)).map(|rel| rel.reversed()),
+            None => match self.source2dest.get(&(as2, as1)) {
+                Some(rel) => Some(rel.reversed()),
+                None => self.is_sibling(as1, as2).then_some(S2S),
+            },
         }
     }
 
+    /// Organization ID of `aut_num`, if loaded from an AS-to-organization
+    /// mapping.
+    pub fn org(&self, aut_num: u32) -> Option<&str> {
+        self.orgs.get(&aut_num).map(String::as_str)
+    }
+
+    fn is_sibling(&self, as1: u32, as2: u32) -> bool {
+        as1 != as2
+            && matches!((self.org(as1), self.org(as2)), (Some(org1), Some(org2)) if org1 == org2)
+    }
+
     pub fn is_clique(&self, aut_num: &u32) -> bool {
         self.clique.contains(aut_num)
     }
 }
 
-fn add_line(
-    line: &str,
-    clique: &mut HashSet<u32>,
-    source2dest: &mut HashMap<(u32, u32), Relationship>,
-) -> Result<()> {
-    if line.starts_with('#') {
-        if clique.is_empty() && line[2..].starts_with("input clique: ") {
-            *clique = line[16..]
-                .split_whitespace()
-                .map(|s| s.parse())
-                .collect::<Result<_, _>>()
-                .with_context(|| format!("adding {line} into AsRelDb"))?;
+/// Organization of each AS in an AS-to-organization mapping file.
+#[derive(Default)]
+struct As2Org {
+    orgs: HashMap<u32, String>,
+    /// If in the AS section, `None` before any format line.
+    in_as_section: Option<bool>,
+}
+
+impl As2Org {
+    fn add_line(&mut self, line: &str) -> Result<()> {
+        if let Some(format) = line.strip_prefix("# format:") {
+            self.in_as_section = Some(format.starts_with("aut|"));
+            return Ok(());
         }
-    } else {
-        let (key, relationship) = try_parse_as_rel(line)?;
-        source2dest.insert(key, relationship);
+        if line.starts_with('#') || line.trim().is_empty() {
+            return Ok(());
+        }
+        let mut fields = line.split('|');
+        let first = fields.next().unwrap_or_default();
+        let asn = match (self.in_as_section, first.parse::<u32>()) {
+            (Some(false), _) | (None, Err(_)) => return Ok(()),
+            (Some(true), asn) => {
+                asn.with_context(|| format!("invalid AS-to-organization line `{line}`"))?
+            }
+            (None, Ok(asn)) => asn,
+        };
+        let org_id = fields
+            .nth(2)
+            .with_context(|| format!("missing org_id in AS-to-organization line `{line}`"))?;
+        self.orgs.insert(asn, org_id.into());
+        Ok(())
+    }
+
+    fn into_db(self) -> AsRelDb {
+        let db = AsRelDb {
+            orgs: self.orgs,
+            ..AsRelDb::default()
+        };
+        db.loaded()
     }
-    Ok(())
 }
 
 /// Try parsing `line` as a non-comment line in a AS relationship file.
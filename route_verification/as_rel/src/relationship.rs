@@ -9,6 +9,8 @@ pub enum Relationship {
     P2P,
     /// Customer to provider.
     C2P,
+    /// Sibling to sibling, for ASes of the same organization.
+    S2S,
 }
 
 impl FromStr for Relationship {
@@ -30,6 +32,7 @@ impl Relationship {
             P2C => C2P,
             P2P => P2P,
             C2P => P2C,
+            S2S => S2S,
         }
     }
 }
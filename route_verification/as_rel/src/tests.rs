@@ -43,3 +43,88 @@ fn parse_mini_file() -> Result<()> {
     assert!(!db.is_clique(&205));
     Ok(())
 }
+
+#[test]
+fn customer_cones() -> Result<()> {
+    // 4 and 5 are in a P2C cycle.
+    let db = AsRelDb::from_lines([
+        "1|2|-1", "1|3|-1", "3|4|-1", "4|5|-1", "5|4|-1", "6|3|1", "2|3|0",
+    ])?;
+    assert_eq!(db.customers(1), [2, 3]);
+    assert_eq!(db.customers(6), []);
+    assert_eq!(*db.customer_cone(4), HashSet::from([4, 5]));
+    assert_eq!(*db.customer_cone(1), HashSet::from([1, 2, 3, 4, 5, 6]));
+    assert_eq!(*db.customer_cone(6), HashSet::from([6]));
+    assert_eq!(*db.clone().customer_cone(3), HashSet::from([3, 4, 5, 6]));
+    Ok(())
+}
+
+#[test]
+fn parse_serial_2() -> Result<()> {
+    let db = AsRelDb::from_lines(["# source:topology|BGP", "1|2|-1|bgp", "2|3|0|mlp", "3|4|0"])?;
+    assert_eq!(db.get(2, 1), Some(C2P));
+    assert_eq!(db.sources.get(&(1, 2)).map(String::as_str), Some("bgp"));
+    assert_eq!(db.sources.get(&(2, 3)).map(String::as_str), Some("mlp"));
+    assert_eq!(db.sources.get(&(3, 4)), None);
+    Ok(())
+}
+
+const AS2ORG_LINES: &str = "# format:org_id|changed|org_name|country|source
+LVLT-ARIN|20120130|Level 3 Communications, Inc.|US|ARIN
+1|20120224|LVLT-1|LVLT-ARIN|e5e3b9c13678dfc483fb1f819d70883c_ARIN|ARIN
+# format:aut|changed|aut_name|org_id|opaque_id|source
+1|20120224|LVLT-1|LVLT-ARIN|e5e3b9c13678dfc483fb1f819d70883c_ARIN|ARIN
+3356|20120224|LEVEL3|LVLT-ARIN|e5e3b9c13678dfc483fb1f819d70883c_ARIN|ARIN
+3549|20120224|LVLT-3549|LVLT-ARIN|e5e3b9c13678dfc483fb1f819d70883c_ARIN|ARIN
+13335|20100927|CLOUDFLARENET|CLOUD14-ARIN|d6b3a1e1d4a0c6ad7c5a1b0c1b5a6e2c_ARIN|ARIN
+";
+
+#[test]
+fn parse_as2org_siblings() -> Result<()> {
+    let db = AsRelDb::from_as2org_lines(AS2ORG_LINES.lines())?;
+    assert!(db.source2dest().is_empty());
+    assert_eq!(db.org(3356), Some("LVLT-ARIN"));
+    assert_eq!(db.get(3356, 1), Some(S2S));
+    assert_eq!(db.get(3549, 3356), Some(S2S));
+    assert_eq!(db.get(13335, 1), None);
+
+    // Without format lines, AS lines are recognized by the AS number.
+    let db = AsRelDb::from_as2org_lines(["1|x|a|ORG|o|ARIN", "2|x|b|ORG|o|ARIN"])?;
+    assert_eq!(db.get(1, 2), Some(S2S));
+    assert!(AsRelDb::from_as2org_lines(["# format:aut|changed", "AS1|x|a|ORG"]).is_err());
+    Ok(())
+}
+
+#[test]
+fn overlay_with_precedence() -> Result<()> {
+    let caida = AsRelDb::from_lines(["# input clique: 1 2", "1|2|0", "3356|1|-1|bgp", "4|5|-1"])?;
+    let siblings = AsRelDb::from_as2org_lines(AS2ORG_LINES.lines())?;
+    let manual = AsRelDb::from_lines(["5|4|0", "3549|3356|-1"])?;
+    let db = [caida, siblings, manual]
+        .into_iter()
+        .reduce(AsRelDb::overlay)
+        .context("no database")?;
+    assert_eq!(db.get(1, 3356), Some(S2S));
+    assert_eq!(db.sources.get(&(3356, 1)), None);
+    assert_eq!(db.get(1, 2), Some(P2P));
+    assert_eq!(db.get(4, 5), Some(P2P));
+    assert_eq!(db.get(3549, 3356), Some(P2C));
+    assert_eq!(db.get(3549, 13335), None);
+    assert!(db.is_clique(&2));
+    assert_eq!(*db.customer_cone(4), HashSet::from([4]));
+    Ok(())
+}
+
+#[test]
+fn load_gz() -> Result<()> {
+    use flate2::{write::GzEncoder, Compression};
+
+    let path = std::env::temp_dir().join(format!("as_rel_test_{}.txt.gz", std::process::id()));
+    let mut encoder = GzEncoder::new(File::create(&path)?, Compression::default());
+    encoder.write_all(FILE_LINES.as_bytes())?;
+    encoder.finish()?;
+    let db = AsRelDb::load_gz(&path);
+    std::fs::remove_file(&path)?;
+    assert_eq!(db?.get(1239, 4657), Some(P2C));
+    Ok(())
+}
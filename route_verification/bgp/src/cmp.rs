@@ -1,8 +1,12 @@
-use std::net::{Ipv4Addr, Ipv6Addr};
+use std::{
+    collections::HashMap,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+};
 
 pub use as_path_regex;
 use as_path_regex::interpreter::{InterpretErr::*, Interpreter};
 use ipnet::*;
+use rpki::{AspaDb, AspaState, RoaDb, RovState};
 
 use super::*;
 
@@ -12,15 +16,81 @@ use {
 
 pub mod as_regex;
 mod compliance;
+mod explain;
 mod filter;
 mod hill;
+mod leak;
 mod peering;
+mod special_case;
+mod what_if;
 
 pub(crate) use filter::*;
-pub use {compliance::*, peering::*};
+pub use {compliance::*, explain::*, leak::*, peering::*, special_case::*, what_if::*};
 
 pub const RECURSION_LIMIT: isize = 0x100;
 
+/// Sets being resolved while checking one `<filter>` or `<peering>`,
+/// and the reports of those already resolved.
+#[derive(Clone, Debug, Default)]
+pub struct SetVisits<'a> {
+    /// Names of the sets being resolved, outermost first.
+    chain: Vec<&'a str>,
+    /// Reports of fully resolved sets, by name, range operators applied, and
+    /// remaining recursion depth, so sets shared by several branches are
+    /// only resolved once.
+    resolved: HashMap<(&'a str, Vec<RangeOperator>, isize), AnyReport>,
+    /// Position in `chain` of the outermost set a cycle found so far
+    /// leads back to.
+    cycle_start: Option<usize>,
+}
+
+impl<'a> SetVisits<'a> {
+    /// Resolve set `name` with range operators `ops` using `resolve` while it
+    /// is on top of the chain, unless it was already resolved.
+    /// Like `flatten_as_set` for as-sets, sets already being resolved are not
+    /// entered again; `Err` then contains the cycle, from the first occurrence
+    /// of `name` back to `name`.
+    pub fn visit<F>(
+        &mut self,
+        name: &'a str,
+        ops: &[RangeOperator],
+        depth: isize,
+        resolve: F,
+    ) -> Result<AnyReport, Vec<String>>
+    where
+        F: FnOnce(&mut Self) -> AnyReport,
+    {
+        if let Some(start) = self
+            .chain
+            .iter()
+            .position(|set| set.eq_ignore_ascii_case(name))
+        {
+            self.cycle_start = Some(self.cycle_start.map_or(start, |s| s.min(start)));
+            let mut cycle: Vec<String> =
+                self.chain[start..].iter().map(|&set| set.into()).collect();
+            cycle.push(name.into());
+            return Err(cycle);
+        }
+        let key = (name, ops.to_vec(), depth);
+        if let Some(report) = self.resolved.get(&key) {
+            return Ok(report.clone());
+        }
+        let position = self.chain.len();
+        self.chain.push(name);
+        let report = resolve(self);
+        self.chain.pop();
+        match self.cycle_start {
+            // The report depends on the sets enclosing this one.
+            Some(start) if start < position => {}
+            _ => {
+                self.cycle_start = None;
+                self.resolved.insert(key, report.clone());
+            }
+        }
+        Ok(report)
+    }
+}
+
 /// All information needed for a route to be compared to [`QueryIr`].
 /// The main usage is to generate [`Report`]s with [`check`](#method.check).
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -29,15 +99,32 @@ pub struct Compare {
     pub prefix: IpNet,
     /// AS path for the propagation.
     pub as_path: Vec<AsPathEntry>,
-    /// Recursion limit when checking against [`QueryIr`].
+    /// Recursion limit for nested `<filter>`s and `<as-expression>`s when
+    /// checking against [`QueryIr`]; sets use cycle detection instead.
     /// Default to [`RECURSION_LIMIT`]
     pub recursion_limit: isize,
     /// [`Verbosity`] level when generating report.
     pub verbosity: Verbosity,
+    /// The BGP neighbor of the collector that provided this route, used to
+    /// check router expressions for the first hop on `as_path`.
+    pub collector_peer: Option<CollectorPeer>,
+    /// Route origin validation state of this route, reported as
+    /// [`Rpki`] by [`check`](#method.check) if set.
+    pub rov: Option<RovState>,
+    /// ASPA verification state of `as_path`, reported as [`Aspa`] by
+    /// [`check`](#method.check) if set.
+    pub aspa: Option<AspaState>,
+    /// Rules turning bad reports into "meh" in
+    /// [`check_with_relationship`](#method.check_with_relationship).
+    /// Default to [`SpecialCaseRules::builtin`].
+    pub special_case_rules: SpecialCaseRules,
 }
 
 impl Compare {
+    /// Confederation segments in `as_path` are stripped, since they are hops
+    /// inside one AS; the remaining entries are deduplicated.
     pub fn new(prefix: IpNet, mut as_path: Vec<AsPathEntry>) -> Self {
+        as_path.retain(|entry| !entry.is_confed());
         as_path.dedup();
         as_path.shrink_to_fit();
         Self {
@@ -45,6 +132,10 @@ impl Compare {
             as_path,
             recursion_limit: RECURSION_LIMIT,
             verbosity: Verbosity::default(),
+            collector_peer: None,
+            rov: None,
+            aspa: None,
+            special_case_rules: SpecialCaseRules::default(),
         }
     }
 
@@ -53,21 +144,82 @@ impl Compare {
         Self { verbosity, ..self }
     }
 
+    /// Add site-specific `rule` after those in `self.special_case_rules`.
+    pub fn special_case_rule<R: SpecialCaseRule + 'static>(mut self, rule: R) -> Self {
+        self.special_case_rules.push(rule);
+        self
+    }
+
+    /// Set `self.rov` by validating the origin against the ROAs in `db`.
+    pub fn validate_origin(self, db: &RoaDb) -> Self {
+        let rov = Some(db.validate(&self.prefix, self.origin()));
+        Self { rov, ..self }
+    }
+
+    /// Set `self.aspa` by verifying `self.as_path` against the ASPAs in `db`,
+    /// as received from a provider if `from_provider`, otherwise as received
+    /// from a customer or peer.
+    /// Paths with AS sets are invalid.
+    pub fn verify_as_path(self, db: &AspaDb, from_provider: bool) -> Self {
+        let path: Option<Vec<u32>> = self
+            .as_path
+            .iter()
+            .filter(|entry| !entry.is_confed())
+            .map(|entry| match entry {
+                Seq(n) => Some(*n),
+                _ => None,
+            })
+            .collect();
+        let aspa = Some(match (path, from_provider) {
+            (None, _) => AspaState::Invalid,
+            (Some(path), true) => db.verify_downstream(&path),
+            (Some(path), false) => db.verify_upstream(&path),
+        });
+        Self { aspa, ..self }
+    }
+
+    /// The AS originating the route, or `None` if `self.as_path` ends in an
+    /// AS set.
+    pub fn origin(&self) -> Option<u32> {
+        match self.as_path.last() {
+            Some(Seq(origin)) => Some(*origin),
+            _ => None,
+        }
+    }
+
     /// Create [`Compare`] from a line of table dump generated by `bgpdump`
     /// on a MRT file.
     pub fn with_line_dump(line: &str) -> Result<Self> {
-        let (prefix, as_path, _, _) = parse_table_dump(line)?;
-        Ok(Self::new(prefix, as_path))
+        let (prefix, as_path, collector_peer, _) = parse_table_dump(line)?;
+        Ok(Self {
+            collector_peer: Some(collector_peer),
+            ..Self::new(prefix, as_path)
+        })
     }
 
     /// Check `self` against RPSL policy `query` and generate reports.
     /// Depending on which [`Verbosity`] `self.verbosity` is set to,
     /// the reports have different levels of details.
     /// - If `verbosity.stop_at_first`, stops at the first report.
-    /// - Skip generating reports if the AS Path has only one entry.
+    /// - Skip generating reports if the AS Path has only one entry,
+    ///   except the [`Rpki`] and [`Aspa`] reports if `self.rov` and
+    ///   `self.aspa` are set, and the [`RouteObject`] report if
+    ///   `verbosity.check_route_object`.
     pub fn check(&self, query: &QueryIr) -> Vec<Report> {
+        let route_object = match (self.verbosity.check_route_object, self.origin()) {
+            (true, Some(origin)) => {
+                let state = query.route_object_state(&self.prefix, origin);
+                Some(Report::route_object(origin, state))
+            }
+            _ => None,
+        };
+        let route_reports = [
+            route_object,
+            self.rov.map(|rov| Report::rpki(self.origin(), rov)),
+            self.aspa.map(Report::aspa),
+        ];
         if self.as_path.len() <= 1 {
-            return vec![];
+            return route_reports.into_iter().flatten().collect();
         }
 
         let mut reports = Vec::with_capacity(self.as_path.len() << 1);
@@ -89,11 +241,13 @@ impl Compare {
                 }));
             }
         }
+        reports.extend(route_reports.into_iter().flatten());
         reports.shrink_to_fit();
         reports
     }
 
     /// `prev_path` is previous path for `to`.
+    /// See [`pre_check`] for the hops whose policies are not checked.
     pub fn check_pair(
         &self,
         query: &QueryIr,
@@ -101,27 +255,36 @@ impl Compare {
         to: u32,
         prev_path: &[AsPathEntry],
     ) -> Vec<Report> {
-        let from_report = match query.aut_nums.get(&from) {
-            Some(from_an) => self.check_export(query, from_an, from, to, prev_path),
-            None => self.verbosity.show_unrec.then(|| {
-                let items = aut_num_unrecorded_items(from);
-                UnrecExport { from, to, items }
-            }),
-        };
+        let from_report = self.check_policy(query, from, to, prev_path, true);
         let from_report = match (from_report, self.verbosity.stop_at_first) {
             (Some(r), true) => return vec![r],
             (from_report, _) => from_report,
         };
-        let to_report = match query.aut_nums.get(&to) {
-            Some(to_an) => self.check_import(query, to_an, from, to, prev_path),
-            None => self.verbosity.show_unrec.then(|| {
-                let items = aut_num_unrecorded_items(to);
-                UnrecImport { from, to, items }
-            }),
-        };
+        let to_report = self.check_policy(query, from, to, prev_path, false);
         [from_report, to_report].into_iter().flatten().collect()
     }
 
+    /// Check the exports of `from` to `to` if `export`, otherwise the
+    /// imports of `to` from `from`, unless [`pre_check`] decides otherwise.
+    /// `prev_path` is as in [`check_pair`](#method.check_pair).
+    pub fn check_policy(
+        &self,
+        query: &QueryIr,
+        from: u32,
+        to: u32,
+        prev_path: &[AsPathEntry],
+        export: bool,
+    ) -> Option<Report> {
+        let versions = match pre_check(query, from, to, export) {
+            Ok(versions) => versions,
+            Err(report) => return self.policy_report(from, to, export, report),
+        };
+        self.check_versions(query, versions, from, to, prev_path, export)
+    }
+
+    /// Check the exports of `from_an`, the aut-num of `from`, to `to`,
+    /// without [`pre_check`].
+    /// `prev_path` is as in [`check_pair`](#method.check_pair).
     pub fn check_export(
         &self,
         query: &QueryIr,
@@ -131,44 +294,15 @@ impl Compare {
         prev_path: &[AsPathEntry],
     ) -> Option<Report> {
         if from_an.exports.is_empty() {
-            return self.verbosity.show_unrec.then(|| {
-                let items = vec![UnrecExportEmpty];
-                UnrecExport { from, to, items }
-            });
-        }
-        let mut report = match (Compliance {
-            cmp: self,
-            query,
-            accept_num: to,
-            self_num: from,
-            export: true,
-            prev_path: &prev_path[prev_path.len().min(1)..],
-        })
-        .check(&from_an.exports)
-        {
-            None => return self.verbosity.show_success.then_some(OkExport { from, to }),
-            Some(report) => report,
-        };
-        report.shrink_to_fit();
-        match report {
-            SkipAnyReport(items) => {
-                self.verbosity
-                    .show_skips
-                    .then_some(SkipExport { from, to, items })
-            }
-            UnrecAnyReport(items) => {
-                self.verbosity
-                    .show_unrec
-                    .then_some(UnrecExport { from, to, items })
-            }
-            MehAnyReport(items) => self
-                .verbosity
-                .show_meh
-                .then_some(MehExport { from, to, items }),
-            BadAnyReport(items) => Some(BadExport { from, to, items }),
+            let report = UnrecAnyReport(vec![UnrecExportEmpty]);
+            return self.policy_report(from, to, true, report);
         }
+        self.check_versions(query, &from_an.exports, from, to, prev_path, true)
     }
 
+    /// Check the imports of `to_an`, the aut-num of `to`, from `from`,
+    /// without [`pre_check`].
+    /// `prev_path` is as in [`check_pair`](#method.check_pair).
     pub fn check_import(
         &self,
         query: &QueryIr,
@@ -178,42 +312,100 @@ impl Compare {
         prev_path: &[AsPathEntry],
     ) -> Option<Report> {
         if to_an.imports.is_empty() {
-            return self.verbosity.show_unrec.then(|| UnrecImport {
-                from,
-                to,
-                items: vec![UnrecImportEmpty],
-            });
+            let report = UnrecAnyReport(vec![UnrecImportEmpty]);
+            return self.policy_report(from, to, false, report);
+        }
+        self.check_versions(query, &to_an.imports, from, to, prev_path, false)
+    }
+
+    fn check_versions(
+        &self,
+        query: &QueryIr,
+        versions: &Versions,
+        from: u32,
+        to: u32,
+        prev_path: &[AsPathEntry],
+        export: bool,
+    ) -> Option<Report> {
+        let compliance = self.compliance(query, from, to, prev_path, export);
+        match compliance.check(versions) {
+            None => {
+                let rule = match self.verbosity.record_rule {
+                    true => compliance.matching_rule(versions),
+                    false => None,
+                };
+                self.verbosity.show_success.then_some(match export {
+                    true => OkExport { from, to, rule },
+                    false => OkImport { from, to, rule },
+                })
+            }
+            Some(report) => self.policy_report(from, to, export, report),
         }
-        let mut report = match (Compliance {
+    }
+
+    /// [`Compliance`] for the exports of `from` to `to` if `export`,
+    /// otherwise the imports of `to` from `from`.
+    pub fn compliance<'a>(
+        &'a self,
+        query: &'a QueryIr,
+        from: u32,
+        to: u32,
+        prev_path: &'a [AsPathEntry],
+        export: bool,
+    ) -> Compliance<'a> {
+        let (self_num, accept_num, prev_path) = match export {
+            true => (from, to, &prev_path[prev_path.len().min(1)..]),
+            false => (to, from, prev_path),
+        };
+        Compliance {
             cmp: self,
             query,
-            accept_num: from,
-            self_num: to,
-            export: false,
+            accept_num,
+            self_num,
+            export,
             prev_path,
-        })
-        .check(&to_an.imports)
-        {
-            None => return self.verbosity.show_success.then_some(OkImport { from, to }),
-            Some(report) => report,
-        };
+            collector_peer_ip: self.collector_peer_ip(from, to),
+            trace: None,
+        }
+    }
+
+    /// Turn the report on a policy into a [`Report`] if `self.verbosity`
+    /// shows it.
+    fn policy_report(
+        &self,
+        from: u32,
+        to: u32,
+        export: bool,
+        mut report: AnyReportCase,
+    ) -> Option<Report> {
         report.shrink_to_fit();
-        match report {
-            SkipAnyReport(items) => {
-                self.verbosity
-                    .show_skips
-                    .then_some(SkipImport { from, to, items })
-            }
-            UnrecAnyReport(items) => {
-                self.verbosity
-                    .show_unrec
-                    .then_some(UnrecImport { from, to, items })
+        let show = match report {
+            SkipAnyReport(_) => self.verbosity.show_skips,
+            UnrecAnyReport(_) => self.verbosity.show_unrec,
+            MehAnyReport(_) => self.verbosity.show_meh,
+            BadAnyReport(_) => true,
+        };
+        show.then_some(match (report, export) {
+            (SkipAnyReport(items), true) => SkipExport { from, to, items },
+            (SkipAnyReport(items), false) => SkipImport { from, to, items },
+            (UnrecAnyReport(items), true) => UnrecExport { from, to, items },
+            (UnrecAnyReport(items), false) => UnrecImport { from, to, items },
+            (MehAnyReport(items), true) => MehExport { from, to, items },
+            (MehAnyReport(items), false) => MehImport { from, to, items },
+            (BadAnyReport(items), true) => BadExport { from, to, items },
+            (BadAnyReport(items), false) => BadImport { from, to, items },
+        })
+    }
+
+    /// IP address of the collector peer if `to` is its AS, the first on
+    /// the AS path, and `from` is next to it.
+    pub fn collector_peer_ip(&self, from: u32, to: u32) -> Option<IpAddr> {
+        let peer = self.collector_peer?;
+        match self.as_path.as_slice() {
+            [Seq(first), Seq(second), ..] if *first == to && *second == from && peer.asn == to => {
+                Some(peer.ip)
             }
-            MehAnyReport(items) => self
-                .verbosity
-                .show_meh
-                .then_some(MehImport { from, to, items }),
-            BadAnyReport(items) => Some(BadImport { from, to, items }),
+            _ => None,
         }
     }
 
@@ -245,6 +437,42 @@ pub fn is_multicast(prefix: &IpNet) -> bool {
     }
 }
 
+/// Checks on the hop `from` -> `to` before the exports of `from` if
+/// `export`, otherwise the imports of `to`, are evaluated.
+/// - Hops with AS_TRANS are skipped because the actual AS is unknown.
+/// - The policies of ASes that are not public are not checked.
+/// - Unrecorded aut-nums and empty policies are unrecorded.
+///
+/// `Ok` contains the policies to evaluate, `Err` the report on them instead.
+pub fn pre_check(
+    query: &QueryIr,
+    from: u32,
+    to: u32,
+    export: bool,
+) -> Result<&Versions, AnyReportCase> {
+    if from == AS_TRANS || to == AS_TRANS {
+        return Err(SkipAnyReport(vec![SkipAsTrans]));
+    }
+    let self_num = match export {
+        true => from,
+        false => to,
+    };
+    let class = classify_asn(self_num);
+    if !class.is_public() {
+        return Err(MehAnyReport(vec![SpecNonPublicAsn(self_num, class)]));
+    }
+    let aut_num = query
+        .aut_nums
+        .get(&self_num)
+        .ok_or_else(|| UnrecAnyReport(aut_num_unrecorded_items(self_num)))?;
+    match export {
+        true if aut_num.exports.is_empty() => Err(UnrecAnyReport(vec![UnrecExportEmpty])),
+        false if aut_num.imports.is_empty() => Err(UnrecAnyReport(vec![UnrecImportEmpty])),
+        true => Ok(&aut_num.exports),
+        false => Ok(&aut_num.imports),
+    }
+}
+
 fn aut_num_unrecorded_items(aut_num: u32) -> Vec<ReportItem> {
     vec![UnrecordedAutNum(aut_num)]
 }
@@ -7,30 +7,88 @@ pub struct Compliance<'a> {
     pub self_num: u32,
     pub export: bool,
     pub prev_path: &'a [AsPathEntry],
+    /// IP address of the collector peer if it is the router on `self_num`'s
+    /// (importing) or `accept_num`'s (exporting) side of this hop.
+    pub collector_peer_ip: Option<IpAddr>,
+    /// Where to record the checks as an [`Explanation`], if anywhere.
+    pub trace: Option<&'a Trace>,
 }
 
 impl<'a> Compliance<'a> {
     pub fn check(&self, policy: &Versions) -> AnyReport {
-        Some(
-            match self.cmp.prefix {
-                IpNet::V4(_) => self.check_casts(&policy.ipv4),
-                IpNet::V6(_) => self.check_casts(&policy.ipv6),
-            }? | self.check_casts(&policy.any)?,
-        )
+        let specific_version = match self.cmp.prefix {
+            IpNet::V4(_) => (PolicyVersion::Ipv4, &policy.ipv4),
+            IpNet::V6(_) => (PolicyVersion::Ipv6, &policy.ipv6),
+        };
+        let mut report = AnyReportCase::const_default();
+        for (version, casts) in [specific_version, (PolicyVersion::Any, &policy.any)] {
+            if !casts.is_empty() {
+                let node = || ExplainNode::Version(version);
+                report |= traced(self.trace, node, || self.check_casts(casts))?;
+            }
+        }
+        Some(report)
     }
 
     pub fn check_casts(&self, casts: &Casts) -> AnyReport {
-        let mut report = AnyReportCase::const_default();
         let specific_cast = match is_multicast(&self.cmp.prefix) {
-            true => &casts.multicast,
-            false => &casts.unicast,
+            true => (PolicyCast::Multicast, &casts.multicast),
+            false => (PolicyCast::Unicast, &casts.unicast),
         };
-        for entry in [specific_cast, &casts.any].into_iter().flatten() {
-            report |= self.check_entry(entry).to_any()?;
+        let mut report = AnyReportCase::const_default();
+        for (cast, entries) in [specific_cast, (PolicyCast::Any, &casts.any)] {
+            if !entries.is_empty() {
+                let node = || ExplainNode::Cast(cast);
+                report |= traced(self.trace, node, || self.check_entries(entries))?;
+            }
+        }
+        Some(report)
+    }
+
+    fn check_entries(&self, entries: &[Entry]) -> AnyReport {
+        let mut report = AnyReportCase::const_default();
+        for (index, entry) in entries.iter().enumerate() {
+            let node = || ExplainNode::Entry(index);
+            report |= traced(self.trace, node, || self.check_entry(entry).to_any())?;
         }
         Some(report)
     }
 
+    /// Index of the first entry in `policy` that accepts the route, in the
+    /// order [`check`](#method.check) tries them.
+    pub fn matching_rule(&self, policy: &Versions) -> Option<RuleIndex> {
+        let specific_version = match self.cmp.prefix {
+            IpNet::V4(_) => (PolicyVersion::Ipv4, &policy.ipv4),
+            IpNet::V6(_) => (PolicyVersion::Ipv6, &policy.ipv6),
+        };
+        let specific_cast = match is_multicast(&self.cmp.prefix) {
+            true => PolicyCast::Multicast,
+            false => PolicyCast::Unicast,
+        };
+        [specific_version, (PolicyVersion::Any, &policy.any)]
+            .into_iter()
+            .flat_map(|(version, casts)| {
+                [specific_cast, PolicyCast::Any]
+                    .into_iter()
+                    .map(move |cast| (version, cast, casts))
+            })
+            .find_map(|(version, cast, casts)| {
+                let entries = match cast {
+                    PolicyCast::Any => &casts.any,
+                    PolicyCast::Unicast => &casts.unicast,
+                    PolicyCast::Multicast => &casts.multicast,
+                };
+                let entry = entries
+                    .iter()
+                    .position(|entry| self.check_entry(entry).to_any().is_none())?;
+                Some(RuleIndex {
+                    version,
+                    cast,
+                    entry,
+                })
+            })
+    }
+
     pub fn check_entry(&self, entry: &Entry) -> AllReport {
         let peering_report = CheckPeering {
             c: self,
@@ -52,8 +110,13 @@ impl<'a> Compliance<'a> {
             export: self.export,
             prev_path: self.prev_path,
             mp_peerings: &entry.mp_peerings,
+            trace: self.trace,
         }
-        .check_filter(&entry.mp_filter, self.cmp.recursion_limit)
+        .check_filter(
+            &entry.mp_filter,
+            self.cmp.recursion_limit,
+            &mut SetVisits::default(),
+        )
         .to_all()
         .map_err(|mut report| {
             if self.cmp.verbosity.per_filter_err {
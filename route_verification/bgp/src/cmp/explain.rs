@@ -0,0 +1,243 @@
+use std::{
+    cell::RefCell,
+    fmt::{Display, Write},
+};
+
+use super::*;
+
+/// Verdict of a node in an [`Explanation`].
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum Verdict {
+    Ok,
+    Skip,
+    Unrec,
+    Meh,
+    Bad,
+}
+
+impl Verdict {
+    /// Verdict and items of `report`.
+    pub fn of(report: AnyReport) -> (Self, ReportItems) {
+        match report {
+            None => (Self::Ok, vec![]),
+            Some(SkipAnyReport(items)) => (Self::Skip, items),
+            Some(UnrecAnyReport(items)) => (Self::Unrec, items),
+            Some(MehAnyReport(items)) => (Self::Meh, items),
+            Some(BadAnyReport(items)) => (Self::Bad, items),
+        }
+    }
+}
+
+/// The part of a policy an [`Explanation`] is about.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum ExplainNode {
+    /// The exports of `from` to `to`, or the imports of `to` from `from`.
+    Policy { from: u32, to: u32, export: bool },
+    /// `any`, `ipv4`, or `ipv6` policies.
+    Version(PolicyVersion),
+    /// `any`, `unicast`, or `multicast` policies.
+    Cast(PolicyCast),
+    /// The entry at `index` in the cast.
+    Entry(usize),
+    /// The `<mp-peering>` at `index` in the entry, as RPSL.
+    Peering { index: usize, peering: String },
+    /// A remote `<as-expression>` or its sub-expression, as RPSL.
+    RemoteAs(String),
+    /// A `<mp-filter>` or its sub-expression, as RPSL.
+    Filter(String),
+}
+
+impl Display for ExplainNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Policy {
+                from,
+                to,
+                export: true,
+            } => write!(f, "export AS{from} -> AS{to}"),
+            Self::Policy {
+                from,
+                to,
+                export: false,
+            } => write!(f, "import AS{to} <- AS{from}"),
+            Self::Version(version) => {
+                let version = match version {
+                    PolicyVersion::Any => "any",
+                    PolicyVersion::Ipv4 => "ipv4",
+                    PolicyVersion::Ipv6 => "ipv6",
+                };
+                write!(f, "version {version}")
+            }
+            Self::Cast(cast) => {
+                let cast = match cast {
+                    PolicyCast::Any => "any",
+                    PolicyCast::Unicast => "unicast",
+                    PolicyCast::Multicast => "multicast",
+                };
+                write!(f, "cast {cast}")
+            }
+            Self::Entry(index) => write!(f, "entry {index}"),
+            Self::Peering { index, peering } => write!(f, "peering {index} {peering}"),
+            Self::RemoteAs(remote_as) => write!(f, "as-expression {remote_as}"),
+            Self::Filter(filter) => write!(f, "filter {filter}"),
+        }
+    }
+}
+
+/// Tree explaining how a policy was evaluated for a hop,
+/// generated by [`Compare::explain`].
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct Explanation {
+    pub node: ExplainNode,
+    pub verdict: Verdict,
+    pub items: ReportItems,
+    pub children: Vec<Explanation>,
+}
+
+impl Explanation {
+    fn new(node: ExplainNode, report: AnyReport, children: Vec<Explanation>) -> Self {
+        let (verdict, items) = Verdict::of(report);
+        Self {
+            node,
+            verdict,
+            items,
+            children,
+        }
+    }
+
+    /// Render as indented text, one node per line.
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        self.write_text(&mut text, 0);
+        text
+    }
+
+    fn write_text(&self, text: &mut String, depth: usize) {
+        _ = write!(
+            text,
+            "{:indent$}{}: {:?}",
+            "",
+            self.node,
+            self.verdict,
+            indent = depth * 2
+        );
+        if !self.items.is_empty() {
+            _ = write!(text, " {:?}", self.items);
+        }
+        text.push('\n');
+        for child in &self.children {
+            child.write_text(text, depth + 1);
+        }
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+}
+
+impl Display for Explanation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_text())
+    }
+}
+
+/// [`Explanation`] being recorded while [`Compliance`] checks a policy,
+/// with the nodes being checked, outermost first.
+#[derive(Debug)]
+pub struct Trace(RefCell<Vec<Explanation>>);
+
+impl Trace {
+    fn new(root: ExplainNode) -> Self {
+        Self(RefCell::new(vec![Explanation::new(root, None, vec![])]))
+    }
+
+    /// Run `check`, recording it as `node` under the node being checked.
+    fn record<F>(&self, node: ExplainNode, check: F) -> AnyReport
+    where
+        F: FnOnce() -> AnyReport,
+    {
+        self.0
+            .borrow_mut()
+            .push(Explanation::new(node, None, vec![]));
+        let report = check();
+        let mut nodes = self.0.borrow_mut();
+        let mut explanation = nodes.pop().expect("pushed above");
+        (explanation.verdict, explanation.items) = Verdict::of(report.clone());
+        nodes
+            .last_mut()
+            .expect("the root is only popped in `finish`")
+            .children
+            .push(explanation);
+        report
+    }
+
+    fn finish(self, report: AnyReport) -> Explanation {
+        let mut root = self.0.into_inner().pop().expect("the root is never popped");
+        (root.verdict, root.items) = Verdict::of(report);
+        root
+    }
+}
+
+/// Run `check`, recording it as the node from `node` if `trace` is set.
+pub(crate) fn traced<N, F>(trace: Option<&Trace>, node: N, check: F) -> AnyReport
+where
+    N: FnOnce() -> ExplainNode,
+    F: FnOnce() -> AnyReport,
+{
+    match trace {
+        Some(trace) => trace.record(node(), check),
+        None => check(),
+    }
+}
+
+impl Compare {
+    /// Explain the checks of each pair of ASes on `self.as_path`, from the
+    /// origin on, the export before the import.
+    /// All errors and special cases are shown regardless of `self.verbosity`.
+    pub fn explain(&self, query: &QueryIr) -> Vec<Explanation> {
+        let cmp = Self {
+            verbosity: Verbosity {
+                all_err: true,
+                ..Verbosity::minimum_all()
+            },
+            ..self.clone()
+        };
+        let mut explanations = Vec::new();
+        let path = self.as_path.iter();
+        for ((index, from), to) in path.clone().enumerate().rev().zip(path.rev().skip(1)) {
+            if let (Seq(from), Seq(to)) = (from, to) {
+                let prev_path = &self.as_path[index..];
+                for export in [true, false] {
+                    explanations.push(cmp.explain_policy(query, *from, *to, prev_path, export));
+                }
+            }
+        }
+        explanations
+    }
+
+    /// Explain the exports of `from` to `to` if `export`, otherwise the
+    /// imports of `to` from `from`, recording the parts of the policy
+    /// [`check_policy`](#method.check_policy) evaluates.
+    /// `prev_path` is as in [`check_pair`](#method.check_pair).
+    pub fn explain_policy(
+        &self,
+        query: &QueryIr,
+        from: u32,
+        to: u32,
+        prev_path: &[AsPathEntry],
+        export: bool,
+    ) -> Explanation {
+        let node = ExplainNode::Policy { from, to, export };
+        let versions = match pre_check(query, from, to, export) {
+            Ok(versions) => versions,
+            Err(report) => return Explanation::new(node, Some(report), vec![]),
+        };
+        let trace = Trace::new(node);
+        let report = Compliance {
+            trace: Some(&trace),
+            ..self.compliance(query, from, to, prev_path, export)
+        }
+        .check(versions);
+        trace.finish(report)
+    }
+}
@@ -10,44 +10,77 @@ pub struct CheckFilter<'a> {
     pub export: bool,
     pub prev_path: &'a [AsPathEntry],
     pub mp_peerings: &'a [PeeringAction],
+    /// Where to record the checks as an [`Explanation`], if anywhere.
+    pub trace: Option<&'a Trace>,
 }
 
 impl<'a> CheckFilter<'a> {
-    pub fn check_filter(&self, filter: &'a Filter, depth: isize) -> AnyReport {
+    /// `visited` holds the names of the sets being resolved, outermost first,
+    /// to detect cyclic set references.
+    pub fn check_filter(
+        &self,
+        filter: &'a Filter,
+        depth: isize,
+        visited: &mut SetVisits<'a>,
+    ) -> AnyReport {
+        let node = || ExplainNode::Filter(filter.to_string());
+        traced(self.trace, node, || {
+            self.do_check_filter(filter, depth, visited)
+        })
+    }
+
+    fn do_check_filter(
+        &self,
+        filter: &'a Filter,
+        depth: isize,
+        visited: &mut SetVisits<'a>,
+    ) -> AnyReport {
         if depth <= 0 {
             return bad_any_report(RecCheckFilter);
         }
         match filter {
-            FilterSet(name) => self.filter_set(name, depth),
+            FilterSet(name) => self.filter_set(name, depth, visited),
             Any => None,
             AddrPrefixSet(prefixes) => self.filter_prefixes(prefixes),
-            RouteSet(name, op) => self.filter_route_set(name, *op, depth),
-            AsNum(num, op) => self.filter_as_num(*num, *op),
-            AsSet(name, op) => self.filter_as_set(name, *op),
+            RouteSet(name, op) => {
+                self.filter_route_set(name, &with_inner_op(*op, &[]), depth, visited)
+            }
+            AsNum(num, op) => self.filter_as_num(*num, *op, &[]),
+            AsSet(name, op) => self.filter_as_set(name, *op, &[]),
             AsPathRE(expr) => self.filter_as_regex(expr),
-            PeerAS => self.filter_as_num(self.accept_num, RangeOperator::NoOp),
-            And { left, right } => self.filter_and(left, right, depth).to_any(),
-            Or { left, right } => self.filter_or(left, right, depth),
-            Not(filter) => self.filter_not(filter, depth),
-            Group(filter) => self.check_filter(filter, depth),
+            PeerAS => self.filter_as_num(self.accept_num, RangeOperator::NoOp, &[]),
+            And { left, right } => self.filter_and(left, right, depth, visited).to_any(),
+            Or { left, right } => self.filter_or(left, right, depth, visited),
+            Not(filter) => self.filter_not(filter, depth, visited),
+            Group(filter) => self.check_filter(filter, depth, visited),
             Community(community) => self.filter_community(community),
             Unknown(unknown) => self.bad_any_report(|| RpslUnknownFilter(unknown.into())),
         }
     }
 
-    fn filter_set(&self, name: &str, depth: isize) -> AnyReport {
+    fn filter_set(&self, name: &'a str, depth: isize, visited: &mut SetVisits<'a>) -> AnyReport {
+        visited
+            .visit(name, &[], depth, |visited| {
+                self.do_filter_set(name, depth, visited)
+            })
+            .unwrap_or_else(|cycle| bad_any_report(RecSetCycle(cycle)))
+    }
+
+    fn do_filter_set(&self, name: &str, depth: isize, visited: &mut SetVisits<'a>) -> AnyReport {
         let filter_set = match self.query.filter_sets.get(name) {
             Some(f) => f,
             None => return self.unrec_any_report(|| UnrecordedFilterSet(name.into())),
         };
         let mut report = AnyReportCase::const_default();
         for filter in &filter_set.filters {
-            report |= self.check_filter(filter, depth - 1)?;
+            report |= self.check_filter(filter, depth, visited)?;
         }
         Some(report)
     }
 
-    fn filter_as_num(&self, num: u32, op: RangeOperator) -> AnyReport {
+    /// `outers` are the range operators of the route sets `num` is in,
+    /// innermost first.
+    fn filter_as_num(&self, num: u32, op: RangeOperator, outers: &[RangeOperator]) -> AnyReport {
         let routes = match self.query.as_routes.get(&num) {
             Some(r) => r,
             None => {
@@ -57,14 +90,15 @@ impl<'a> CheckFilter<'a> {
                 }
             }
         };
-        if match_ips(&self.cmp.prefix, routes, op) {
+        if match_ips_composed(&self.cmp.prefix, routes, op, outers) {
             return None;
         }
-        if self.is_filter_export_customer(num, op) {
+        let outer_op = outermost_op(op, outers);
+        if self.is_filter_export_customer(num, outer_op) {
             self.special_any_report(|| SpecExportCustomers)
-        } else if self.is_filter_as_origin(num, op) {
+        } else if self.is_filter_as_origin(num, outer_op) {
             self.special_any_report(|| SpecAsIsOriginButNoRoute(num))
-        } else if self.is_filter_import_from_neighbor(num, op) {
+        } else if self.is_filter_import_from_neighbor(num, outer_op) {
             let reason = match self
                 .query
                 .as_sets
@@ -167,48 +201,86 @@ impl<'a> CheckFilter<'a> {
         }
     }
 
-    fn filter_route_set(&self, name: &str, op: RangeOperator, depth: isize) -> AnyReport {
-        if depth <= 0 {
-            return bad_any_report(RecFilterRouteSet(name.into()));
-        }
+    /// `ops` are the range operators applied to the route set, innermost first.
+    fn filter_route_set(
+        &self,
+        name: &'a str,
+        ops: &[RangeOperator],
+        depth: isize,
+        visited: &mut SetVisits<'a>,
+    ) -> AnyReport {
+        visited
+            .visit(name, ops, depth, |visited| {
+                self.do_filter_route_set(name, ops, depth, visited)
+            })
+            .unwrap_or_else(|cycle| bad_any_report(RecSetCycle(cycle)))
+    }
+
+    fn do_filter_route_set(
+        &self,
+        name: &str,
+        ops: &[RangeOperator],
+        depth: isize,
+        visited: &mut SetVisits<'a>,
+    ) -> AnyReport {
         let route_set = match self.query.route_sets.get(name) {
             Some(r) => r,
             None => return self.unrec_any_report(|| UnrecordedRouteSet(name.into())),
         };
         let mut report = AnyReportCase::const_default();
         for member in &route_set.members {
-            report |= self.filter_route_set_member(member, op, depth - 1)?;
+            report |= self.filter_route_set_member(member, ops, depth, visited)?;
         }
-        if let BadAnyReport(_) = report {
-            self.bad_any_report(|| MatchFilterRouteSet(name.into()))
-        } else {
-            Some(report)
+        match report {
+            BadAnyReport(mut items) => {
+                // Summarize the members' errors, but keep the cycles found.
+                items.retain(|item| matches!(item, RecSetCycle(_)));
+                Some(
+                    BadAnyReport(items)
+                        | self.bad_any_report(|| MatchFilterRouteSet(name.into()))?,
+                )
+            }
+            report => Some(report),
         }
     }
 
     fn filter_route_set_member(
         &self,
-        member: &RouteSetMember,
-        op: RangeOperator,
+        member: &'a RouteSetMember,
+        outers: &[RangeOperator],
         depth: isize,
+        visited: &mut SetVisits<'a>,
     ) -> AnyReport {
-        if depth <= 0 {
-            return bad_any_report(RecFilterRouteSetMember(Box::new(member.clone())));
-        }
         match member {
-            RouteSetMember::RSRange(prefix) => match (prefix.range_operator, op) {
-                (RangeOperator::NoOp, RangeOperator::NoOp) => self.filter_prefixes([prefix]),
-                (RangeOperator::NoOp, op) => self.filter_prefixes([&AddrPfxRange {
-                    range_operator: op,
+            RouteSetMember::RSRange(prefix) => match prefix
+                .range_operator
+                .compose_all(outers, &prefix.address_prefix)
+            {
+                Some(range_operator) if range_operator == prefix.range_operator => {
+                    self.filter_prefixes([prefix])
+                }
+                Some(range_operator) => self.filter_prefixes([&AddrPfxRange {
+                    range_operator,
                     ..prefix.clone()
                 }]),
-                _ => self.filter_prefixes([prefix]),
+                None => self.bad_any_report(|| MatchFilterPrefixes),
             },
-            RouteSetMember::NameOp(name, op) => self.filter_route_set(name, *op, depth - 1),
+            RouteSetMember::NameOp(name, op) => {
+                self.filter_route_set(name, &with_inner_op(*op, outers), depth, visited)
+            }
+            RouteSetMember::AsNum(num, op) => self.filter_as_num(*num, *op, outers),
+            RouteSetMember::AsSet(name, op) => self.filter_as_set(name, *op, outers),
         }
     }
 
-    fn filter_as_set(&self, name: &'a str, op: RangeOperator) -> AnyReport {
+    /// `outers` are the range operators of the route sets `name` is in,
+    /// innermost first.
+    fn filter_as_set(
+        &self,
+        name: &'a str,
+        op: RangeOperator,
+        outers: &[RangeOperator],
+    ) -> AnyReport {
         let as_set = match self.query.as_sets.get(name) {
             Some(s) => s,
             None => return self.unrec_any_report(|| UnrecordedAsSetRoute(name.into())),
@@ -218,7 +290,7 @@ impl<'a> CheckFilter<'a> {
         for num in &as_set.members {
             match self.query.as_routes.get(num) {
                 Some(as_routes) => {
-                    if match_ips(&self.cmp.prefix, as_routes, op) {
+                    if match_ips_composed(&self.cmp.prefix, as_routes, op, outers) {
                         return None;
                     }
                 }
@@ -227,7 +299,7 @@ impl<'a> CheckFilter<'a> {
         }
 
         if all_members_recorded && as_set.unrecorded_members.is_empty() {
-            match self.is_filter_as_set_origin(name, op) {
+            match self.is_filter_as_set_origin(name, outermost_op(op, outers)) {
                 spec_report @ Some(_) => spec_report,
                 None => self.bad_any_report(|| MatchFilterAsSet(name.into(), op)),
             }
@@ -255,9 +327,10 @@ impl<'a> CheckFilter<'a> {
     fn filter_as_regex(&self, expr: &str) -> AnyReport {
         let path = self.prev_path.iter();
         let path = match path
+            .filter(|p| !p.is_confed())
             .map(|p| match p {
                 Seq(n) => Ok(*n),
-                Set(_) => Err(()),
+                _ => Err(()),
             })
             .collect::<Result<Vec<_>, _>>()
         {
@@ -273,26 +346,46 @@ impl<'a> CheckFilter<'a> {
         .check(path)
     }
 
-    fn filter_and(&self, left: &'a Filter, right: &'a Filter, depth: isize) -> AllReport {
+    fn filter_and(
+        &self,
+        left: &'a Filter,
+        right: &'a Filter,
+        depth: isize,
+        visited: &mut SetVisits<'a>,
+    ) -> AllReport {
         if depth <= 0 {
             return bad_all_report(RecFilterAnd);
         }
-        Ok(self.check_filter(left, depth - 1).to_all()?
-            & self.check_filter(right, depth).to_all()?)
+        Ok(self.check_filter(left, depth - 1, visited).to_all()?
+            & self.check_filter(right, depth, visited).to_all()?)
     }
 
-    fn filter_or(&self, left: &'a Filter, right: &'a Filter, depth: isize) -> AnyReport {
+    fn filter_or(
+        &self,
+        left: &'a Filter,
+        right: &'a Filter,
+        depth: isize,
+        visited: &mut SetVisits<'a>,
+    ) -> AnyReport {
         if depth <= 0 {
             return bad_any_report(RecFilterOr);
         }
-        Some(self.check_filter(left, depth - 1)? | self.check_filter(right, depth)?)
+        Some(
+            self.check_filter(left, depth - 1, visited)?
+                | self.check_filter(right, depth, visited)?,
+        )
     }
 
-    fn filter_not(&self, filter: &'a Filter, depth: isize) -> AnyReport {
+    fn filter_not(
+        &self,
+        filter: &'a Filter,
+        depth: isize,
+        visited: &mut SetVisits<'a>,
+    ) -> AnyReport {
         if depth <= 0 {
             return bad_any_report(RecFilterNot);
         }
-        match self.check_filter(filter, depth) {
+        match self.check_filter(filter, depth, visited) {
             Some(report @ SkipAnyReport(_) | report @ UnrecAnyReport(_)) => {
                 Some(report | self.bad_any_report(|| MatchFilter)?)
             }
@@ -327,6 +420,19 @@ impl<'a> CheckFilter<'a> {
     }
 }
 
+/// `outers` with `op` prepended as the innermost range operator,
+/// skipping [`RangeOperator::NoOp`] because it composes to the identity.
+fn with_inner_op(op: RangeOperator, outers: &[RangeOperator]) -> Vec<RangeOperator> {
+    let inner = (op != RangeOperator::NoOp).then_some(op);
+    inner.into_iter().chain(outers.iter().copied()).collect()
+}
+
+/// The range operator that limits the prefix length last, used to check
+/// special cases without knowledge of the address prefix set.
+fn outermost_op(op: RangeOperator, outers: &[RangeOperator]) -> RangeOperator {
+    outers.last().copied().unwrap_or(op)
+}
+
 impl<'a> VerbosityReport for CheckFilter<'a> {
     fn get_verbosity(&self) -> Verbosity {
         self.cmp.verbosity
@@ -2,9 +2,9 @@ use super::*;
 
 impl Compare {
     /// Same as [`check`](#method.check), except that AS Relationship DB `db` is used to
-    /// convert suitable "bad" reports to "meh".
+    /// convert suitable "bad" reports to "meh" with `self.special_case_rules`.
     /// - If `self.verbosity.show_meh` is `false`,
-    ///     then these "meh" reports are removed.
+    ///   then these "meh" reports are removed.
     pub fn check_with_relationship(&self, query: &QueryIr, db: &AsRelDb) -> Vec<Report> {
         let mut reports = self.check(query);
         for report in reports.iter_mut() {
@@ -17,31 +17,21 @@ impl Compare {
     }
 
     fn alter_report_with_relationship(&self, report: &mut Report, query: &QueryIr, db: &AsRelDb) {
-        let (from, to, items, this_as, is_export) = match report {
-            BadImport { from, to, items } => (*from, *to, items, *to, false),
-            BadExport { from, to, items } => (*from, *to, items, *from, true),
+        let (from, to, items, is_export) = match report {
+            BadImport { from, to, items } => (*from, *to, items, false),
+            BadExport { from, to, items } => (*from, *to, items, true),
             _ => return,
         };
-        let only_provider_policies =
-            || {
-                self.verbosity.check_only_provider_policies
-                    && query.as_properties.get(&this_as).map(|property| {
-                        property.import_only_provider && property.export_only_provider
-                    }) == Some(true)
-            };
-        let maybe_report_reason = match (db.get(from, to), is_export) {
-            (Some(P2P), _) if only_provider_policies() => Some(SpecPeerOnlyProviderPolicies),
-            (Some(C2P), false) | (Some(P2C), true) if only_provider_policies() => {
-                Some(SpecCustomerOnlyProviderPolicies)
-            }
-            (None, _) if only_provider_policies() => Some(SpecOtherOnlyProviderPolicies),
-            (Some(C2P), _) if self.verbosity.special_uphill => Some(match db.is_clique(&to) {
-                true => SpecUphillTier1,
-                false => SpecUphill,
-            }),
-            _ if db.is_clique(&from) && db.is_clique(&to) => Some(SpecTier1Pair),
-            _ => None,
+        let case = SpecialCase {
+            cmp: self,
+            query,
+            db,
+            from,
+            to,
+            is_export,
+            items,
         };
+        let maybe_report_reason = self.special_case_rules.check(&case);
         if let Some(reason) = maybe_report_reason {
             *report = match is_export {
                 false => self.meh_import(from, to, mem::take(items), reason),
@@ -0,0 +1,111 @@
+use as_rel::Relationship;
+
+use super::*;
+
+/// A valley-free violation on an AS path: `leaker` received the route from
+/// `from`, a provider or peer, and propagated it to `to`, a provider or peer.
+/// Sibling ASes of the same organization are transparent, so `from` is the
+/// AS the organization of `leaker` received the route from.
+/// Hops whose relationships are unknown are also reported, unless the known
+/// relationship rules out a leak.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct LeakReport {
+    /// The AS the organization of `leaker` received the route from.
+    pub from: u32,
+    /// The AS that leaked the route.
+    pub leaker: u32,
+    /// The AS `leaker` propagated the route to.
+    pub to: u32,
+    /// Relationship from `from` to `leaker` or its sibling that received the
+    /// route, either [`P2C`] or [`P2P`], or `None` if unknown.
+    pub received: Option<Relationship>,
+    /// Relationship from `leaker` to `to`, either [`C2P`] or [`P2P`],
+    /// or `None` if unknown.
+    pub sent: Option<Relationship>,
+    /// Report of checking the RPSL `mp-export` of `leaker` to `to`.
+    pub export: Report,
+}
+
+impl LeakReport {
+    /// If this may not be a leak because a relationship is unknown.
+    pub fn is_unknown(&self) -> bool {
+        self.received.is_none() || self.sent.is_none()
+    }
+
+    /// If the RPSL of `leaker` permits the leak, or `None` if it cannot be
+    /// determined, e.g., the policy is unrecorded or skipped.
+    pub fn permitted_by_rpsl(&self) -> Option<bool> {
+        match self.export {
+            OkExport { .. } | MehExport { .. } => Some(true),
+            BadExport { .. } => Some(false),
+            _ => None,
+        }
+    }
+}
+
+impl Compare {
+    /// Detect route leaks on `self.as_path` with valley-free analysis using AS
+    /// relationships in `db`: a route must not go up or across after it
+    /// has gone down or across.
+    /// Each leak is cross-checked against the exports of the leaking AS in
+    /// `query`, regardless of `self.verbosity`.
+    /// Hops with unknown relationships are reported as
+    /// [`unknown`](LeakReport::is_unknown) if they may be leaks;
+    /// hops between siblings carry the relationship the organization received
+    /// the route with; hops with AS sets are not considered.
+    pub fn detect_leaks(&self, query: &QueryIr, db: &AsRelDb) -> Vec<LeakReport> {
+        let mut leaks = Vec::new();
+        // The AS and relationship the route entered the current organization
+        // with, carried across sibling hops.
+        let mut entry = None;
+        // `as_path` is ordered from the collector to the origin, so the route
+        // propagates from `index + 1` to `index - 1` through `index`.
+        for (index, window) in self.as_path.windows(3).enumerate().rev() {
+            let [Seq(to), Seq(leaker), Seq(from)] = window else {
+                entry = None;
+                continue;
+            };
+            let (from, received) = match (db.get(*from, *leaker), entry.take()) {
+                (Some(S2S), Some(entry)) => entry,
+                (received, _) => (*from, received),
+            };
+            let sent = db.get(*leaker, *to);
+            if sent == Some(S2S) {
+                entry = Some((from, received));
+            } else if matches!(received, Some(P2C | P2P) | None)
+                && matches!(sent, Some(C2P | P2P) | None)
+            {
+                let export =
+                    self.leak_export_report(query, *leaker, *to, &self.as_path[index + 1..]);
+                leaks.push(LeakReport {
+                    from,
+                    leaker: *leaker,
+                    to: *to,
+                    received,
+                    sent,
+                    export,
+                });
+            }
+        }
+        leaks.reverse();
+        leaks
+    }
+
+    fn leak_export_report(
+        &self,
+        query: &QueryIr,
+        leaker: u32,
+        to: u32,
+        prev_path: &[AsPathEntry],
+    ) -> Report {
+        let cmp = Self {
+            verbosity: Verbosity {
+                all_err: true,
+                ..Verbosity::minimum_all()
+            },
+            ..self.clone()
+        };
+        cmp.check_policy(query, leaker, to, prev_path, true)
+            .expect("`Verbosity::minimum_all` shows all reports")
+    }
+}
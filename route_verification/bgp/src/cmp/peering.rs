@@ -11,15 +11,24 @@ impl<'a> CheckPeering<'a> {
         I: IntoIterator<Item = &'b PeeringAction>,
     {
         let mut report = AnyReportCase::const_default();
-        for peering_actions in peerings.into_iter() {
-            let new = self.check_peering_action(peering_actions);
-            report |= new.to_any()?;
+        for (index, peering_action) in peerings.into_iter().enumerate() {
+            let node = || ExplainNode::Peering {
+                index,
+                peering: peering_action.mp_peering.to_string(),
+            };
+            report |= traced(self.c.trace, node, || {
+                self.check_peering_action(peering_action).to_any()
+            })?;
         }
         Some(report)
     }
 
     pub fn check_peering_action(&self, peering_actions: &PeeringAction) -> AllReport {
-        self.check(&peering_actions.mp_peering, self.c.cmp.recursion_limit)
+        self.check(
+            &peering_actions.mp_peering,
+            self.c.cmp.recursion_limit,
+            &mut SetVisits::default(),
+        )
         // Skipped.
         /* ?
         .join(self.check_actions(&peering_actions.actions)?)
@@ -33,31 +42,90 @@ impl<'a> CheckPeering<'a> {
         Ok(OkAllReport)
     }
 
-    /// Do not check `remote_router` or `local_router` because we do not have
-    /// the router information needed.
-    pub fn check(
+    /// Only check `remote_router` (exporting) or `local_router` (importing)
+    /// if the collector peer is the router on that side,
+    /// because we do not have the router information needed otherwise.
+    /// `visited` holds the names of the peering sets being resolved.
+    pub fn check<'v>(
         &self,
         Peering {
             remote_as,
-            remote_router: _,
-            local_router: _,
-        }: &Peering,
+            remote_router,
+            local_router,
+        }: &'v Peering,
         depth: isize,
-    ) -> AllReport {
-        self.check_remote_as(remote_as, depth).to_all()
+        visited: &mut SetVisits<'v>,
+    ) -> AllReport
+    where
+        'a: 'v,
+    {
+        let router = match self.c.export {
+            true => remote_router,
+            false => local_router,
+        };
+        Ok(self.check_remote_as(remote_as, depth, visited).to_all()?
+            & self.check_router(router.as_ref()).to_all()?)
+    }
+
+    fn check_router(&self, router: Option<&RouterExpr>) -> AnyReport {
+        let (router, ip) = match (router, self.c.collector_peer_ip) {
+            (Some(router), Some(ip)) => (router, ip),
+            _ => return None,
+        };
+        let RouterIps { ips, unrecorded } = self.c.query.router_ips(router);
+        if ips.contains(&ip) {
+            return None;
+        }
+        if unrecorded.is_empty() {
+            return self.bad_any_report(|| MatchRouter(ip));
+        }
+        let mut report = AnyReportCase::const_default();
+        for router in &unrecorded {
+            report |= self.unrec_any_report(|| match router {
+                RouterExpr::RtrSet(name) => UnrecordedRtrSet(name.into()),
+                RouterExpr::InetRtr(name) => UnrecordedInetRtr(name.into()),
+                _ => unreachable!("only inet-rtrs and rtr-sets can be unrecorded"),
+            })?;
+        }
+        Some(report)
+    }
+
+    pub(super) fn check_remote_as<'v>(
+        &self,
+        remote_as: &'v AsExpr,
+        depth: isize,
+        visited: &mut SetVisits<'v>,
+    ) -> AnyReport
+    where
+        'a: 'v,
+    {
+        let node = || ExplainNode::RemoteAs(remote_as.to_string());
+        traced(self.c.trace, node, || {
+            self.do_check_remote_as(remote_as, depth, visited)
+        })
     }
 
-    fn check_remote_as(&self, remote_as: &AsExpr, depth: isize) -> AnyReport {
+    fn do_check_remote_as<'v>(
+        &self,
+        remote_as: &'v AsExpr,
+        depth: isize,
+        visited: &mut SetVisits<'v>,
+    ) -> AnyReport
+    where
+        'a: 'v,
+    {
         if depth <= 0 {
             return bad_any_report(RecCheckRemoteAs);
         }
         match remote_as {
             AsExpr::Single(as_name) => self.check_remote_as_name(as_name, depth),
-            AsExpr::PeeringSet(name) => self.check_remote_peering_set(name, depth),
-            AsExpr::And { left, right } => self.check_and(left, right, depth).to_any(),
-            AsExpr::Or { left, right } => self.check_or(left, right, depth),
-            AsExpr::Except { left, right } => self.check_except(left, right, depth).to_any(),
-            AsExpr::Group(remote_as) => self.check_remote_as(remote_as, depth),
+            AsExpr::PeeringSet(name) => self.check_remote_peering_set(name, depth, visited),
+            AsExpr::And { left, right } => self.check_and(left, right, depth, visited).to_any(),
+            AsExpr::Or { left, right } => self.check_or(left, right, depth, visited),
+            AsExpr::Except { left, right } => {
+                self.check_except(left, right, depth, visited).to_any()
+            }
+            AsExpr::Group(remote_as) => self.check_remote_as(remote_as, depth, visited),
         }
     }
 
@@ -75,22 +143,35 @@ impl<'a> CheckPeering<'a> {
 
     fn check_remote_as_num(&self, num: u32) -> AnyReport {
         if self.accept_num == num {
-            None
-        } else {
-            self.bad_any_report(|| MatchRemoteAsNum(num))
+            return None;
+        }
+        if let Some(route_server) = self.c.query.route_server_by_asn(num) {
+            if let Some(report) = self.check_transparent_route_server(route_server) {
+                return report;
+            }
+        }
+        let report = self.bad_any_report(|| MatchRemoteAsNum(num))?;
+        match classify_asn(num) {
+            AsnClass::Public => Some(report),
+            class => Some(report | self.bad_any_report(|| RpslNonPublicAsn(num, class))?),
         }
     }
 
     fn check_remote_as_set(&self, name: &'a str) -> AnyReport {
-        let as_set = match self.c.query.as_sets.get(name) {
+        let as_set = self.c.query.as_sets.get(name);
+        if as_set.is_some_and(|as_set| as_set.contains(&self.accept_num)) {
+            return None;
+        }
+        for route_server in self.c.query.route_servers_by_member_set(name) {
+            if let Some(report) = self.check_transparent_route_server(route_server) {
+                return report;
+            }
+        }
+        let as_set = match as_set {
             Some(r) => r,
             None => return self.unrec_any_report(|| UnrecordedAsSet(name.into())),
         };
 
-        if as_set.contains(&self.accept_num) {
-            return None;
-        }
-
         if as_set.unrecorded_members.is_empty() {
             self.bad_any_report(|| MatchRemoteAsSet(name.into()))
         } else {
@@ -98,42 +179,102 @@ impl<'a> CheckPeering<'a> {
         }
     }
 
-    fn check_remote_peering_set(&self, name: &str, depth: isize) -> AnyReport {
-        if depth <= 0 {
-            return bad_any_report(RecRemotePeeringSet(name.into()));
-        }
+    /// `Some` special case if both ASes of the hop peer at `route_server`,
+    /// so the route may have gone through it transparently.
+    fn check_transparent_route_server(&self, route_server: &RouteServer) -> Option<AnyReport> {
+        let query = self.c.query;
+        (query.is_route_server_member(route_server, self.accept_num)
+            && query.is_route_server_member(route_server, self.c.self_num))
+        .then(|| self.special_any_report(|| SpecTransparentRouteServer(route_server.asn)))
+    }
+
+    fn check_remote_peering_set<'v>(
+        &self,
+        name: &'v str,
+        depth: isize,
+        visited: &mut SetVisits<'v>,
+    ) -> AnyReport
+    where
+        'a: 'v,
+    {
+        visited
+            .visit(name, &[], depth, |visited| {
+                self.do_check_remote_peering_set(name, depth, visited)
+            })
+            .unwrap_or_else(|cycle| bad_any_report(RecSetCycle(cycle)))
+    }
+
+    fn do_check_remote_peering_set<'v>(
+        &self,
+        name: &str,
+        depth: isize,
+        visited: &mut SetVisits<'v>,
+    ) -> AnyReport
+    where
+        'a: 'v,
+    {
         let peering_set = match self.c.query.peering_sets.get(name) {
             Some(r) => r,
             None => return self.unrec_any_report(|| UnrecordedPeeringSet(name.into())),
         };
         let mut report = AnyReportCase::const_default();
         for peering in &peering_set.peerings {
-            report |= self.check(peering, depth - 1).to_any()?;
+            report |= self.check(peering, depth, visited).to_any()?;
         }
         Some(report)
     }
 
-    fn check_and(&self, left: &AsExpr, right: &AsExpr, depth: isize) -> AllReport {
+    fn check_and<'v>(
+        &self,
+        left: &'v AsExpr,
+        right: &'v AsExpr,
+        depth: isize,
+        visited: &mut SetVisits<'v>,
+    ) -> AllReport
+    where
+        'a: 'v,
+    {
         if depth <= 0 {
             return bad_all_report(RecPeeringAnd);
         }
-        Ok(self.check_remote_as(left, depth - 1).to_all()?
-            & self.check_remote_as(right, depth).to_all()?)
+        Ok(self.check_remote_as(left, depth - 1, visited).to_all()?
+            & self.check_remote_as(right, depth, visited).to_all()?)
     }
 
-    fn check_or(&self, left: &AsExpr, right: &AsExpr, depth: isize) -> AnyReport {
+    fn check_or<'v>(
+        &self,
+        left: &'v AsExpr,
+        right: &'v AsExpr,
+        depth: isize,
+        visited: &mut SetVisits<'v>,
+    ) -> AnyReport
+    where
+        'a: 'v,
+    {
         if depth <= 0 {
             return bad_any_report(RecPeeringOr);
         }
-        Some(self.check_remote_as(left, depth - 1)? | self.check_remote_as(right, depth)?)
+        Some(
+            self.check_remote_as(left, depth - 1, visited)?
+                | self.check_remote_as(right, depth, visited)?,
+        )
     }
 
-    fn check_except(&self, left: &AsExpr, right: &AsExpr, depth: isize) -> AllReport {
+    fn check_except<'v>(
+        &self,
+        left: &'v AsExpr,
+        right: &'v AsExpr,
+        depth: isize,
+        visited: &mut SetVisits<'v>,
+    ) -> AllReport
+    where
+        'a: 'v,
+    {
         if depth <= 0 {
             return bad_all_report(RecPeeringExcept);
         }
-        Ok(self.check_remote_as(left, depth - 1).to_all()?
-            & match self.check_remote_as(right, depth) {
+        Ok(self.check_remote_as(left, depth - 1, visited).to_all()?
+            & match self.check_remote_as(right, depth, visited) {
                 report @ Some(SkipAnyReport(_) | UnrecAnyReport(_)) => report.to_all()?,
                 Some(MehAnyReport(_) | BadAnyReport(_)) => OkAllReport,
                 None => self.bad_all_report(|| MatchExceptPeeringRight)?,
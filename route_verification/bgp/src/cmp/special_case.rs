@@ -0,0 +1,238 @@
+use std::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+    sync::{Arc, OnceLock},
+};
+
+use super::*;
+
+/// A bad import or export considered by [`SpecialCaseRule`]s.
+#[derive(Clone, Copy, Debug)]
+pub struct SpecialCase<'a> {
+    pub cmp: &'a Compare,
+    pub query: &'a QueryIr,
+    pub db: &'a AsRelDb,
+    pub from: u32,
+    pub to: u32,
+    /// `true` for a bad export of `from`, `false` for a bad import of `to`.
+    pub is_export: bool,
+    pub items: &'a [ReportItem],
+}
+
+impl SpecialCase<'_> {
+    /// The AS whose policy is checked.
+    pub fn this_as(&self) -> u32 {
+        match self.is_export {
+            true => self.from,
+            false => self.to,
+        }
+    }
+
+    /// Relationship from `from` to `to`.
+    pub fn relationship(&self) -> Option<as_rel::Relationship> {
+        self.db.get(self.from, self.to)
+    }
+}
+
+/// Heuristic that explains a bad import or export as a special case,
+/// turning the report into "meh" in
+/// [`check_with_relationship`](Compare::check_with_relationship).
+///
+/// Site-specific rules can report [`SpecCustomRule`] with their name.
+pub trait SpecialCaseRule: Send + Sync {
+    /// Name identifying the rule in [`SpecialCaseRules`].
+    fn name(&self) -> &str;
+
+    /// Parameters of the rule, e.g., `"64500"` for a rule about AS64500,
+    /// or empty if none.
+    /// Rules of the same name but different parameters are different.
+    fn params(&self) -> String;
+
+    /// The reason if `case` is a special case under this rule.
+    fn check(&self, case: &SpecialCase) -> Option<ReportItem>;
+}
+
+/// Only provider policies are specified, checked if
+/// `verbosity.check_only_provider_policies`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OnlyProviderPolicies;
+
+impl SpecialCaseRule for OnlyProviderPolicies {
+    fn name(&self) -> &str {
+        "only_provider_policies"
+    }
+
+    fn params(&self) -> String {
+        String::new()
+    }
+
+    fn check(&self, case: &SpecialCase) -> Option<ReportItem> {
+        let only_provider_policies = case.cmp.verbosity.check_only_provider_policies
+            && case
+                .query
+                .as_properties
+                .get(&case.this_as())
+                .map(|property| property.import_only_provider && property.export_only_provider)
+                == Some(true);
+        if !only_provider_policies {
+            return None;
+        }
+        match (case.relationship(), case.is_export) {
+            (Some(P2P), _) => Some(SpecPeerOnlyProviderPolicies),
+            (Some(C2P), false) | (Some(P2C), true) => Some(SpecCustomerOnlyProviderPolicies),
+            (None, _) => Some(SpecOtherOnlyProviderPolicies),
+            _ => None,
+        }
+    }
+}
+
+/// Route from customer to provider, checked if `verbosity.special_uphill`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Uphill;
+
+impl SpecialCaseRule for Uphill {
+    fn name(&self) -> &str {
+        "uphill"
+    }
+
+    fn params(&self) -> String {
+        String::new()
+    }
+
+    fn check(&self, case: &SpecialCase) -> Option<ReportItem> {
+        match case.relationship() {
+            Some(C2P) if case.cmp.verbosity.special_uphill => {
+                Some(match case.db.is_clique(&case.to) {
+                    true => SpecUphillTier1,
+                    false => SpecUphill,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Route between Tier 1 ASes.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Tier1Pair;
+
+impl SpecialCaseRule for Tier1Pair {
+    fn name(&self) -> &str {
+        "tier1_pair"
+    }
+
+    fn params(&self) -> String {
+        String::new()
+    }
+
+    fn check(&self, case: &SpecialCase) -> Option<ReportItem> {
+        (case.db.is_clique(&case.from) && case.db.is_clique(&case.to)).then_some(SpecTier1Pair)
+    }
+}
+
+/// Route between sibling ASes of the same organization, whose policies
+/// often leave out each other.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Siblings;
+
+impl SpecialCaseRule for Siblings {
+    fn name(&self) -> &str {
+        "siblings"
+    }
+
+    fn params(&self) -> String {
+        String::new()
+    }
+
+    fn check(&self, case: &SpecialCase) -> Option<ReportItem> {
+        (case.relationship() == Some(S2S)).then_some(SpecSiblings)
+    }
+}
+
+/// Registry of [`SpecialCaseRule`]s, tried in order until one applies.
+/// Registries are compared and hashed by the names and parameters of their
+/// rules.
+/// Clones share the rules until one of them is modified.
+#[derive(Clone)]
+pub struct SpecialCaseRules(Arc<Vec<Arc<dyn SpecialCaseRule>>>);
+
+impl SpecialCaseRules {
+    /// No rules, so no bad report becomes "meh".
+    pub fn empty() -> Self {
+        Self(Arc::new(Vec::new()))
+    }
+
+    /// Built-in [`OnlyProviderPolicies`], [`Uphill`], [`Tier1Pair`], and
+    /// [`Siblings`], shared by all registries created this way.
+    pub fn builtin() -> Self {
+        static BUILTIN: OnceLock<SpecialCaseRules> = OnceLock::new();
+        BUILTIN
+            .get_or_init(|| {
+                Self(Arc::new(vec![
+                    Arc::new(OnlyProviderPolicies),
+                    Arc::new(Uphill),
+                    Arc::new(Tier1Pair),
+                    Arc::new(Siblings),
+                ]))
+            })
+            .clone()
+    }
+
+    /// Add `rule` after the existing rules.
+    pub fn push<R: SpecialCaseRule + 'static>(&mut self, rule: R) {
+        Arc::make_mut(&mut self.0).push(Arc::new(rule));
+    }
+
+    /// Reason from the first rule that applies to `case`.
+    pub fn check(&self, case: &SpecialCase) -> Option<ReportItem> {
+        self.0.iter().find_map(|rule| rule.check(case))
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(|rule| rule.name())
+    }
+
+    fn keys(&self) -> impl Iterator<Item = (&str, String)> {
+        self.0.iter().map(|rule| (rule.name(), rule.params()))
+    }
+}
+
+impl Default for SpecialCaseRules {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+impl std::fmt::Debug for SpecialCaseRules {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.names()).finish()
+    }
+}
+
+impl PartialEq for SpecialCaseRules {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0) || self.keys().eq(other.keys())
+    }
+}
+
+impl Eq for SpecialCaseRules {}
+
+impl PartialOrd for SpecialCaseRules {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SpecialCaseRules {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.keys().cmp(other.keys())
+    }
+}
+
+impl Hash for SpecialCaseRules {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for key in self.keys() {
+            key.hash(state);
+        }
+    }
+}
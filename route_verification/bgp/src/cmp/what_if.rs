@@ -0,0 +1,45 @@
+use super::*;
+
+/// Answer to a "what-if" query on [`QueryIr`] for a single hop.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct WhatIf {
+    /// What [`Compare::check`] would report for the hop, with the
+    /// [`RuleIndex`] of the accepting entry if successful.
+    pub report: Report,
+    pub explanation: Explanation,
+}
+
+impl QueryIr {
+    /// Would `to` import `prefix` from `from`?
+    /// `path` is the AS path `from` received the route with, empty if `from`
+    /// originates it; it is only used by AS path regex filters.
+    /// Like [`Compare::check_pair`], hops with AS_TRANS are skipped and
+    /// the policies of non-public ASes are not checked.
+    pub fn would_import(&self, prefix: IpNet, from: u32, to: u32, path: &[u32]) -> WhatIf {
+        self.what_if(prefix, from, to, path, false)
+    }
+
+    /// Would `from` export `prefix` to `to`?
+    /// `path` is as in [`would_import`](#method.would_import).
+    pub fn would_export(&self, prefix: IpNet, from: u32, to: u32, path: &[u32]) -> WhatIf {
+        self.what_if(prefix, from, to, path, true)
+    }
+
+    fn what_if(&self, prefix: IpNet, from: u32, to: u32, path: &[u32], export: bool) -> WhatIf {
+        let as_path = [to, from].iter().chain(path).map(|n| Seq(*n)).collect();
+        let cmp = Compare::new(prefix, as_path).verbosity(Verbosity {
+            all_err: true,
+            record_rule: true,
+            ..Verbosity::minimum_all()
+        });
+        let prev_path: Vec<_> = [from].iter().chain(path).map(|n| Seq(*n)).collect();
+        let report = cmp
+            .check_policy(self, from, to, &prev_path, export)
+            .expect("`Verbosity::minimum_all` shows all reports");
+        let explanation = cmp.explain_policy(self, from, to, &prev_path, export);
+        WhatIf {
+            report,
+            explanation,
+        }
+    }
+}
@@ -0,0 +1,243 @@
+//! Which routes change verdicts between two [`Ir`] snapshots.
+use std::collections::BTreeSet;
+
+use super::*;
+
+use {AsPathEntry::Seq, Report::*};
+
+/// Objects that differ between two [`Ir`] snapshots,
+/// ignoring object bodies and source spans.
+/// Changes to `inet-rtr` and `rtr-set` objects are not tracked.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct IrDiff {
+    /// Aut-nums whose imports or exports were added, removed, or changed.
+    pub aut_nums: BTreeSet<u32>,
+    /// Sets added, removed, or changed, and
+    /// AS numbers whose route objects changed.
+    pub objects: BTreeSet<ObjectName>,
+}
+
+impl IrDiff {
+    pub fn new(old: &Ir, new: &Ir) -> Self {
+        let aut_nums = changed_keys(&old.aut_nums, &new.aut_nums, |a, b| {
+            same_policies(&a.imports, &b.imports) && same_policies(&a.exports, &b.exports)
+        })
+        .copied()
+        .collect();
+        let as_sets = changed_keys(&old.as_sets, &new.as_sets, |a, b| {
+            (&a.members, &a.set_members, a.is_any) == (&b.members, &b.set_members, b.is_any)
+        });
+        let route_sets = changed_keys(&old.route_sets, &new.route_sets, |a, b| {
+            a.members == b.members
+        });
+        let filter_sets = changed_keys(&old.filter_sets, &new.filter_sets, |a, b| {
+            a.filters == b.filters
+        });
+        let peering_sets = changed_keys(&old.peering_sets, &new.peering_sets, |a, b| {
+            a.peerings == b.peerings
+        });
+        let as_routes = changed_keys(&old.as_routes, &new.as_routes, PartialEq::eq);
+        let objects = as_sets
+            .cloned()
+            .map(ObjectName::AsSet)
+            .chain(route_sets.cloned().map(ObjectName::RouteSet))
+            .chain(filter_sets.cloned().map(ObjectName::FilterSet))
+            .chain(peering_sets.cloned().map(ObjectName::PeeringSet))
+            .chain(as_routes.copied().map(ObjectName::AsNum))
+            .collect();
+        Self { aut_nums, objects }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.aut_nums.is_empty() && self.objects.is_empty()
+    }
+
+    /// Aut-nums whose checks may change: those changed, and those with rules
+    /// referring to changed objects in either snapshot, directly or through
+    /// sets, per `old_index` and `new_index` from [`Ir::reference_index`].
+    pub fn affected_aut_nums(
+        &self,
+        old_index: &ReferenceIndex,
+        new_index: &ReferenceIndex,
+    ) -> BTreeSet<u32> {
+        let mut affected = self.aut_nums.clone();
+        for name in &self.objects {
+            affected.extend(old_index.affected_aut_nums(name));
+            affected.extend(new_index.affected_aut_nums(name));
+        }
+        affected
+    }
+}
+
+/// Keys only in one of `old` and `new`, or whose values are not `same`.
+fn changed_keys<'a, K, V, F>(
+    old: &'a BTreeMap<K, V>,
+    new: &'a BTreeMap<K, V>,
+    same: F,
+) -> impl Iterator<Item = &'a K>
+where
+    K: Ord,
+    F: Fn(&V, &V) -> bool + 'a,
+{
+    let keys: BTreeSet<_> = old.keys().chain(new.keys()).collect();
+    keys.into_iter()
+        .filter(move |key| match (old.get(key), new.get(key)) {
+            (Some(a), Some(b)) => !same(a, b),
+            _ => true,
+        })
+}
+
+fn same_policies(a: &Versions, b: &Versions) -> bool {
+    let same_entries = |a: &Vec<Entry>, b: &Vec<Entry>| {
+        a.len() == b.len()
+            && a.iter()
+                .zip(b)
+                .all(|(a, b)| (&a.mp_peerings, &a.mp_filter) == (&b.mp_peerings, &b.mp_filter))
+    };
+    entry_lists(a)
+        .iter()
+        .flatten()
+        .zip(entry_lists(b).iter().flatten())
+        .all(|(a, b)| same_entries(a, b))
+}
+
+fn entry_lists(versions: &Versions) -> [[&Vec<Entry>; 3]; 3] {
+    [&versions.any, &versions.ipv4, &versions.ipv6]
+        .map(|casts| [&casts.any, &casts.unicast, &casts.multicast])
+}
+
+/// A change in the verdict of the export of `from` to `to` if `export`,
+/// otherwise the import of `to` from `from`.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct Transition {
+    pub from: u32,
+    pub to: u32,
+    pub export: bool,
+    pub old: Verdict,
+    pub new: Verdict,
+}
+
+impl Transition {
+    /// The AS whose policy is checked.
+    pub const fn aut_num(&self) -> u32 {
+        match self.export {
+            true => self.from,
+            false => self.to,
+        }
+    }
+}
+
+/// Verdict changes of the checks on a route.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct RouteImpact {
+    pub prefix: IpNet,
+    pub as_path: Vec<AsPathEntry>,
+    pub transitions: Vec<Transition>,
+}
+
+/// Result of [`Impact::analyze`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Impact {
+    pub diff: IrDiff,
+    pub affected_aut_nums: BTreeSet<u32>,
+    /// Number of routes re-verified because their AS paths contain
+    /// any of `affected_aut_nums`.
+    pub n_checked: usize,
+    /// Routes with verdict changes.
+    pub routes: Vec<RouteImpact>,
+    /// For each AS whose policy is checked, the number of each pair of
+    /// old and new verdicts in `routes`.
+    pub per_as: BTreeMap<u32, BTreeMap<(Verdict, Verdict), usize>>,
+}
+
+impl Impact {
+    /// Compute the objects changed from `old` to `new`, re-verify `routes`
+    /// whose AS paths contain aut-nums affected by them against both, and
+    /// collect the changes in verdicts of each import and export check.
+    pub fn analyze(old: Ir, new: Ir, routes: &[Compare]) -> Self {
+        let diff = IrDiff::new(&old, &new);
+        let affected_aut_nums =
+            diff.affected_aut_nums(&old.reference_index(), &new.reference_index());
+        let (old, new) = (QueryIr::from_ir(old), QueryIr::from_ir(new));
+        let checked: Vec<_> = routes
+            .par_iter()
+            .filter(|compare| {
+                // Only hops between `Seq`s are checked.
+                compare
+                    .as_path
+                    .iter()
+                    .any(|entry| matches!(entry, Seq(num) if affected_aut_nums.contains(num)))
+            })
+            .map(|compare| route_impact(compare, &old, &new))
+            .collect();
+        let n_checked = checked.len();
+        let routes: Vec<_> = checked
+            .into_iter()
+            .filter(|route| !route.transitions.is_empty())
+            .collect();
+        let mut per_as: BTreeMap<_, BTreeMap<_, _>> = BTreeMap::new();
+        for transition in routes.iter().flat_map(|route| &route.transitions) {
+            *per_as
+                .entry(transition.aut_num())
+                .or_default()
+                .entry((transition.old, transition.new))
+                .or_default() += 1;
+        }
+        Self {
+            diff,
+            affected_aut_nums,
+            n_checked,
+            routes,
+            per_as,
+        }
+    }
+}
+
+/// Check `compare` against `old` and `new`, showing all verdicts.
+fn route_impact(compare: &Compare, old: &QueryIr, new: &QueryIr) -> RouteImpact {
+    let compare = compare.clone().verbosity(Verbosity::minimum_all());
+    let verdicts = |query| -> BTreeMap<_, _> {
+        compare
+            .check(query)
+            .into_iter()
+            .filter_map(|report| hop_verdict(&report))
+            .collect()
+    };
+    let new_verdicts = verdicts(new);
+    let transitions = verdicts(old)
+        .into_iter()
+        .filter_map(|((from, to, export), old)| {
+            let new = *new_verdicts.get(&(from, to, export))?;
+            (old != new).then_some(Transition {
+                from,
+                to,
+                export,
+                old,
+                new,
+            })
+        })
+        .collect();
+    RouteImpact {
+        prefix: compare.prefix,
+        as_path: compare.as_path,
+        transitions,
+    }
+}
+
+/// `(from, to, export)` and the verdict of an import or export report.
+fn hop_verdict(report: &Report) -> Option<((u32, u32, bool), Verdict)> {
+    let (from, to, export, verdict) = match report {
+        OkImport { from, to, .. } => (from, to, false, Verdict::Ok),
+        OkExport { from, to, .. } => (from, to, true, Verdict::Ok),
+        SkipImport { from, to, .. } => (from, to, false, Verdict::Skip),
+        SkipExport { from, to, .. } => (from, to, true, Verdict::Skip),
+        UnrecImport { from, to, .. } => (from, to, false, Verdict::Unrec),
+        UnrecExport { from, to, .. } => (from, to, true, Verdict::Unrec),
+        MehImport { from, to, .. } => (from, to, false, Verdict::Meh),
+        MehExport { from, to, .. } => (from, to, true, Verdict::Meh),
+        BadImport { from, to, .. } => (from, to, false, Verdict::Bad),
+        BadExport { from, to, .. } => (from, to, true, Verdict::Bad),
+        AsPathPairWithSet { .. } | Rpki { .. } | RouteObject { .. } | Aspa { .. } => return None,
+    };
+    Some(((*from, *to, export), verdict))
+}
@@ -0,0 +1,216 @@
+//! Infer AS relationships from the RPSL policies of aut-nums.
+//!
+//! An aut-num that imports `ANY` from a neighbor treats it as a provider,
+//! and one that exports `ANY` to a neighbor treats it as a customer.
+//! Restricted imports and exports, e.g., `export: to AS1 announce AS-ME`,
+//! rule out the neighbor being a provider or a customer, respectively.
+use as_rel::Relationship;
+use hashbrown::HashMap;
+
+use super::*;
+
+/// How much the policies support an inferred relationship.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Confidence {
+    /// One policy of one side, or restricted policies of one side for P2P.
+    Low,
+    /// Consistent imports and exports of one side.
+    Medium,
+    /// Both sides agree.
+    High,
+}
+
+/// A relationship inferred from RPSL, with its [`Confidence`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct InferredRel {
+    pub relationship: Relationship,
+    pub confidence: Confidence,
+}
+
+impl InferredRel {
+    pub fn reversed(self) -> Self {
+        Self {
+            relationship: self.relationship.reversed(),
+            ..self
+        }
+    }
+}
+
+/// An inferred relationship from `as1` to `as2` that differs from the one
+/// `recorded` in an [`AsRelDb`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct RelDisagreement {
+    pub as1: u32,
+    pub as2: u32,
+    pub inferred: InferredRel,
+    pub recorded: Relationship,
+}
+
+/// # AS relationships inferred from RPSL
+/// **Construct** from aut-nums using [`from_aut_nums`](#method.from_aut_nums).
+///
+/// **Query** [`InferredRel`] between two ASes using [`get`](#method.get),
+/// and compare against an [`AsRelDb`] using [`diff`](#method.diff).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct InferredRelDb {
+    /// Keyed by AS pairs with the smaller AS first.
+    pub source2dest: HashMap<(u32, u32), InferredRel>,
+}
+
+/// What the policies of an aut-num toward a neighbor say.
+#[derive(Clone, Copy, Debug, Default)]
+struct Evidence {
+    import_any: bool,
+    import_restricted: bool,
+    export_any: bool,
+    export_restricted: bool,
+}
+
+impl Evidence {
+    /// Relationship from the aut-num to the neighbor.
+    fn infer(self) -> Option<InferredRel> {
+        let (relationship, confidence) = match self {
+            Self {
+                import_any: true,
+                export_any: true,
+                ..
+            } => return None,
+            Self {
+                import_any: true,
+                export_restricted,
+                ..
+            } => (C2P, Self::confidence(export_restricted)),
+            Self {
+                export_any: true,
+                import_restricted,
+                ..
+            } => (P2C, Self::confidence(import_restricted)),
+            Self {
+                import_restricted: true,
+                export_restricted: true,
+                ..
+            } => (P2P, Confidence::Low),
+            _ => return None,
+        };
+        Some(InferredRel {
+            relationship,
+            confidence,
+        })
+    }
+
+    fn confidence(consistent: bool) -> Confidence {
+        match consistent {
+            true => Confidence::Medium,
+            false => Confidence::Low,
+        }
+    }
+}
+
+impl InferredRelDb {
+    /// Infer relationships from the imports and exports of `aut_nums`.
+    /// Only peerings with a single AS number as the remote AS are used.
+    /// If both sides of a pair infer the same relationship, it has
+    /// [`Confidence::High`]; if they disagree, the one with higher confidence
+    /// is kept, or neither if tied.
+    pub fn from_aut_nums<'a, I>(aut_nums: I) -> Self
+    where
+        I: IntoIterator<Item = (&'a u32, &'a AutNum)>,
+    {
+        let mut inferred: HashMap<(u32, u32), Vec<InferredRel>> = HashMap::new();
+        for (num, aut_num) in aut_nums {
+            for (neighbor, rel) in infer_aut_num(*num, aut_num) {
+                let (key, rel) = match *num < neighbor {
+                    true => ((*num, neighbor), rel),
+                    false => ((neighbor, *num), rel.reversed()),
+                };
+                inferred.entry(key).or_default().push(rel);
+            }
+        }
+        let source2dest = inferred
+            .into_iter()
+            .filter_map(|(key, rels)| merge(&rels).map(|rel| (key, rel)))
+            .collect();
+        Self { source2dest }
+    }
+
+    /// Get [`InferredRel`] from `as1` to `as2`, if inferred.
+    pub fn get(&self, as1: u32, as2: u32) -> Option<InferredRel> {
+        match as1 < as2 {
+            true => self.source2dest.get(&(as1, as2)).copied(),
+            false => self.source2dest.get(&(as2, as1)).map(|rel| rel.reversed()),
+        }
+    }
+
+    /// Inferred relationships that differ from those recorded in `db`,
+    /// sorted. Pairs missing from `db` are not disagreements.
+    pub fn diff(&self, db: &AsRelDb) -> Vec<RelDisagreement> {
+        let mut disagreements: Vec<_> = self
+            .source2dest
+            .iter()
+            .filter_map(|(&(as1, as2), &inferred)| {
+                let recorded = db.get(as1, as2)?;
+                (recorded != inferred.relationship).then_some(RelDisagreement {
+                    as1,
+                    as2,
+                    inferred,
+                    recorded,
+                })
+            })
+            .collect();
+        disagreements.sort();
+        disagreements
+    }
+
+    pub fn len(&self) -> usize {
+        self.source2dest.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.source2dest.is_empty()
+    }
+}
+
+/// Relationships from `num` to each neighbor, inferred from `aut_num`.
+fn infer_aut_num(num: u32, aut_num: &AutNum) -> impl Iterator<Item = (u32, InferredRel)> {
+    let mut evidences: BTreeMap<u32, Evidence> = BTreeMap::new();
+    for (versions, is_export) in [(&aut_num.imports, false), (&aut_num.exports, true)] {
+        for entry in versions.entries_iter() {
+            let is_any = entry.mp_filter == Filter::Any;
+            for peering in &entry.mp_peerings {
+                let AsExpr::Single(AsName::Num(neighbor)) = peering.mp_peering.remote_as else {
+                    continue;
+                };
+                if neighbor == num {
+                    continue;
+                }
+                let evidence = evidences.entry(neighbor).or_default();
+                match (is_export, is_any) {
+                    (false, true) => evidence.import_any = true,
+                    (false, false) => evidence.import_restricted = true,
+                    (true, true) => evidence.export_any = true,
+                    (true, false) => evidence.export_restricted = true,
+                }
+            }
+        }
+    }
+    evidences
+        .into_iter()
+        .filter_map(|(neighbor, evidence)| evidence.infer().map(|rel| (neighbor, rel)))
+}
+
+/// Merge relationships inferred from one or both sides of a pair.
+fn merge(rels: &[InferredRel]) -> Option<InferredRel> {
+    match rels {
+        [rel] => Some(*rel),
+        [a, b] if a.relationship == b.relationship => Some(InferredRel {
+            relationship: a.relationship,
+            confidence: Confidence::High,
+        }),
+        [a, b] => match a.confidence.cmp(&b.confidence) {
+            std::cmp::Ordering::Less => Some(*b),
+            std::cmp::Ordering::Equal => None,
+            std::cmp::Ordering::Greater => Some(*a),
+        },
+        _ => None,
+    }
+}
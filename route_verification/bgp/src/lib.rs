@@ -9,8 +9,11 @@ use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 
 pub mod cmp;
+pub mod impact;
+pub mod infer;
 pub mod query;
 pub mod report;
+pub mod router_config;
 pub mod stats;
 #[cfg(test)]
 mod tests;
@@ -20,9 +23,20 @@ pub mod wrapper;
 #[doc(inline)]
 pub use {
     bgpmap::{self as map, AsPathEntry},
-    cmp::Compare,
-    query::{customer_set, AsProperty, QueryAsSet, QueryIr},
+    cmp::{
+        Compare, ExplainNode, Explanation, LeakReport, SpecialCase, SpecialCaseRule,
+        SpecialCaseRules, Verdict, WhatIf,
+    },
+    impact::{Impact, IrDiff, RouteImpact, Transition},
+    infer::{Confidence, InferredRel, InferredRelDb, RelDisagreement},
+    ir::{PolicyCast, PolicyVersion, RuleIndex},
+    query::{
+        customer_cone_set, customer_set, AsProperty, AsnSet, PrefixList, PrefixListError,
+        QueryAsSet, QueryIr, RemoteAsns, RouteObjectState, RouteServer, RouterIps,
+    },
     report::{Report, ReportItem},
+    router_config::{Bird2, ConfigBackend, ConfigError, IosXr, Junos, NeighborPolicy},
+    rpki::{AspaDb, AspaState, RoaDb, RovState},
     verbosity::Verbosity,
     wrapper::{parse_mrt, Line},
 };
@@ -1,10 +1,19 @@
+use std::sync::OnceLock;
+
 use hashbrown::{HashMap, HashSet};
 
 use super::*;
 
+mod prefix_list;
 mod pseudo_set;
+mod remote_as;
+mod route_object;
+mod route_server;
+mod router;
 
-pub use pseudo_set::*;
+pub use {
+    prefix_list::*, pseudo_set::*, remote_as::*, route_object::*, route_server::*, router::*,
+};
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct QueryAsSet {
@@ -79,8 +88,15 @@ pub struct QueryIr {
     pub filter_sets: HashMap<String, FilterSet>,
     /// Each value should always be sorted.
     pub as_routes: HashMap<u32, Vec<IpNet>>,
+    /// Sorted origins of each prefix in `as_routes`, only built once
+    /// [`route_object_state`](#method.route_object_state) is first called.
+    prefix_origins: OnceLock<HashMap<IpNet, Vec<u32>>>,
     /// Special properties for some ASes.
     pub as_properties: HashMap<u32, AsProperty>,
+    pub inet_rtrs: HashMap<String, InetRtr>,
+    pub rtr_sets: HashMap<String, RtrSet>,
+    /// Transparent IXP route servers, keyed by their ASNs.
+    pub route_servers: HashMap<u32, RouteServer>,
 }
 
 impl QueryIr {
@@ -93,6 +109,8 @@ impl QueryIr {
             peering_sets,
             filter_sets,
             mut as_routes,
+            inet_rtrs,
+            rtr_sets,
         } = ir;
         as_routes.par_iter_mut().for_each(|(_, routes)| {
             routes.sort();
@@ -105,6 +123,8 @@ impl QueryIr {
         let peering_sets = HashMap::from_iter(peering_sets);
         let filter_sets = HashMap::from_iter(filter_sets);
         let as_routes = HashMap::from_iter(as_routes);
+        let inet_rtrs = HashMap::from_iter(inet_rtrs);
+        let rtr_sets = HashMap::from_iter(rtr_sets);
         Self {
             aut_nums,
             as_sets,
@@ -112,7 +132,11 @@ impl QueryIr {
             peering_sets,
             filter_sets,
             as_routes,
+            prefix_origins: OnceLock::new(),
             as_properties: HashMap::new(),
+            inet_rtrs,
+            rtr_sets,
+            route_servers: HashMap::new(),
         }
     }
 
@@ -133,6 +157,15 @@ impl QueryIr {
             ..Self::from_ir(ir)
         }
     }
+
+    /// Same as [`from_ir_and_as_relationship`](#method.from_ir_and_as_relationship),
+    /// but also with customer cone pseudo sets injected under names
+    /// `cc#{aut_num}`.
+    /// These sets can be large for ASes near the top of the hierarchy.
+    pub fn from_ir_and_as_relationship_with_cones(mut ir: Ir, db: &AsRelDb) -> Self {
+        ir.as_sets.extend(make_customer_cone_pseudo_set(db));
+        Self::from_ir_and_as_relationship(ir, db)
+    }
 }
 
 fn flatten_as_set(
@@ -163,6 +196,8 @@ pub fn flatten_as_sets(as_sets: &BTreeMap<String, AsSet>) -> HashMap<String, Que
                 members,
                 set_members,
                 is_any,
+                member_spans: _,
+                set_member_spans: _,
             } = set;
             let mut query_as_set = QueryAsSet {
                 body: body.clone(),
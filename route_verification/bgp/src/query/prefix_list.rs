@@ -0,0 +1,345 @@
+use std::{
+    collections::BTreeSet,
+    net::{Ipv4Addr, Ipv6Addr},
+};
+
+use ipnet::{Ipv4Net, Ipv6Net};
+use thiserror::Error;
+
+use super::*;
+
+/// Explicit prefix list per address family a filter compiles to,
+/// as `bgpq3`/`bgpq4` would generate.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct PrefixList {
+    /// Sorted and aggregated.
+    pub ipv4: Vec<AddrPfxRange>,
+    /// Sorted and aggregated.
+    pub ipv6: Vec<AddrPfxRange>,
+}
+
+impl PrefixList {
+    pub fn is_empty(&self) -> bool {
+        self.ipv4.is_empty() && self.ipv6.is_empty()
+    }
+
+    /// Aggregated union of `self` and `other`.
+    pub fn union(self, other: Self) -> Self {
+        let merge = |ranges: Vec<AddrPfxRange>, others: Vec<AddrPfxRange>| {
+            let ranges = ranges
+                .into_iter()
+                .chain(others)
+                .filter_map(|range| LenRange::new(range.address_prefix, range.range_operator));
+            aggregate(ranges.collect())
+        };
+        Self {
+            ipv4: merge(self.ipv4, other.ipv4),
+            ipv6: merge(self.ipv6, other.ipv6),
+        }
+    }
+}
+
+/// Why a filter cannot be compiled into a [`PrefixList`].
+#[derive(Clone, Debug, Eq, Error, Hash, PartialEq)]
+pub enum PrefixListError {
+    #[error("`NOT` cannot be expressed as a prefix list")]
+    Not,
+    #[error("`AND` cannot be expressed as a prefix list")]
+    And,
+    #[error("AS path regex `{0}` cannot be expressed as a prefix list")]
+    AsPathRE(String),
+    #[error("community filter `{0:?}` cannot be expressed as a prefix list")]
+    Community(Call),
+    #[error("`PeerAS` without a peer")]
+    PeerAS,
+    #[error("unknown filter `{0}`")]
+    Unknown(String),
+    #[error("unrecorded filter-set `{0}`")]
+    UnrecordedFilterSet(String),
+    #[error("unrecorded route-set `{0}`")]
+    UnrecordedRouteSet(String),
+    #[error("unrecorded as-set `{0}`")]
+    UnrecordedAsSet(String),
+}
+
+/// A prefix with the absolute range of lengths of its more specifics.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+struct LenRange {
+    prefix: IpNet,
+    low: u8,
+    high: u8,
+}
+
+impl LenRange {
+    fn new(prefix: IpNet, op: RangeOperator) -> Option<Self> {
+        let (len, max_len) = (prefix.prefix_len(), prefix.max_prefix_len());
+        let (low, high) = match op {
+            RangeOperator::NoOp => (len, len),
+            RangeOperator::Minus => (len + 1, max_len),
+            RangeOperator::Plus => (len, max_len),
+            RangeOperator::Num(n) => (n.max(len), n.min(max_len)),
+            RangeOperator::Range(n, m) => (n.max(len), m.min(max_len)),
+        };
+        (low <= high).then_some(Self {
+            prefix: prefix.trunc(),
+            low,
+            high,
+        })
+    }
+
+    fn covers(&self, other: &Self) -> bool {
+        self.prefix.contains(&other.prefix) && self.low <= other.low && other.high <= self.high
+    }
+
+    fn to_range(self) -> AddrPfxRange {
+        let (len, max_len) = (self.prefix.prefix_len(), self.prefix.max_prefix_len());
+        let range_operator = match (self.low, self.high) {
+            (low, high) if low == len && high == len => RangeOperator::NoOp,
+            (low, high) if low == len + 1 && high == max_len => RangeOperator::Minus,
+            (low, high) if low == len && high == max_len => RangeOperator::Plus,
+            (low, high) if low == high => RangeOperator::Num(low),
+            (low, high) => RangeOperator::Range(low, high),
+        };
+        AddrPfxRange {
+            address_prefix: self.prefix,
+            range_operator,
+        }
+    }
+}
+
+/// Remove ranges covered by others and merge sibling prefixes with the same
+/// more-specific lengths into their parents, until nothing changes.
+fn aggregate(ranges: Vec<LenRange>) -> Vec<AddrPfxRange> {
+    let mut ranges: BTreeSet<LenRange> = ranges.into_iter().collect();
+    loop {
+        remove_covered(&mut ranges);
+        if !merge_siblings(&mut ranges) {
+            break;
+        }
+    }
+    ranges.into_iter().map(LenRange::to_range).collect()
+}
+
+fn remove_covered(ranges: &mut BTreeSet<LenRange>) {
+    let mut by_prefix: HashMap<IpNet, Vec<LenRange>> = HashMap::new();
+    for range in ranges.iter() {
+        by_prefix.entry(range.prefix).or_default().push(*range);
+    }
+    ranges.retain(|range| {
+        let mut prefix = Some(range.prefix);
+        while let Some(p) = prefix {
+            let covered = by_prefix.get(&p).is_some_and(|others| {
+                others
+                    .iter()
+                    .any(|other| other != range && other.covers(range))
+            });
+            if covered {
+                return false;
+            }
+            prefix = p.supernet();
+        }
+        true
+    });
+}
+
+/// Whether any siblings were merged.
+fn merge_siblings(ranges: &mut BTreeSet<LenRange>) -> bool {
+    let mut merged = false;
+    // Longest prefixes first, so merged parents can merge again.
+    let mut candidates: Vec<_> = ranges.iter().copied().collect();
+    candidates.sort_unstable_by_key(|range| std::cmp::Reverse(range.prefix.prefix_len()));
+    for range in candidates {
+        if !ranges.contains(&range) {
+            continue;
+        }
+        let Some(parent) = range.prefix.supernet() else {
+            continue;
+        };
+        let Some(sibling) = parent
+            .subnets(range.prefix.prefix_len())
+            .into_iter()
+            .flatten()
+            .find(|subnet| *subnet != range.prefix)
+        else {
+            continue;
+        };
+        let sibling = LenRange {
+            prefix: sibling,
+            ..range
+        };
+        if ranges.remove(&sibling) {
+            ranges.remove(&range);
+            ranges.insert(LenRange {
+                prefix: parent,
+                ..range
+            });
+            merged = true;
+        }
+    }
+    merged
+}
+
+impl QueryIr {
+    /// Compile `filter` into an explicit, aggregated [`PrefixList`],
+    /// resolving AS numbers, as-sets, route-sets, and filter-sets.
+    /// `peer_as` is what `PeerAS` stands for, if known.
+    ///
+    /// AS numbers without route objects contribute no prefix, as in `bgpq3`;
+    /// cyclic set references are skipped.
+    pub fn prefix_list(
+        &self,
+        filter: &Filter,
+        peer_as: Option<u32>,
+    ) -> Result<PrefixList, PrefixListError> {
+        let mut ranges = Vec::new();
+        self.compile_filter(filter, peer_as, &mut HashSet::new(), &mut ranges)?;
+        let (ipv4, ipv6): (Vec<_>, Vec<_>) = ranges
+            .into_iter()
+            .partition(|range| matches!(range.prefix, IpNet::V4(_)));
+        Ok(PrefixList {
+            ipv4: aggregate(ipv4),
+            ipv6: aggregate(ipv6),
+        })
+    }
+
+    /// `visited` holds the filter-sets and route-sets being resolved.
+    fn compile_filter(
+        &self,
+        filter: &Filter,
+        peer_as: Option<u32>,
+        visited: &mut HashSet<String>,
+        ranges: &mut Vec<LenRange>,
+    ) -> Result<(), PrefixListError> {
+        match filter {
+            Filter::FilterSet(name) => {
+                let filter_set = self
+                    .filter_sets
+                    .get(name)
+                    .ok_or_else(|| PrefixListError::UnrecordedFilterSet(name.into()))?;
+                if visited.insert(name.clone()) {
+                    for filter in &filter_set.filters {
+                        self.compile_filter(filter, peer_as, visited, ranges)?;
+                    }
+                    visited.remove(name);
+                }
+            }
+            Filter::Any => {
+                for prefix in [
+                    IpNet::V4(Ipv4Net::new(Ipv4Addr::UNSPECIFIED, 0).expect("valid length")),
+                    IpNet::V6(Ipv6Net::new(Ipv6Addr::UNSPECIFIED, 0).expect("valid length")),
+                ] {
+                    ranges.extend(LenRange::new(prefix, RangeOperator::Plus));
+                }
+            }
+            Filter::AddrPrefixSet(prefixes) => ranges.extend(
+                prefixes
+                    .iter()
+                    .filter_map(|p| LenRange::new(p.address_prefix, p.range_operator)),
+            ),
+            Filter::RouteSet(name, op) => {
+                self.compile_route_set(name, &outers_of(*op, &[]), visited, ranges)?
+            }
+            Filter::AsNum(num, op) => self.compile_as_num(*num, *op, &[], ranges),
+            Filter::AsSet(name, op) => self.compile_as_set(name, *op, &[], ranges)?,
+            Filter::PeerAS => {
+                let num = peer_as.ok_or(PrefixListError::PeerAS)?;
+                self.compile_as_num(num, RangeOperator::NoOp, &[], ranges);
+            }
+            Filter::Or { left, right } => {
+                self.compile_filter(left, peer_as, visited, ranges)?;
+                self.compile_filter(right, peer_as, visited, ranges)?;
+            }
+            Filter::Group(filter) => self.compile_filter(filter, peer_as, visited, ranges)?,
+            Filter::And { .. } => return Err(PrefixListError::And),
+            Filter::Not(_) => return Err(PrefixListError::Not),
+            Filter::AsPathRE(expr) => return Err(PrefixListError::AsPathRE(expr.into())),
+            Filter::Community(call) => return Err(PrefixListError::Community(call.clone())),
+            Filter::Unknown(unknown) => return Err(PrefixListError::Unknown(unknown.into())),
+        }
+        Ok(())
+    }
+
+    /// `outers` are the range operators of the route sets, innermost first.
+    fn compile_route_set(
+        &self,
+        name: &str,
+        outers: &[RangeOperator],
+        visited: &mut HashSet<String>,
+        ranges: &mut Vec<LenRange>,
+    ) -> Result<(), PrefixListError> {
+        let route_set = self
+            .route_sets
+            .get(name)
+            .ok_or_else(|| PrefixListError::UnrecordedRouteSet(name.into()))?;
+        if !visited.insert(name.into()) {
+            return Ok(());
+        }
+        for member in &route_set.members {
+            match member {
+                RouteSetMember::RSRange(prefix) => ranges.extend(
+                    prefix
+                        .range_operator
+                        .compose_all(outers, &prefix.address_prefix)
+                        .and_then(|op| LenRange::new(prefix.address_prefix, op)),
+                ),
+                RouteSetMember::NameOp(name, op) => {
+                    self.compile_route_set(name, &outers_of(*op, outers), visited, ranges)?
+                }
+                RouteSetMember::AsNum(num, op) => self.compile_as_num(*num, *op, outers, ranges),
+                RouteSetMember::AsSet(name, op) => {
+                    self.compile_as_set(name, *op, outers, ranges)?
+                }
+            }
+        }
+        visited.remove(name);
+        Ok(())
+    }
+
+    fn compile_as_num(
+        &self,
+        num: u32,
+        op: RangeOperator,
+        outers: &[RangeOperator],
+        ranges: &mut Vec<LenRange>,
+    ) {
+        let Some(routes) = self.as_routes.get(&num) else {
+            return;
+        };
+        ranges.extend(routes.iter().filter_map(|route| {
+            let op = op.compose_all(outers, route)?;
+            LenRange::new(*route, op)
+        }));
+    }
+
+    fn compile_as_set(
+        &self,
+        name: &str,
+        op: RangeOperator,
+        outers: &[RangeOperator],
+        ranges: &mut Vec<LenRange>,
+    ) -> Result<(), PrefixListError> {
+        let as_set = self
+            .as_sets
+            .get(name)
+            .ok_or_else(|| PrefixListError::UnrecordedAsSet(name.into()))?;
+        if let Some(member) = as_set.unrecorded_members.first() {
+            return Err(PrefixListError::UnrecordedAsSet(member.clone()));
+        }
+        if as_set.is_any {
+            return self.compile_filter(&Filter::Any, None, &mut HashSet::new(), ranges);
+        }
+        let mut members: Vec<_> = as_set.members.iter().copied().collect();
+        members.sort_unstable();
+        for num in members {
+            self.compile_as_num(num, op, outers, ranges);
+        }
+        Ok(())
+    }
+}
+
+/// Range operators applied to a route set with `op`, within route sets
+/// with `outers`, innermost first.
+fn outers_of(op: RangeOperator, outers: &[RangeOperator]) -> Vec<RangeOperator> {
+    let inner = (op != RangeOperator::NoOp).then_some(op);
+    inner.into_iter().chain(outers.iter().copied()).collect()
+}
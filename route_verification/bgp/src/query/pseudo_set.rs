@@ -2,14 +2,14 @@ use super::*;
 
 /// Extract customers for each AutNum into a set under the name `c#{aut_num}`.
 pub fn make_customer_pseudo_set(db: &AsRelDb) -> BTreeMap<String, AsSet> {
-    db.source2dest
+    db.source2dest()
         .par_iter()
         .fold(
             BTreeMap::<_, Vec<_>>::new,
             |mut result, ((from, to), relationship)| {
                 match *relationship {
                     P2C => result.entry(*from).or_default().push(*to),
-                    P2P => (),
+                    P2P | S2S => (),
                     C2P => result.entry(*to).or_default().push(*from),
                 }
                 result
@@ -36,3 +36,40 @@ pub fn make_customer_pseudo_set(db: &AsRelDb) -> BTreeMap<String, AsSet> {
 pub fn customer_set(aut_num: u32) -> String {
     format!("c#{aut_num}")
 }
+
+/// Extract the customer cone of each AutNum with customers into a set under
+/// the name `cc#{aut_num}`, excluding the AutNum itself.
+pub fn make_customer_cone_pseudo_set(db: &AsRelDb) -> BTreeMap<String, AsSet> {
+    let providers: Vec<_> = db
+        .source2dest()
+        .iter()
+        .filter_map(|((from, to), relationship)| match relationship {
+            P2C => Some(*from),
+            P2P | S2S => None,
+            C2P => Some(*to),
+        })
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    providers
+        .into_par_iter()
+        .map(|aut_num| {
+            let mut cone: Vec<_> = db
+                .customer_cone(aut_num)
+                .iter()
+                .copied()
+                .filter(|customer| *customer != aut_num)
+                .collect();
+            cone.sort_unstable();
+            (
+                customer_cone_set(aut_num),
+                AsSet::new("".into(), cone, vec![]),
+            )
+        })
+        .collect()
+}
+
+/// Name of the customer cone pseudo set corresponding to `aut_num`.
+pub fn customer_cone_set(aut_num: u32) -> String {
+    format!("cc#{aut_num}")
+}
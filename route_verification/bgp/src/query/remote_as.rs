@@ -0,0 +1,176 @@
+use std::collections::BTreeSet;
+
+use super::*;
+
+/// A set of ASNs, possibly all ASNs except some.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum AsnSet {
+    Only(BTreeSet<u32>),
+    AnyExcept(BTreeSet<u32>),
+}
+
+impl AsnSet {
+    pub fn any() -> Self {
+        Self::AnyExcept(BTreeSet::new())
+    }
+
+    pub fn contains(&self, num: &u32) -> bool {
+        match self {
+            Self::Only(asns) => asns.contains(num),
+            Self::AnyExcept(asns) => !asns.contains(num),
+        }
+    }
+
+    pub fn union(self, other: Self) -> Self {
+        use AsnSet::*;
+        match (self, other) {
+            (Only(mut a), Only(b)) => {
+                a.extend(b);
+                Only(a)
+            }
+            (Only(only), AnyExcept(except)) | (AnyExcept(except), Only(only)) => {
+                AnyExcept(&except - &only)
+            }
+            (AnyExcept(a), AnyExcept(b)) => AnyExcept(&a & &b),
+        }
+    }
+
+    pub fn intersection(self, other: Self) -> Self {
+        use AsnSet::*;
+        match (self, other) {
+            (Only(a), Only(b)) => Only(&a & &b),
+            (Only(only), AnyExcept(except)) | (AnyExcept(except), Only(only)) => {
+                Only(&only - &except)
+            }
+            (AnyExcept(mut a), AnyExcept(b)) => {
+                a.extend(b);
+                AnyExcept(a)
+            }
+        }
+    }
+
+    pub fn difference(self, other: Self) -> Self {
+        self.intersection(other.complement())
+    }
+
+    pub fn complement(self) -> Self {
+        match self {
+            Self::Only(asns) => Self::AnyExcept(asns),
+            Self::AnyExcept(asns) => Self::Only(asns),
+        }
+    }
+}
+
+impl Default for AsnSet {
+    fn default() -> Self {
+        Self::Only(BTreeSet::new())
+    }
+}
+
+/// ASNs an [`AsExpr`] resolves to.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RemoteAsns {
+    pub asns: AsnSet,
+    /// as-sets, peering-sets, and invalid AS names whose ASNs we don't know.
+    pub unrecorded: Vec<AsExpr>,
+}
+
+impl QueryIr {
+    /// Resolve `expr` to the ASNs it stands for.
+    /// Unrecorded as-sets and peering-sets resolve to no ASN,
+    /// so `EXCEPT` may keep ASNs it should have excluded.
+    /// Cyclic peering-set members are skipped.
+    pub fn remote_asns(&self, expr: &AsExpr) -> RemoteAsns {
+        let mut unrecorded = Vec::new();
+        let asns = self.resolve_as_expr(expr, &mut HashSet::new(), &mut unrecorded);
+        clean_vec(&mut unrecorded);
+        RemoteAsns { asns, unrecorded }
+    }
+
+    /// Neighbors `num` declares export rules for if `export`, otherwise
+    /// import rules, or `None` if its aut-num is unrecorded.
+    pub fn declared_neighbors(&self, num: u32, export: bool) -> Option<RemoteAsns> {
+        let aut_num = self.aut_nums.get(&num)?;
+        let policy = match export {
+            true => &aut_num.exports,
+            false => &aut_num.imports,
+        };
+        let mut unrecorded = Vec::new();
+        let mut visited = HashSet::new();
+        let asns = policy
+            .entries_iter()
+            .flat_map(|entry| &entry.mp_peerings)
+            .map(|peering| {
+                let remote_as = &peering.mp_peering.remote_as;
+                self.resolve_as_expr(remote_as, &mut visited, &mut unrecorded)
+            })
+            .fold(AsnSet::default(), AsnSet::union);
+        clean_vec(&mut unrecorded);
+        Some(RemoteAsns { asns, unrecorded })
+    }
+
+    /// `visited` holds the peering-sets being resolved, to skip cyclic members.
+    fn resolve_as_expr(
+        &self,
+        expr: &AsExpr,
+        visited: &mut HashSet<String>,
+        unrecorded: &mut Vec<AsExpr>,
+    ) -> AsnSet {
+        match expr {
+            AsExpr::Single(AsName::Any) => AsnSet::any(),
+            AsExpr::Single(AsName::Num(num)) => AsnSet::Only(BTreeSet::from([*num])),
+            AsExpr::Single(AsName::Set(name)) => match self.as_sets.get(name) {
+                Some(as_set) if as_set.is_any => AsnSet::any(),
+                Some(as_set) => {
+                    unrecorded.extend(
+                        as_set
+                            .unrecorded_members
+                            .iter()
+                            .map(|member| AsExpr::Single(AsName::Set(member.clone()))),
+                    );
+                    AsnSet::Only(as_set.members.iter().copied().collect())
+                }
+                None => {
+                    unrecorded.push(expr.clone());
+                    AsnSet::default()
+                }
+            },
+            AsExpr::Single(AsName::Invalid(_)) => {
+                unrecorded.push(expr.clone());
+                AsnSet::default()
+            }
+            AsExpr::PeeringSet(name) => {
+                let peering_set = match self.peering_sets.get(name) {
+                    Some(peering_set) => peering_set,
+                    None => {
+                        unrecorded.push(expr.clone());
+                        return AsnSet::default();
+                    }
+                };
+                if !visited.insert(name.clone()) {
+                    return AsnSet::default();
+                }
+                let asns = peering_set
+                    .peerings
+                    .iter()
+                    .map(|peering| self.resolve_as_expr(&peering.remote_as, visited, unrecorded))
+                    .fold(AsnSet::default(), AsnSet::union);
+                visited.remove(name);
+                asns
+            }
+            AsExpr::And { left, right } => {
+                let left = self.resolve_as_expr(left, visited, unrecorded);
+                left.intersection(self.resolve_as_expr(right, visited, unrecorded))
+            }
+            AsExpr::Or { left, right } => {
+                let left = self.resolve_as_expr(left, visited, unrecorded);
+                left.union(self.resolve_as_expr(right, visited, unrecorded))
+            }
+            AsExpr::Except { left, right } => {
+                let left = self.resolve_as_expr(left, visited, unrecorded);
+                left.difference(self.resolve_as_expr(right, visited, unrecorded))
+            }
+            AsExpr::Group(expr) => self.resolve_as_expr(expr, visited, unrecorded),
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use super::*;
+
+/// How the route(6) objects in the IRR match a route's prefix and origin.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum RouteObjectState {
+    /// A route object for the prefix and the origin exists.
+    Exact,
+    /// Only route objects for less-specifics of the prefix exist for the
+    /// origin.
+    Covered,
+    /// The most specific prefix with a route object for the origin,
+    /// the prefix itself or a less-specific, also has route objects for
+    /// these other origins.
+    Moas(Vec<u32>),
+    /// Route objects for the prefix or its less-specifics only exist for
+    /// these other origins.
+    OtherOrigins(Vec<u32>),
+    /// No route object for the prefix or its less-specifics exists.
+    Missing,
+}
+
+impl QueryIr {
+    /// Check `prefix` and `origin` against the route objects in
+    /// `self.as_routes`, indexed by prefix on the first call,
+    /// so `self.as_routes` should not change afterwards.
+    pub fn route_object_state(&self, prefix: &IpNet, origin: u32) -> RouteObjectState {
+        let prefix_origins = self
+            .prefix_origins
+            .get_or_init(|| prefix_origins(&self.as_routes));
+        let mut others = Vec::new();
+        for (index, covering) in covering_prefixes(prefix).enumerate() {
+            let Some(origins) = prefix_origins.get(&covering) else {
+                continue;
+            };
+            if origins.contains(&origin) {
+                let moas: Vec<_> = origins.iter().copied().filter(|o| *o != origin).collect();
+                return match (index, moas.is_empty()) {
+                    (_, false) => RouteObjectState::Moas(moas),
+                    (0, true) => RouteObjectState::Exact,
+                    (_, true) => RouteObjectState::Covered,
+                };
+            }
+            others.extend(origins);
+        }
+        match others.is_empty() {
+            true => RouteObjectState::Missing,
+            false => {
+                clean_vec(&mut others);
+                RouteObjectState::OtherOrigins(others)
+            }
+        }
+    }
+}
+
+/// Origins of each prefix in `as_routes`.
+pub fn prefix_origins(as_routes: &HashMap<u32, Vec<IpNet>>) -> HashMap<IpNet, Vec<u32>> {
+    let mut prefix_origins: HashMap<IpNet, Vec<u32>> = HashMap::new();
+    for (origin, routes) in as_routes {
+        for route in routes {
+            prefix_origins
+                .entry(route.trunc())
+                .or_default()
+                .push(*origin);
+        }
+    }
+    prefix_origins.values_mut().for_each(clean_vec);
+    prefix_origins
+}
+
+/// `prefix` and its less-specifics, most specific first.
+fn covering_prefixes(prefix: &IpNet) -> impl Iterator<Item = IpNet> + '_ {
+    (0..=prefix.prefix_len())
+        .rev()
+        .filter_map(|len| IpNet::new(prefix.addr(), len).ok())
+        .map(|covering| covering.trunc())
+}
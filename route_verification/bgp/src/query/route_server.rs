@@ -0,0 +1,74 @@
+use super::*;
+
+/// An IXP route server that does not insert its ASN into AS paths,
+/// so routes through it appear to go directly between its members.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default)]
+pub struct RouteServer {
+    pub asn: u32,
+    /// as-sets listing the ASes peering at the route server,
+    /// e.g., `AS-RS-MEMBERS`.
+    pub member_sets: Vec<String>,
+    /// Member ASes known besides those in `member_sets`.
+    pub members: Vec<u32>,
+}
+
+impl QueryIr {
+    /// Set `self.route_servers` to `route_servers`, keyed by their ASNs.
+    /// Member as-set names are matched case-insensitively against
+    /// `self.as_sets` and respelled as recorded there,
+    /// so call this after the as-sets are loaded.
+    pub fn with_route_servers<I>(self, route_servers: I) -> Self
+    where
+        I: IntoIterator<Item = RouteServer>,
+    {
+        let route_servers = route_servers
+            .into_iter()
+            .map(|mut route_server| {
+                for name in &mut route_server.member_sets {
+                    if let Some(key) = self
+                        .as_sets
+                        .keys()
+                        .find(|key| key.eq_ignore_ascii_case(name))
+                    {
+                        name.clone_from(key);
+                    }
+                }
+                (route_server.asn, route_server)
+            })
+            .collect();
+        Self {
+            route_servers,
+            ..self
+        }
+    }
+
+    /// Route server with ASN `num`, if configured.
+    pub fn route_server_by_asn(&self, num: u32) -> Option<&RouteServer> {
+        self.route_servers.get(&num)
+    }
+
+    /// Route servers with member as-set `name`.
+    pub fn route_servers_by_member_set<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'a RouteServer> + 'a {
+        self.route_servers.values().filter(move |route_server| {
+            route_server
+                .member_sets
+                .iter()
+                .any(|set| set.eq_ignore_ascii_case(name))
+        })
+    }
+
+    /// If `num` peers at `route_server`, according to its configured members
+    /// and member as-sets.
+    pub fn is_route_server_member(&self, route_server: &RouteServer, num: u32) -> bool {
+        route_server.members.contains(&num)
+            || route_server.member_sets.iter().any(|name| {
+                self.as_sets
+                    .get(name)
+                    .is_some_and(|as_set| as_set.contains(&num))
+            })
+    }
+}
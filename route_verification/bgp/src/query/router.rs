@@ -0,0 +1,77 @@
+use std::{collections::BTreeSet, net::IpAddr};
+
+use super::*;
+
+/// IP addresses a [`RouterExpr`] resolves to.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RouterIps {
+    pub ips: BTreeSet<IpAddr>,
+    /// inet-rtrs and rtr-sets whose addresses we don't know.
+    pub unrecorded: Vec<RouterExpr>,
+}
+
+impl QueryIr {
+    /// Resolve `expr` to the interface addresses of the routers it stands for.
+    /// Unrecorded inet-rtrs and rtr-sets resolve to no address,
+    /// so `EXCEPT` may keep addresses it should have excluded.
+    pub fn router_ips(&self, expr: &RouterExpr) -> RouterIps {
+        let mut unrecorded = Vec::new();
+        let ips = self.resolve_router_expr(expr, &mut HashSet::new(), &mut unrecorded);
+        clean_vec(&mut unrecorded);
+        RouterIps { ips, unrecorded }
+    }
+
+    /// `visited` holds the rtr-sets being resolved, to skip cyclic members.
+    fn resolve_router_expr(
+        &self,
+        expr: &RouterExpr,
+        visited: &mut HashSet<String>,
+        unrecorded: &mut Vec<RouterExpr>,
+    ) -> BTreeSet<IpAddr> {
+        match expr {
+            RouterExpr::Ip(ip) => BTreeSet::from([*ip]),
+            RouterExpr::InetRtr(name) => match self.inet_rtrs.get(name) {
+                Some(inet_rtr) => inet_rtr.ifaddrs.iter().copied().collect(),
+                None => {
+                    unrecorded.push(expr.clone());
+                    BTreeSet::new()
+                }
+            },
+            RouterExpr::RtrSet(name) => {
+                let rtr_set = match self.rtr_sets.get(name) {
+                    Some(rtr_set) => rtr_set,
+                    None => {
+                        unrecorded.push(expr.clone());
+                        return BTreeSet::new();
+                    }
+                };
+                if !visited.insert(name.clone()) {
+                    return BTreeSet::new();
+                }
+                let ips = rtr_set
+                    .members
+                    .iter()
+                    .flat_map(|member| self.resolve_router_expr(member, visited, unrecorded))
+                    .collect();
+                visited.remove(name);
+                ips
+            }
+            RouterExpr::And { left, right } => {
+                let left = self.resolve_router_expr(left, visited, unrecorded);
+                let right = self.resolve_router_expr(right, visited, unrecorded);
+                left.intersection(&right).copied().collect()
+            }
+            RouterExpr::Or { left, right } => {
+                let mut left = self.resolve_router_expr(left, visited, unrecorded);
+                left.extend(self.resolve_router_expr(right, visited, unrecorded));
+                left
+            }
+            RouterExpr::Except { left, right } => {
+                let left = self.resolve_router_expr(left, visited, unrecorded);
+                let right = self.resolve_router_expr(right, visited, unrecorded);
+                left.difference(&right).copied().collect()
+            }
+            RouterExpr::Group(expr) => self.resolve_router_expr(expr, visited, unrecorded),
+        }
+    }
+}
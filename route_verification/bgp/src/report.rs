@@ -1,4 +1,9 @@
-use std::ops::{BitAnd, BitOr, BitOrAssign};
+use std::{
+    net::IpAddr,
+    ops::{BitAnd, BitOr, BitOrAssign},
+};
+
+use rpki::{AspaState, RovState};
 
 use super::*;
 
@@ -15,13 +20,19 @@ use {AllReportCase::*, AnyReportCase::*, Report::*};
 /// Composed of a vector of [`ReportItem`]s.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub enum Report {
+    /// `rule` is recorded if `verbosity.record_rule`.
     OkImport {
         from: u32,
         to: u32,
+        #[serde(default)]
+        rule: Option<RuleIndex>,
     },
+    /// `rule` is recorded if `verbosity.record_rule`.
     OkExport {
         from: u32,
         to: u32,
+        #[serde(default)]
+        rule: Option<RuleIndex>,
     },
     SkipImport {
         from: u32,
@@ -47,6 +58,21 @@ pub enum Report {
         from: AsPathEntry,
         to: AsPathEntry,
     },
+    /// Route origin validation against ROAs.
+    /// `origin` is `None` if the AS path ends in an AS set.
+    Rpki {
+        origin: Option<u32>,
+        items: ReportItems,
+    },
+    /// Route objects for the prefix and `origin`.
+    RouteObject {
+        origin: u32,
+        items: ReportItems,
+    },
+    /// ASPA verification of the AS path.
+    Aspa {
+        items: ReportItems,
+    },
     MehImport {
         from: u32,
         to: u32,
@@ -70,6 +96,41 @@ pub enum Report {
 }
 
 impl Report {
+    pub fn rpki(origin: Option<u32>, rov: RovState) -> Self {
+        let item = match rov {
+            RovState::Valid => ReportItem::RpkiValid,
+            RovState::Invalid => ReportItem::RpkiInvalid,
+            RovState::NotFound => ReportItem::RpkiNotFound,
+        };
+        Rpki {
+            origin,
+            items: vec![item],
+        }
+    }
+
+    pub fn route_object(origin: u32, state: RouteObjectState) -> Self {
+        let item = match state {
+            RouteObjectState::Exact => ReportItem::RouteObjectExact,
+            RouteObjectState::Covered => ReportItem::RouteObjectCovered,
+            RouteObjectState::Moas(origins) => ReportItem::RouteObjectMoas(origins),
+            RouteObjectState::OtherOrigins(origins) => ReportItem::RouteObjectOtherOrigins(origins),
+            RouteObjectState::Missing => ReportItem::RouteObjectMissing,
+        };
+        RouteObject {
+            origin,
+            items: vec![item],
+        }
+    }
+
+    pub fn aspa(aspa: AspaState) -> Self {
+        let item = match aspa {
+            AspaState::Valid => ReportItem::AspaValid,
+            AspaState::Invalid => ReportItem::AspaInvalid,
+            AspaState::Unknown => ReportItem::AspaUnknown,
+        };
+        Aspa { items: vec![item] }
+    }
+
     pub fn is_meh(&self) -> bool {
         matches!(
             self,
@@ -92,6 +153,9 @@ pub enum ReportItem {
     // Skip unimplemented.
     SkipAsRegexWithTilde(String),
     SkipAsRegexPathWithSet,
+    /// AS_TRANS (23456) is on the path because a 4-byte AS number could not
+    /// be reconstructed from AS4_PATH.
+    SkipAsTrans,
     SkipCommunityCheckUnimplemented(Box<Call>),
 
     // No policies recorded.
@@ -108,6 +172,8 @@ pub enum ReportItem {
     UnrecordedRouteSet(String),
     UnrecordedPeeringSet(String),
     UnrecordedFilterSet(String),
+    UnrecordedInetRtr(String),
+    UnrecordedRtrSet(String),
 
     // Special cases for ASN filter.
     // Can be repetitive for each import/export.
@@ -127,6 +193,8 @@ pub enum ReportItem {
     // Unique for each import/export.
     /// Route between Tier 1 ASes.
     SpecTier1Pair,
+    /// Route between sibling ASes of the same organization.
+    SpecSiblings,
     /// Route between peers while only provider policies are specified.
     SpecPeerOnlyProviderPolicies,
     /// Import/export from customer while only provider policies are specified.
@@ -138,6 +206,33 @@ pub enum ReportItem {
     SpecUphillTier1,
     /// Route from customer to provider.
     SpecUphill,
+    /// The AS whose policy is checked is not public, e.g., private,
+    /// so any aut-num for it is unlikely to be about the AS on the path.
+    SpecNonPublicAsn(u32, AsnClass),
+    /// The peering is the transparent route server with this ASN, or its
+    /// member as-set, and both ASes of the hop peer at the route server.
+    SpecTransparentRouteServer(u32),
+    /// The site-specific [`SpecialCaseRule`](crate::cmp::SpecialCaseRule)
+    /// with this name applies.
+    SpecCustomRule(String),
+
+    // Route origin validation, as in RFC 6811.
+    RpkiValid,
+    RpkiInvalid,
+    RpkiNotFound,
+    // Route objects in the IRR.
+    RouteObjectExact,
+    RouteObjectCovered,
+    /// Route objects for the matching prefix also exist for these other
+    /// origins.
+    RouteObjectMoas(Vec<u32>),
+    /// Route objects only exist for these other origins.
+    RouteObjectOtherOrigins(Vec<u32>),
+    RouteObjectMissing,
+    // ASPA verification.
+    AspaValid,
+    AspaInvalid,
+    AspaUnknown,
 
     // Match problem.
     MatchFilter,
@@ -149,18 +244,20 @@ pub enum ReportItem {
     MatchRemoteAsSet(String),
     MatchExceptPeeringRight,
     MatchPeering,
+    /// The collector peer's IP address is not in the router expression.
+    MatchRouter(IpAddr),
     MatchRegex(String),
 
     // Invalid RPSL.
     RpslInvalidAsName(String),
+    /// Peering with an AS number that is not public.
+    RpslNonPublicAsn(u32, AsnClass),
     RpslInvalidFilter(String),
     RpslInvalidAsRegex(String),
     RpslUnknownFilter(String),
 
     // Recursion error.
     RecCheckFilter,
-    RecFilterRouteSet(String),
-    RecFilterRouteSetMember(Box<RouteSetMember>),
     RecFilterAsSet(String),
     RecFilterAsName(Box<AsName>),
     RecFilterAnd,
@@ -170,10 +267,12 @@ pub enum ReportItem {
     RecCheckRemoteAs,
     RecRemoteAsName(Box<AsName>),
     RecRemoteAsSet(String),
-    RecRemotePeeringSet(String),
     RecPeeringAnd,
     RecPeeringOr,
     RecPeeringExcept,
+    /// Route sets, filter sets, or peering sets that refer back to
+    /// themselves, from the first set in the cycle back to it.
+    RecSetCycle(Vec<String>),
 }
 
 pub type ReportItems = Vec<ReportItem>;
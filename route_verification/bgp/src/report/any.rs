@@ -64,6 +64,7 @@ pub const fn empty_bad_any_report() -> AnyReport {
     Some(BadAnyReport(vec![]))
 }
 
+#[derive(Clone, Debug)]
 pub enum AnyReportCase {
     SkipAnyReport(ReportItems),
     UnrecAnyReport(ReportItems),
@@ -0,0 +1,134 @@
+//! Generate router configuration that filters routes per RPSL policies,
+//! using the [`PrefixList`]s their filters compile to.
+use std::fmt::Write;
+
+use thiserror::Error;
+
+use super::*;
+
+mod bird;
+mod iosxr;
+mod junos;
+
+pub use {bird::Bird2, iosxr::IosXr, junos::Junos};
+
+/// Router configuration syntax a [`NeighborPolicy`] can be rendered in.
+pub trait ConfigBackend {
+    /// Configuration filtering routes per `policy`,
+    /// accepting those in its prefix list and rejecting the rest.
+    fn render(&self, policy: &NeighborPolicy) -> String;
+}
+
+/// Routes an AS imports from or exports to a neighbor, per its aut-num.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct NeighborPolicy {
+    pub aut_num: u32,
+    pub neighbor: u32,
+    pub export: bool,
+    pub prefix_list: PrefixList,
+}
+
+impl NeighborPolicy {
+    /// Name for the generated policy, e.g., `AS1-IMPORT-AS2`.
+    pub fn name(&self) -> String {
+        let direction = match self.export {
+            true => "EXPORT",
+            false => "IMPORT",
+        };
+        format!("AS{}-{direction}-AS{}", self.aut_num, self.neighbor)
+    }
+
+    /// One-line description of the policy for a comment.
+    pub fn description(&self) -> String {
+        match self.export {
+            true => format!(
+                "Routes AS{} exports to AS{}, generated from RPSL.",
+                self.aut_num, self.neighbor
+            ),
+            false => format!(
+                "Routes AS{} imports from AS{}, generated from RPSL.",
+                self.aut_num, self.neighbor
+            ),
+        }
+    }
+
+    /// All ranges, IPv4 first.
+    pub fn ranges(&self) -> impl Iterator<Item = &AddrPfxRange> {
+        self.prefix_list.ipv4.iter().chain(&self.prefix_list.ipv6)
+    }
+}
+
+/// Why a [`NeighborPolicy`] cannot be generated.
+#[derive(Clone, Debug, Eq, Error, Hash, PartialEq)]
+pub enum ConfigError {
+    #[error("unrecorded aut-num AS{0}")]
+    UnrecordedAutNum(u32),
+    /// The remote AS of a peering refers to unrecorded as-sets or
+    /// peering-sets, or invalid AS names, so whether it includes the
+    /// neighbor is unknown.
+    #[error("peering `{0}` refers to unrecorded sets or invalid AS names")]
+    UnrecordedPeering(AsExpr),
+    #[error(transparent)]
+    PrefixList(#[from] PrefixListError),
+}
+
+impl QueryIr {
+    /// Routes `aut_num` exports to `neighbor` if `export`, otherwise imports
+    /// from `neighbor`, per the unicast entries whose peerings include
+    /// `neighbor`. Actions and router addresses are ignored.
+    ///
+    /// Fails if any of these entries has a filter that
+    /// [`prefix_list`](#method.prefix_list) cannot compile, since dropping
+    /// it would reject routes the policy accepts.
+    /// Also fails if any unicast entry has a peering whose ASNs are not all
+    /// [`recorded`](RemoteAsns::unrecorded), since it may include `neighbor`.
+    pub fn neighbor_policy(
+        &self,
+        aut_num: u32,
+        neighbor: u32,
+        export: bool,
+    ) -> Result<NeighborPolicy, ConfigError> {
+        let policies = self
+            .aut_nums
+            .get(&aut_num)
+            .ok_or(ConfigError::UnrecordedAutNum(aut_num))?;
+        let versions = match export {
+            true => &policies.exports,
+            false => &policies.imports,
+        };
+        let mut prefix_list = PrefixList::default();
+        for (version, casts) in [
+            (PolicyVersion::Any, &versions.any),
+            (PolicyVersion::Ipv4, &versions.ipv4),
+            (PolicyVersion::Ipv6, &versions.ipv6),
+        ] {
+            for entry in casts.any.iter().chain(&casts.unicast) {
+                let mut includes_neighbor = false;
+                for peering_action in &entry.mp_peerings {
+                    let remote_as = &peering_action.mp_peering.remote_as;
+                    let RemoteAsns { asns, unrecorded } = self.remote_asns(remote_as);
+                    if !unrecorded.is_empty() {
+                        return Err(ConfigError::UnrecordedPeering(remote_as.clone()));
+                    }
+                    includes_neighbor |= asns.contains(&neighbor);
+                }
+                if !includes_neighbor {
+                    continue;
+                }
+                let mut entry_list = self.prefix_list(&entry.mp_filter, Some(neighbor))?;
+                match version {
+                    PolicyVersion::Any => {}
+                    PolicyVersion::Ipv4 => entry_list.ipv6.clear(),
+                    PolicyVersion::Ipv6 => entry_list.ipv4.clear(),
+                }
+                prefix_list = prefix_list.union(entry_list);
+            }
+        }
+        Ok(NeighborPolicy {
+            aut_num,
+            neighbor,
+            export,
+            prefix_list,
+        })
+    }
+}
@@ -0,0 +1,52 @@
+use super::*;
+
+/// BIRD 2 `filter`s with a prefix set `define`d per address family.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Bird2;
+
+impl ConfigBackend for Bird2 {
+    fn render(&self, policy: &NeighborPolicy) -> String {
+        // BIRD symbols cannot contain `-`.
+        let name = policy.name().replace('-', "_");
+        let mut config = format!("# {}\n", policy.description());
+        let families = [
+            ("V4", "NET_IP4", &policy.prefix_list.ipv4),
+            ("V6", "NET_IP6", &policy.prefix_list.ipv6),
+        ];
+        // BIRD does not allow empty sets.
+        let families: Vec<_> = families
+            .into_iter()
+            .filter(|(_, _, ranges)| !ranges.is_empty())
+            .collect();
+        for (suffix, _, ranges) in &families {
+            _ = writeln!(config, "define {name}_{suffix} = [");
+            for (index, range) in ranges.iter().enumerate() {
+                let separator = if index + 1 < ranges.len() { "," } else { "" };
+                _ = writeln!(config, "    {}{separator}", bird_range(range));
+            }
+            config.push_str("];\n");
+        }
+        _ = writeln!(config, "filter {name} {{");
+        for (suffix, net_type, _) in &families {
+            _ = writeln!(
+                config,
+                "    if net.type = {net_type} && net ~ {name}_{suffix} then accept;"
+            );
+        }
+        config.push_str("    reject;\n}\n");
+        config
+    }
+}
+
+fn bird_range(range: &AddrPfxRange) -> String {
+    let prefix = range.address_prefix;
+    let (len, max_len) = (prefix.prefix_len(), prefix.max_prefix_len());
+    match range.range_operator {
+        RangeOperator::NoOp => prefix.to_string(),
+        RangeOperator::Plus => format!("{prefix}+"),
+        // BIRD's `-` stands for the less specifics instead.
+        RangeOperator::Minus => format!("{prefix}{{{},{max_len}}}", len + 1),
+        RangeOperator::Num(n) => format!("{prefix}{{{n},{n}}}"),
+        RangeOperator::Range(n, m) => format!("{prefix}{{{n},{m}}}"),
+    }
+}
@@ -0,0 +1,42 @@
+use super::*;
+
+/// Cisco IOS-XR `prefix-set` and `route-policy`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct IosXr;
+
+impl ConfigBackend for IosXr {
+    fn render(&self, policy: &NeighborPolicy) -> String {
+        let name = policy.name();
+        let mut config = format!("! {}\n", policy.description());
+        let ranges: Vec<_> = policy.ranges().collect();
+        if !ranges.is_empty() {
+            _ = writeln!(config, "prefix-set {name}");
+            for (index, range) in ranges.iter().enumerate() {
+                let separator = if index + 1 < ranges.len() { "," } else { "" };
+                _ = writeln!(config, "  {}{separator}", prefix_set_entry(range));
+            }
+            config.push_str("end-set\n!\n");
+        }
+        _ = writeln!(config, "route-policy {name}");
+        if ranges.is_empty() {
+            config.push_str("  drop\n");
+        } else {
+            _ = writeln!(config, "  if destination in {name} then");
+            config.push_str("    pass\n  else\n    drop\n  endif\n");
+        }
+        config.push_str("end-policy\n!\n");
+        config
+    }
+}
+
+fn prefix_set_entry(range: &AddrPfxRange) -> String {
+    let prefix = range.address_prefix;
+    let (len, max_len) = (prefix.prefix_len(), prefix.max_prefix_len());
+    match range.range_operator {
+        RangeOperator::NoOp => prefix.to_string(),
+        RangeOperator::Minus => format!("{prefix} ge {} le {max_len}", len + 1),
+        RangeOperator::Plus => format!("{prefix} le {max_len}"),
+        RangeOperator::Num(n) => format!("{prefix} eq {n}"),
+        RangeOperator::Range(n, m) => format!("{prefix} ge {n} le {m}"),
+    }
+}
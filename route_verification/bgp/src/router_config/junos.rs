@@ -0,0 +1,53 @@
+use super::*;
+
+/// Juniper Junos `policy-options`, with exact prefixes in a `prefix-list` and
+/// the rest as `route-filter`s, since prefix lists cannot hold length ranges.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Junos;
+
+impl ConfigBackend for Junos {
+    fn render(&self, policy: &NeighborPolicy) -> String {
+        let name = policy.name();
+        let (exact, ranges): (Vec<_>, Vec<_>) = policy
+            .ranges()
+            .partition(|range| range.range_operator == RangeOperator::NoOp);
+        let mut config = format!("/* {} */\npolicy-options {{\n", policy.description());
+        if !exact.is_empty() {
+            _ = writeln!(config, "    prefix-list {name} {{");
+            for range in &exact {
+                _ = writeln!(config, "        {};", range.address_prefix);
+            }
+            config.push_str("    }\n");
+        }
+        _ = writeln!(config, "    policy-statement {name} {{");
+        if !exact.is_empty() {
+            config.push_str("        term exact {\n            from {\n");
+            _ = writeln!(config, "                prefix-list {name};");
+            config.push_str("            }\n            then accept;\n        }\n");
+        }
+        if !ranges.is_empty() {
+            config.push_str("        term ranges {\n            from {\n");
+            for range in &ranges {
+                _ = writeln!(
+                    config,
+                    "                route-filter {};",
+                    route_filter(range)
+                );
+            }
+            config.push_str("            }\n            then accept;\n        }\n");
+        }
+        config.push_str("        then reject;\n    }\n}\n");
+        config
+    }
+}
+
+fn route_filter(range: &AddrPfxRange) -> String {
+    let prefix = range.address_prefix;
+    match range.range_operator {
+        RangeOperator::NoOp => format!("{prefix} exact"),
+        RangeOperator::Minus => format!("{prefix} longer"),
+        RangeOperator::Plus => format!("{prefix} orlonger"),
+        RangeOperator::Num(n) => format!("{prefix} prefix-length-range /{n}-/{n}"),
+        RangeOperator::Range(n, m) => format!("{prefix} prefix-length-range /{n}-/{m}"),
+    }
+}
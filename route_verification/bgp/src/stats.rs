@@ -11,15 +11,25 @@ use Report::*;
 pub mod as_;
 pub mod as_pair;
 pub mod route;
+pub mod rule;
 mod up_down_hill;
 
 pub use as_pair::AsPairStats;
 pub use route::{csv_header, RouteStats};
+pub use rule::{RuleHits, RuleRef};
 pub use up_down_hill::UpDownHillStats;
 
 impl Compare {
+    /// Use [`Verbosity::all_stats`], but keep route object checks opt-in.
+    fn use_stats_verbosity(&mut self) {
+        self.verbosity = Verbosity {
+            check_route_object: self.verbosity.check_route_object,
+            ..Verbosity::all_stats()
+        };
+    }
+
     pub fn as_stats(&mut self, query: &QueryIr, db: &AsRelDb, map: &DashMap<u32, RouteStats<u64>>) {
-        self.verbosity = Verbosity::all_stats();
+        self.use_stats_verbosity();
         let reports = self.check_with_relationship(query, db);
         for report in &reports {
             as_::one(map, report);
@@ -42,15 +52,27 @@ impl Compare {
         db: &AsRelDb,
         map: &DashMap<(u32, u32), AsPairStats>,
     ) {
-        self.verbosity = Verbosity::all_stats();
+        self.use_stats_verbosity();
         let reports = self.check_with_relationship(query, db);
         for report in &reports {
             as_pair::one(db, map, report);
         }
     }
 
+    /// Count the hits of the rules accepting the route in `hits`.
+    pub fn rule_stats(&mut self, query: &QueryIr, hits: &RuleHits) {
+        self.verbosity = Verbosity {
+            record_rule: true,
+            ..Verbosity::minimum_all()
+        };
+        let reports = self.check(query);
+        for report in &reports {
+            hits.one(report);
+        }
+    }
+
     pub fn route_stats(&mut self, query: &QueryIr, db: &AsRelDb) -> RouteStats<u16> {
-        self.verbosity = Verbosity::all_stats();
+        self.use_stats_verbosity();
         let reports = self.check_with_relationship(query, db);
         let mut stats = RouteStats::default();
         for report in &reports {
@@ -2,8 +2,8 @@ use super::*;
 
 pub fn one(map: &DashMap<u32, RouteStats<u64>>, report: &Report) {
     match report {
-        OkImport { from: _, to } => map.entry(*to).or_default().import_ok += 1,
-        OkExport { from, to: _ } => map.entry(*from).or_default().export_ok += 1,
+        OkImport { to, .. } => map.entry(*to).or_default().import_ok += 1,
+        OkExport { from, .. } => map.entry(*from).or_default().export_ok += 1,
         SkipImport { from: _, to, items } => {
             let mut entry = map.entry(*to).or_default();
             entry.import_skip += 1;
@@ -44,6 +44,16 @@ pub fn one(map: &DashMap<u32, RouteStats<u64>>, report: &Report) {
             entry.export_meh += 1;
             entry.meh(items);
         }
-        AsPathPairWithSet { from: _, to: _ } => (),
+        RouteObject { origin, items } => map.entry(*origin).or_default().route_object(items),
+        Rpki {
+            origin: Some(origin),
+            items,
+        } => map.entry(*origin).or_default().rpki(items),
+        Rpki {
+            origin: None,
+            items: _,
+        }
+        | Aspa { items: _ }
+        | AsPathPairWithSet { from: _, to: _ } => (),
     }
 }
@@ -7,8 +7,8 @@ pub fn one(db: &AsRelDb, map: &DashMap<(u32, u32), AsPairStats>, report: &Report
     };
 
     match report {
-        OkImport { from, to } => entry(*from, *to).route_stats.import_ok += 1,
-        OkExport { from, to } => entry(*from, *to).route_stats.export_ok += 1,
+        OkImport { from, to, .. } => entry(*from, *to).route_stats.import_ok += 1,
+        OkExport { from, to, .. } => entry(*from, *to).route_stats.export_ok += 1,
         SkipImport { from, to, items } => {
             let mut entry = entry(*from, *to);
             entry.route_stats.import_skip += 1;
@@ -49,7 +49,16 @@ pub fn one(db: &AsRelDb, map: &DashMap<(u32, u32), AsPairStats>, report: &Report
             entry.route_stats.export_meh += 1;
             entry.route_stats.meh(items);
         }
-        AsPathPairWithSet { from: _, to: _ } => (),
+        Rpki {
+            origin: _,
+            items: _,
+        }
+        | RouteObject {
+            origin: _,
+            items: _,
+        }
+        | Aspa { items: _ }
+        | AsPathPairWithSet { from: _, to: _ } => (),
     }
 }
 
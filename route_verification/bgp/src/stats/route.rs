@@ -3,8 +3,8 @@ use ReportItem::*;
 
 pub fn one(stats: &mut RouteStats<u16>, report: &Report) {
     match report {
-        OkImport { from: _, to: _ } => stats.import_ok.inc(),
-        OkExport { from: _, to: _ } => stats.export_ok.inc(),
+        OkImport { .. } => stats.import_ok.inc(),
+        OkExport { .. } => stats.export_ok.inc(),
         SkipImport {
             from: _,
             to: _,
@@ -69,6 +69,8 @@ pub fn one(stats: &mut RouteStats<u16>, report: &Report) {
             stats.export_meh.inc();
             stats.meh(items);
         }
+        RouteObject { origin: _, items } => stats.route_object(items),
+        Rpki { origin: _, items } | Aspa { items } => stats.rpki(items),
         AsPathPairWithSet { from: _, to: _ } => (),
     }
 }
@@ -105,9 +107,21 @@ pub struct RouteStats<T: Inc> {
     pub export_meh: T,
     pub import_err: T,
     pub export_err: T,
+    pub route_object_exact: T,
+    pub route_object_covered: T,
+    pub route_object_moas: T,
+    pub route_object_other_origins: T,
+    pub route_object_missing: T,
+    pub rpki_valid: T,
+    pub rpki_invalid: T,
+    pub rpki_not_found: T,
+    pub aspa_valid: T,
+    pub aspa_invalid: T,
+    pub aspa_unknown: T,
     pub skip_regex_tilde: T,
     pub skip_regex_with_set: T,
     pub skip_community: T,
+    pub skip_as_trans: T,
     pub unrec_import_empty: T,
     pub unrec_export_empty: T,
     pub unrec_filter_set: T,
@@ -119,9 +133,12 @@ pub struct RouteStats<T: Inc> {
     pub unrec_some_as_set_route: T,
     pub unrec_aut_num: T,
     pub unrec_peering_set: T,
+    pub unrec_inet_rtr: T,
+    pub unrec_rtr_set: T,
     pub spec_uphill: T,
     pub spec_uphill_tier1: T,
     pub spec_tier1_pair: T,
+    pub spec_siblings: T,
     pub spec_peer_only_provider_policies: T,
     pub spec_customer_only_provider_policies: T,
     pub spec_other_only_provider_policies: T,
@@ -130,6 +147,9 @@ pub struct RouteStats<T: Inc> {
     pub spec_import_from_neighbor: T,
     pub spec_as_is_origin_but_no_route: T,
     pub spec_as_set_contains_origin_but_no_route: T,
+    pub spec_non_public_asn: T,
+    pub spec_transparent_route_server: T,
+    pub spec_custom_rule: T,
     pub err_filter: T,
     pub err_filter_as_num: T,
     pub err_filter_as_set: T,
@@ -139,8 +159,10 @@ pub struct RouteStats<T: Inc> {
     pub err_remote_as_set: T,
     pub err_except_peering_right: T,
     pub err_peering: T,
+    pub err_router: T,
     pub err_regex: T,
     pub rpsl_as_name: T,
+    pub rpsl_non_public_asn: T,
     pub rpsl_filter: T,
     pub rpsl_regex: T,
     pub rpsl_unknown_filter: T,
@@ -153,6 +175,7 @@ impl<T: Inc> RouteStats<T> {
             match item {
                 SkipAsRegexWithTilde(_) => self.skip_regex_tilde.inc(),
                 SkipAsRegexPathWithSet => self.skip_regex_with_set.inc(),
+                SkipAsTrans => self.skip_as_trans.inc(),
                 SkipCommunityCheckUnimplemented(_) => self.skip_community.inc(),
                 _ => (),
             }
@@ -173,6 +196,35 @@ impl<T: Inc> RouteStats<T> {
                 UnrecordedSomeAsSetRoute(_) => self.unrec_some_as_set_route.inc(),
                 UnrecordedAutNum(_) => self.unrec_aut_num.inc(),
                 UnrecordedPeeringSet(_) => self.unrec_peering_set.inc(),
+                UnrecordedInetRtr(_) => self.unrec_inet_rtr.inc(),
+                UnrecordedRtrSet(_) => self.unrec_rtr_set.inc(),
+                _ => (),
+            }
+        }
+    }
+
+    pub fn route_object(&mut self, items: &ReportItems) {
+        for item in items {
+            match item {
+                RouteObjectExact => self.route_object_exact.inc(),
+                RouteObjectCovered => self.route_object_covered.inc(),
+                RouteObjectMoas(_) => self.route_object_moas.inc(),
+                RouteObjectOtherOrigins(_) => self.route_object_other_origins.inc(),
+                RouteObjectMissing => self.route_object_missing.inc(),
+                _ => (),
+            }
+        }
+    }
+
+    pub fn rpki(&mut self, items: &ReportItems) {
+        for item in items {
+            match item {
+                RpkiValid => self.rpki_valid.inc(),
+                RpkiInvalid => self.rpki_invalid.inc(),
+                RpkiNotFound => self.rpki_not_found.inc(),
+                AspaValid => self.aspa_valid.inc(),
+                AspaInvalid => self.aspa_invalid.inc(),
+                AspaUnknown => self.aspa_unknown.inc(),
                 _ => (),
             }
         }
@@ -182,8 +234,12 @@ impl<T: Inc> RouteStats<T> {
         if let Some(item) = items.iter().reduce(|acc, e| if acc < e { acc } else { e }) {
             match item {
                 SpecUphill => self.spec_uphill.inc(),
+                SpecNonPublicAsn(_, _) => self.spec_non_public_asn.inc(),
+                SpecTransparentRouteServer(_) => self.spec_transparent_route_server.inc(),
+                SpecCustomRule(_) => self.spec_custom_rule.inc(),
                 SpecUphillTier1 => self.spec_uphill_tier1.inc(),
                 SpecTier1Pair => self.spec_tier1_pair.inc(),
+                SpecSiblings => self.spec_siblings.inc(),
                 SpecPeerOnlyProviderPolicies => self.spec_peer_only_provider_policies.inc(),
                 SpecCustomerOnlyProviderPolicies => self.spec_customer_only_provider_policies.inc(),
                 SpecOtherOnlyProviderPolicies => self.spec_other_only_provider_policies.inc(),
@@ -211,27 +267,17 @@ impl<T: Inc> RouteStats<T> {
                 MatchRemoteAsSet(_) => self.err_remote_as_set.inc(),
                 MatchExceptPeeringRight => self.err_except_peering_right.inc(),
                 MatchPeering => self.err_peering.inc(),
+                MatchRouter(_) => self.err_router.inc(),
                 MatchRegex(_) => self.err_regex.inc(),
                 RpslInvalidAsName(_) => self.rpsl_as_name.inc(),
+                RpslNonPublicAsn(_, _) => self.rpsl_non_public_asn.inc(),
                 RpslInvalidFilter(_) => self.rpsl_filter.inc(),
                 RpslInvalidAsRegex(_) => self.rpsl_regex.inc(),
                 RpslUnknownFilter(_) => self.rpsl_unknown_filter.inc(),
-                RecCheckFilter
-                | RecFilterRouteSet(_)
-                | RecFilterRouteSetMember(_)
-                | RecFilterAsSet(_)
-                | RecFilterAsName(_)
-                | RecFilterAnd
-                | RecFilterOr
-                | RecFilterNot
-                | RecCheckSetMember(_)
-                | RecCheckRemoteAs
-                | RecRemoteAsName(_)
-                | RecRemoteAsSet(_)
-                | RecRemotePeeringSet(_)
-                | RecPeeringAnd
-                | RecPeeringOr
-                | RecPeeringExcept => self.recursion.inc(),
+                RecCheckFilter | RecFilterAsSet(_) | RecFilterAsName(_) | RecFilterAnd
+                | RecFilterOr | RecFilterNot | RecCheckSetMember(_) | RecCheckRemoteAs
+                | RecRemoteAsName(_) | RecRemoteAsSet(_) | RecPeeringAnd | RecPeeringOr
+                | RecPeeringExcept | RecSetCycle(_) => self.recursion.inc(),
                 _ => (),
             }
         }
@@ -249,9 +295,21 @@ impl<T: Inc> RouteStats<T> {
             export_meh,
             import_err,
             export_err,
+            route_object_exact,
+            route_object_covered,
+            route_object_moas,
+            route_object_other_origins,
+            route_object_missing,
+            rpki_valid,
+            rpki_invalid,
+            rpki_not_found,
+            aspa_valid,
+            aspa_invalid,
+            aspa_unknown,
             skip_regex_tilde,
             skip_regex_with_set,
             skip_community,
+            skip_as_trans,
             unrec_import_empty,
             unrec_export_empty,
             unrec_filter_set,
@@ -263,9 +321,12 @@ impl<T: Inc> RouteStats<T> {
             unrec_some_as_set_route,
             unrec_aut_num,
             unrec_peering_set,
+            unrec_inet_rtr,
+            unrec_rtr_set,
             spec_uphill,
             spec_uphill_tier1,
             spec_tier1_pair,
+            spec_siblings,
             spec_peer_only_provider_policies,
             spec_customer_only_provider_policies,
             spec_other_only_provider_policies,
@@ -274,6 +335,9 @@ impl<T: Inc> RouteStats<T> {
             spec_import_from_neighbor,
             spec_as_is_origin_but_no_route,
             spec_as_set_contains_origin_but_no_route,
+            spec_non_public_asn,
+            spec_transparent_route_server,
+            spec_custom_rule,
             err_filter,
             err_filter_as_num,
             err_filter_as_set,
@@ -283,8 +347,10 @@ impl<T: Inc> RouteStats<T> {
             err_remote_as_set,
             err_except_peering_right,
             err_peering,
+            err_router,
             err_regex,
             rpsl_as_name,
+            rpsl_non_public_asn,
             rpsl_filter,
             rpsl_regex,
             rpsl_unknown_filter,
@@ -301,9 +367,21 @@ impl<T: Inc> RouteStats<T> {
             export_meh,
             import_err,
             export_err,
+            route_object_exact,
+            route_object_covered,
+            route_object_moas,
+            route_object_other_origins,
+            route_object_missing,
+            rpki_valid,
+            rpki_invalid,
+            rpki_not_found,
+            aspa_valid,
+            aspa_invalid,
+            aspa_unknown,
             skip_regex_tilde,
             skip_regex_with_set,
             skip_community,
+            skip_as_trans,
             unrec_import_empty,
             unrec_export_empty,
             unrec_filter_set,
@@ -315,9 +393,12 @@ impl<T: Inc> RouteStats<T> {
             unrec_some_as_set_route,
             unrec_aut_num,
             unrec_peering_set,
+            unrec_inet_rtr,
+            unrec_rtr_set,
             spec_uphill,
             spec_uphill_tier1,
             spec_tier1_pair,
+            spec_siblings,
             spec_peer_only_provider_policies,
             spec_customer_only_provider_policies,
             spec_other_only_provider_policies,
@@ -326,6 +407,9 @@ impl<T: Inc> RouteStats<T> {
             spec_import_from_neighbor,
             spec_as_is_origin_but_no_route,
             spec_as_set_contains_origin_but_no_route,
+            spec_non_public_asn,
+            spec_transparent_route_server,
+            spec_custom_rule,
             err_filter,
             err_filter_as_num,
             err_filter_as_set,
@@ -335,8 +419,10 @@ impl<T: Inc> RouteStats<T> {
             err_remote_as_set,
             err_except_peering_right,
             err_peering,
+            err_router,
             err_regex,
             rpsl_as_name,
+            rpsl_non_public_asn,
             rpsl_filter,
             rpsl_regex,
             rpsl_unknown_filter,
@@ -359,9 +445,21 @@ pub fn csv_header() -> String {
             export_meh,
             import_err,
             export_err,
+            route_object_exact,
+            route_object_covered,
+            route_object_moas,
+            route_object_other_origins,
+            route_object_missing,
+            rpki_valid,
+            rpki_invalid,
+            rpki_not_found,
+            aspa_valid,
+            aspa_invalid,
+            aspa_unknown,
             skip_regex_tilde,
             skip_regex_with_set,
             skip_community,
+            skip_as_trans,
             unrec_import_empty,
             unrec_export_empty,
             unrec_filter_set,
@@ -373,9 +471,12 @@ pub fn csv_header() -> String {
             unrec_some_as_set_route,
             unrec_aut_num,
             unrec_peering_set,
+            unrec_inet_rtr,
+            unrec_rtr_set,
             spec_uphill,
             spec_uphill_tier1,
             spec_tier1_pair,
+            spec_siblings,
             spec_peer_only_provider_policies,
             spec_customer_only_provider_policies,
             spec_other_only_provider_policies,
@@ -384,6 +485,9 @@ pub fn csv_header() -> String {
             spec_import_from_neighbor,
             spec_as_is_origin_but_no_route,
             spec_as_set_contains_origin_but_no_route,
+            spec_non_public_asn,
+            spec_transparent_route_server,
+            spec_custom_rule,
             err_filter,
             err_filter_as_num,
             err_filter_as_set,
@@ -393,8 +497,10 @@ pub fn csv_header() -> String {
             err_remote_as_set,
             err_except_peering_right,
             err_peering,
+            err_router,
             err_regex,
             rpsl_as_name,
+            rpsl_non_public_asn,
             rpsl_filter,
             rpsl_regex,
             rpsl_unknown_filter,
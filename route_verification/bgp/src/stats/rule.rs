@@ -0,0 +1,81 @@
+use super::*;
+
+/// An import or export rule of an aut-num.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct RuleRef {
+    pub aut_num: u32,
+    pub export: bool,
+    pub rule: RuleIndex,
+}
+
+/// Number of routes each rule accepted, aggregated over [`Report`]s with
+/// [`RuleIndex`]es recorded, e.g., from [`Compare::rule_stats`].
+#[derive(Clone, Debug, Default)]
+pub struct RuleHits {
+    pub hits: DashMap<RuleRef, u64>,
+}
+
+impl RuleHits {
+    /// Count the rule in `report` if it is a successful import or export.
+    pub fn one(&self, report: &Report) {
+        let rule_ref = match report {
+            OkImport {
+                to,
+                rule: Some(rule),
+                ..
+            } => RuleRef {
+                aut_num: *to,
+                export: false,
+                rule: *rule,
+            },
+            OkExport {
+                from,
+                rule: Some(rule),
+                ..
+            } => RuleRef {
+                aut_num: *from,
+                export: true,
+                rule: *rule,
+            },
+            _ => return,
+        };
+        *self.hits.entry(rule_ref).or_default() += 1;
+    }
+
+    pub fn get(&self, rule_ref: &RuleRef) -> u64 {
+        self.hits.get(rule_ref).map_or(0, |hits| *hits)
+    }
+
+    /// Rules with hits, the most hit first.
+    pub fn ranked(&self) -> Vec<(RuleRef, u64)> {
+        let mut ranked: Vec<_> = self
+            .hits
+            .iter()
+            .map(|entry| (*entry.key(), *entry.value()))
+            .collect();
+        ranked.sort_unstable_by(|(a, a_hits), (b, b_hits)| b_hits.cmp(a_hits).then(a.cmp(b)));
+        ranked
+    }
+
+    /// Rules of the aut-nums in `query` that never accepted a route, sorted.
+    pub fn dead_rules(&self, query: &QueryIr) -> Vec<RuleRef> {
+        let mut dead: Vec<_> = query
+            .aut_nums
+            .iter()
+            .flat_map(|(&aut_num, an)| {
+                [(false, &an.imports), (true, &an.exports)]
+                    .into_iter()
+                    .flat_map(move |(export, policy)| {
+                        RuleIndex::all(policy).map(move |(rule, _)| RuleRef {
+                            aut_num,
+                            export,
+                            rule,
+                        })
+                    })
+            })
+            .filter(|rule_ref| !self.hits.contains_key(rule_ref))
+            .collect();
+        dead.sort_unstable();
+        dead
+    }
+}
@@ -6,5 +6,43 @@ use super::{stats::*, *};
 
 mod as_property;
 pub mod cmp;
+mod confed;
+mod explain;
+mod impact;
+mod infer;
+mod leak;
+mod non_public_asn;
+mod prefix_list;
 mod psedo_set;
 mod query;
+mod remote_as;
+mod route_object;
+mod route_server;
+mod route_set_member;
+mod router;
+mod router_config;
+mod rpki;
+mod rule;
+mod set_cycle;
+mod special_case;
+mod what_if;
+
+fn num(n: u32) -> AsExpr {
+    AsExpr::Single(AsName::Num(n))
+}
+
+/// Entry with a single peering to or from `remote_as` and `filter`.
+fn entry(remote_as: AsExpr, filter: Filter) -> Entry {
+    Entry {
+        mp_peerings: vec![PeeringAction {
+            mp_peering: Peering {
+                remote_as,
+                remote_router: None,
+                local_router: None,
+            },
+            actions: Default::default(),
+        }],
+        mp_filter: filter,
+        span: None,
+    }
+}
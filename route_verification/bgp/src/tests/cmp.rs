@@ -138,10 +138,12 @@ fn expected_ok_skip_checks() -> [Vec<Report>; 3] {
             OkExport {
                 from: 33549,
                 to: 6939,
+                rule: None,
             },
             OkImport {
                 from: 33549,
                 to: 6939,
+                rule: None,
             },
             UnrecExport {
                 from: 6939,
@@ -163,6 +165,7 @@ fn expected_ok_skip_checks() -> [Vec<Report>; 3] {
             OkImport {
                 from: 60351,
                 to: 196763,
+                rule: None,
             },
             MehExport {
                 from: 196763,
@@ -196,7 +199,7 @@ fn stats() -> Result<()> {
         let map = DashMap::new();
         let mut compare = Compare::with_line_dump(line)?;
         compare.as_stats(&query, &db, &map);
-        let actual = HashMap::from_iter(map.into_iter());
+        let actual = HashMap::from_iter(map);
         assert_eq!(expected, actual);
     }
     Ok(())
@@ -0,0 +1,48 @@
+use super::*;
+
+use {AsPathEntry::*, Report::*, ReportItem::*};
+
+const LINE: &str = "TABLE_DUMP2|1619481601|B|94.156.252.18|1|10.0.0.0/8|1 (65001 65002) [65003] 2 23456 3|IGP|94.156.252.18|0|0||NAG|||";
+
+#[test]
+fn strip_confed_segments() -> Result<()> {
+    let cmp = Compare::with_line_dump(LINE)?;
+    assert_eq!(cmp.as_path, [Seq(1), Seq(2), Seq(23456), Seq(3)]);
+
+    let as_path = map::parse_as_path("1 (65001) 1 2")?;
+    let cmp = Compare::new("10.0.0.0/8".parse()?, as_path);
+    assert_eq!(cmp.as_path, [Seq(1), Seq(2)]);
+    Ok(())
+}
+
+#[test]
+fn skip_as_trans_hops() -> Result<()> {
+    let cmp = Compare::with_line_dump(LINE)?.verbosity(Verbosity {
+        stop_at_first: false,
+        show_skips: true,
+        ..Verbosity::default()
+    });
+    let skips: Vec<_> = [(3, 23456), (23456, 2)]
+        .into_iter()
+        .flat_map(|(from, to)| {
+            [
+                SkipExport {
+                    from,
+                    to,
+                    items: vec![SkipAsTrans],
+                },
+                SkipImport {
+                    from,
+                    to,
+                    items: vec![SkipAsTrans],
+                },
+            ]
+        })
+        .collect();
+    let actual = cmp.check(&QueryIr::default());
+    assert_eq!(actual[..4], skips);
+
+    let cmp = cmp.verbosity(Verbosity::default());
+    assert!(cmp.check(&QueryIr::default()).is_empty());
+    Ok(())
+}
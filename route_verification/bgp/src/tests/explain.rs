@@ -0,0 +1,186 @@
+use super::*;
+
+use {AsPathEntry::Seq, ReportItem::*};
+
+/// AS1 imports from AS3 in its first entry, and imports from AS2 only what
+/// is not `ANY` in its second entry.
+fn explain() -> Result<Vec<Explanation>> {
+    let mut query = QueryIr::default();
+    let mut aut_num = AutNum::default();
+    let imports = &mut aut_num.imports.ipv4.unicast;
+    imports.push(entry(num(3), Filter::Any));
+    let filter = Filter::And {
+        left: Box::new(Filter::Any),
+        right: Box::new(Filter::Not(Box::new(Filter::Any))),
+    };
+    imports.push(entry(num(2), filter));
+    query.aut_nums.insert(1, aut_num);
+    let cmp = Compare::new("10.0.0.0/8".parse()?, vec![Seq(1), Seq(2)]);
+    Ok(cmp.explain(&query))
+}
+
+#[test]
+fn explain_tree() -> Result<()> {
+    let explanations = explain()?;
+    let [export, import] = &explanations[..] else {
+        panic!("expected an export and an import, got {explanations:?}");
+    };
+
+    let export_node = ExplainNode::Policy {
+        from: 2,
+        to: 1,
+        export: true,
+    };
+    assert_eq!(export.node, export_node);
+    assert_eq!(export.verdict, Verdict::Unrec);
+    assert_eq!(export.items, [UnrecordedAutNum(2)]);
+
+    assert_eq!(import.verdict, Verdict::Bad);
+    let [version] = &import.children[..] else {
+        panic!("expected only ipv4, got {:?}", import.children);
+    };
+    assert_eq!(version.node, ExplainNode::Version(PolicyVersion::Ipv4));
+    let [cast] = &version.children[..] else {
+        panic!("expected only unicast, got {:?}", version.children);
+    };
+    assert_eq!(cast.node, ExplainNode::Cast(PolicyCast::Unicast));
+    let [entry0, entry1] = &cast.children[..] else {
+        panic!("expected two entries, got {:?}", cast.children);
+    };
+
+    assert_eq!(entry0.node, ExplainNode::Entry(0));
+    assert_eq!(entry0.verdict, Verdict::Bad);
+    assert_eq!(entry0.items, [MatchRemoteAsNum(3)]);
+    // The filter is not checked once the peering does not match.
+    let [peering] = &entry0.children[..] else {
+        panic!("expected only a peering, got {:?}", entry0.children);
+    };
+    assert_eq!(peering.verdict, Verdict::Bad);
+    let [remote_as] = &peering.children[..] else {
+        panic!("expected one as-expression, got {:?}", peering.children);
+    };
+    assert_eq!(remote_as.node, ExplainNode::RemoteAs("AS3".into()));
+
+    // The peering of the second entry matches, but the filter does not,
+    // because of its right-hand side.
+    assert_eq!(entry1.verdict, Verdict::Bad);
+    let [peering, filter] = &entry1.children[..] else {
+        panic!("expected a peering and a filter, got {:?}", entry1.children);
+    };
+    assert_eq!(peering.verdict, Verdict::Ok);
+    assert_eq!(filter.node, ExplainNode::Filter("ANY AND NOT ANY".into()));
+    assert_eq!(filter.verdict, Verdict::Bad);
+    let verdicts: Vec<_> = filter.children.iter().map(|c| c.verdict).collect();
+    assert_eq!(verdicts, [Verdict::Ok, Verdict::Bad]);
+    Ok(())
+}
+
+#[test]
+fn explain_render() -> Result<()> {
+    let explanations = explain()?;
+    let text = explanations[1].to_text();
+    assert!(text.starts_with("import AS1 <- AS2: Bad"));
+    assert!(text.contains("\n      entry 0: Bad [MatchRemoteAsNum(3)]\n"));
+    assert!(text.contains("\n        peering 0 AS3: Bad [MatchRemoteAsNum(3)]\n"));
+    assert!(text.contains("\n          filter NOT ANY: Bad [MatchFilter]\n"));
+
+    let json = explanations[1].to_json()?;
+    let parsed: Explanation = serde_json::from_str(&json)?;
+    assert_eq!(parsed, explanations[1]);
+    Ok(())
+}
+
+/// Verdicts of the explanations and the reports of [`Compare::check`] on a
+/// path from `from` to AS1, which imports from anyone if `imports`.
+fn explain_and_check(from: u32, imports: bool) -> Result<(Vec<Verdict>, Vec<Report>)> {
+    let mut query = QueryIr::default();
+    let mut aut_num = AutNum::default();
+    if imports {
+        let entry = entry(num(from), Filter::Any);
+        aut_num.imports.any.any.push(entry);
+    }
+    query.aut_nums.insert(1, aut_num);
+    let cmp = Compare::new("10.0.0.0/8".parse()?, vec![Seq(1), Seq(from)])
+        .verbosity(Verbosity::minimum_all());
+    let verdicts = cmp.explain(&query).iter().map(|e| e.verdict).collect();
+    Ok((verdicts, cmp.check(&query)))
+}
+
+#[test]
+fn explain_as_trans() -> Result<()> {
+    let (verdicts, reports) = explain_and_check(AS_TRANS, true)?;
+    assert_eq!(verdicts, [Verdict::Skip, Verdict::Skip]);
+    let expected = [
+        Report::SkipExport {
+            from: AS_TRANS,
+            to: 1,
+            items: vec![SkipAsTrans],
+        },
+        Report::SkipImport {
+            from: AS_TRANS,
+            to: 1,
+            items: vec![SkipAsTrans],
+        },
+    ];
+    assert_eq!(reports, expected);
+    Ok(())
+}
+
+#[test]
+fn explain_private_asn() -> Result<()> {
+    let (verdicts, reports) = explain_and_check(64512, true)?;
+    assert_eq!(verdicts, [Verdict::Meh, Verdict::Ok]);
+    assert_eq!(
+        reports[0],
+        Report::MehExport {
+            from: 64512,
+            to: 1,
+            items: vec![SpecNonPublicAsn(64512, AsnClass::Private)],
+        }
+    );
+    assert!(matches!(reports[1], Report::OkImport { .. }));
+    Ok(())
+}
+
+#[test]
+fn explain_empty_policy() -> Result<()> {
+    let (verdicts, reports) = explain_and_check(2, false)?;
+    assert_eq!(verdicts, [Verdict::Unrec, Verdict::Unrec]);
+    assert_eq!(
+        reports[1],
+        Report::UnrecImport {
+            from: 2,
+            to: 1,
+            items: vec![UnrecImportEmpty],
+        }
+    );
+    Ok(())
+}
+
+/// [`Compare::check_import`] and [`Compare::check_export`] check the given
+/// aut-num like [`Compare::check_policy`], without the pre-checks.
+#[test]
+fn check_aut_num_policies() -> Result<()> {
+    let mut aut_num = AutNum::default();
+    aut_num.imports.any.any.push(entry(num(2), Filter::Any));
+    let mut query = QueryIr::default();
+    query.aut_nums.insert(1, aut_num.clone());
+    let cmp = Compare::new("10.0.0.0/8".parse()?, vec![Seq(1), Seq(2)])
+        .verbosity(Verbosity::minimum_all());
+    let prev_path = &cmp.as_path[1..];
+
+    let import = cmp.check_import(&query, &aut_num, 2, 1, prev_path);
+    assert_eq!(import, cmp.check_policy(&query, 2, 1, prev_path, false));
+    assert!(matches!(
+        import,
+        Some(Report::OkImport { from: 2, to: 1, .. })
+    ));
+    let export = cmp.check_export(&query, &aut_num, AS_TRANS, 1, prev_path);
+    let expected = Report::UnrecExport {
+        from: AS_TRANS,
+        to: 1,
+        items: vec![UnrecExportEmpty],
+    };
+    assert_eq!(export, Some(expected));
+    Ok(())
+}
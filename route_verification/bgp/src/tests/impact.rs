@@ -0,0 +1,91 @@
+use std::collections::BTreeSet;
+
+use super::*;
+
+use AsPathEntry::Seq;
+
+/// AS1 imports AS-A from AS2, which exports anything to AS1.
+/// AS-A contains AS3, which originates 10.0.0.0/8;
+/// AS4 originates 192.0.2.0/24.
+fn old_ir() -> Result<Ir> {
+    let mut ir = Ir::default();
+    let mut aut_num = AutNum::default();
+    let filter = Filter::AsSet("AS-A".into(), RangeOperator::NoOp);
+    aut_num.imports.any.any.push(entry(num(2), filter));
+    ir.aut_nums.insert(1, aut_num);
+    let mut aut_num = AutNum::default();
+    aut_num.exports.any.any.push(entry(num(1), Filter::Any));
+    ir.aut_nums.insert(2, aut_num);
+    let mut aut_num = AutNum::default();
+    aut_num.imports.any.any.push(entry(num(6), Filter::Any));
+    ir.aut_nums.insert(5, aut_num);
+    ir.as_sets
+        .insert("AS-A".into(), AsSet::new("".into(), vec![3], vec![]));
+    ir.as_routes.insert(3, vec!["10.0.0.0/8".parse()?]);
+    ir.as_routes.insert(4, vec!["192.0.2.0/24".parse()?]);
+    Ok(ir)
+}
+
+/// AS-A contains AS4 instead, AS5's rule moved, and RS-NEW is added.
+fn new_ir() -> Result<Ir> {
+    let mut ir = old_ir()?;
+    ir.as_sets
+        .insert("AS-A".into(), AsSet::new("".into(), vec![4], vec![]));
+    let span = SourceSpan {
+        attr: 2,
+        start_line: 3,
+        end_line: 3,
+    };
+    ir.aut_nums.get_mut(&5).unwrap().imports.any.any[0].span = Some(span);
+    ir.route_sets.insert("RS-NEW".into(), Default::default());
+    Ok(ir)
+}
+
+#[test]
+fn diff() -> Result<()> {
+    let (old, mut new) = (old_ir()?, new_ir()?);
+    let diff = IrDiff::new(&old, &new);
+    let expected = IrDiff {
+        aut_nums: BTreeSet::new(),
+        objects: BTreeSet::from([
+            ObjectName::AsSet("AS-A".into()),
+            ObjectName::RouteSet("RS-NEW".into()),
+        ]),
+    };
+    assert_eq!(diff, expected);
+    let affected = diff.affected_aut_nums(&old.reference_index(), &new.reference_index());
+    assert_eq!(affected, BTreeSet::from([1]));
+
+    new.aut_nums.get_mut(&5).unwrap().imports.any.any[0].mp_filter = Filter::PeerAS;
+    new.aut_nums.remove(&2);
+    assert_eq!(IrDiff::new(&old, &new).aut_nums, BTreeSet::from([2, 5]));
+    Ok(())
+}
+
+#[test]
+fn verdict_transitions() -> Result<()> {
+    let prefix = "10.0.0.0/8".parse()?;
+    let routes = [
+        Compare::new(prefix, vec![Seq(1), Seq(2), Seq(3)]),
+        Compare::new(prefix, vec![Seq(6), Seq(7)]),
+    ];
+    let impact = Impact::analyze(old_ir()?, new_ir()?, &routes);
+    assert_eq!(impact.affected_aut_nums, BTreeSet::from([1]));
+    assert_eq!(impact.n_checked, 1);
+    let transition = Transition {
+        from: 2,
+        to: 1,
+        export: false,
+        old: Verdict::Ok,
+        new: Verdict::Bad,
+    };
+    let expected = vec![RouteImpact {
+        prefix,
+        as_path: vec![Seq(1), Seq(2), Seq(3)],
+        transitions: vec![transition],
+    }];
+    assert_eq!(impact.routes, expected);
+    let expected = BTreeMap::from([(1, BTreeMap::from([((Verdict::Ok, Verdict::Bad), 1)]))]);
+    assert_eq!(impact.per_as, expected);
+    Ok(())
+}
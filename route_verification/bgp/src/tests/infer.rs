@@ -0,0 +1,92 @@
+use super::*;
+
+use as_rel::Relationship;
+use Confidence::*;
+
+fn own() -> Filter {
+    Filter::AsNum(0, RangeOperator::NoOp)
+}
+
+/// `imports` and `exports` are `(neighbor, filter)`.
+fn aut_num(imports: Vec<(u32, Filter)>, exports: Vec<(u32, Filter)>) -> AutNum {
+    let mut aut_num = AutNum::default();
+    for (neighbor, filter) in imports {
+        aut_num.imports.any.any.push(entry(num(neighbor), filter));
+    }
+    for (neighbor, filter) in exports {
+        aut_num.exports.any.any.push(entry(num(neighbor), filter));
+    }
+    aut_num
+}
+
+fn rel(relationship: Relationship, confidence: Confidence) -> Option<InferredRel> {
+    Some(InferredRel {
+        relationship,
+        confidence,
+    })
+}
+
+fn db() -> InferredRelDb {
+    let aut_nums = BTreeMap::from([
+        // AS1 is a provider of AS2 and AS3, and peers with AS4.
+        (
+            1,
+            aut_num(
+                vec![(2, own()), (3, own()), (4, own())],
+                vec![(2, Filter::Any), (3, Filter::Any), (4, own())],
+            ),
+        ),
+        // AS2 agrees.
+        (2, aut_num(vec![(1, Filter::Any)], vec![(1, own())])),
+        // AS3 claims AS1 is its peer.
+        (3, aut_num(vec![(1, own())], vec![(1, own())])),
+        // AS5 only imports ANY from AS6, and exports ANY to both AS6 and AS7.
+        (
+            5,
+            aut_num(
+                vec![(6, Filter::Any)],
+                vec![(6, Filter::Any), (7, Filter::Any)],
+            ),
+        ),
+    ]);
+    InferredRelDb::from_aut_nums(&aut_nums)
+}
+
+#[test]
+fn infer_relationships() {
+    let db = db();
+    assert_eq!(db.get(1, 2), rel(P2C, High));
+    assert_eq!(db.get(2, 1), rel(C2P, High));
+    assert_eq!(db.get(1, 3), rel(P2C, Medium));
+    assert_eq!(db.get(4, 1), rel(P2P, Low));
+    assert_eq!(db.get(5, 6), None);
+    assert_eq!(db.get(5, 7), rel(P2C, Low));
+    assert_eq!(db.len(), 4);
+}
+
+#[test]
+fn diff_against_as_rel_db() -> Result<()> {
+    let as_rel_db = AsRelDb::from_lines(["1|2|-1", "1|3|0", "4|1|-1", "7|5|-1"])?;
+    let expected = vec![
+        RelDisagreement {
+            as1: 1,
+            as2: 3,
+            inferred: rel(P2C, Medium).unwrap(),
+            recorded: P2P,
+        },
+        RelDisagreement {
+            as1: 1,
+            as2: 4,
+            inferred: rel(P2P, Low).unwrap(),
+            recorded: C2P,
+        },
+        RelDisagreement {
+            as1: 5,
+            as2: 7,
+            inferred: rel(P2C, Low).unwrap(),
+            recorded: C2P,
+        },
+    ];
+    assert_eq!(db().diff(&as_rel_db), expected);
+    Ok(())
+}
@@ -0,0 +1,130 @@
+use super::*;
+
+use {AsPathEntry::Seq, Report::*};
+
+const DB_FILE: &str = "1|2|-1
+3|2|-1
+2|7|-1
+4|5|0
+4|6|0
+8|1|-1
+1|9|0
+1|11|-1
+";
+
+/// AS2 exports anything to its provider AS3;
+/// AS4 only exports its own routes to its peer AS5.
+fn query() -> QueryIr {
+    let mut query = QueryIr::default();
+    let mut aut_num = AutNum::default();
+    aut_num.exports.any.any.push(entry(num(3), Filter::Any));
+    query.aut_nums.insert(2, aut_num);
+    let mut aut_num = AutNum::default();
+    let filter = Filter::AsNum(4, RangeOperator::NoOp);
+    aut_num.exports.any.any.push(entry(num(5), filter));
+    query.aut_nums.insert(4, aut_num);
+    query
+        .as_routes
+        .insert(4, vec!["192.0.2.0/24".parse().unwrap()]);
+    query
+}
+
+fn detect_leaks(as_path: &[u32]) -> Result<Vec<LeakReport>> {
+    detect_leaks_in(as_path, &AsRelDb::from_lines(DB_FILE.lines())?)
+}
+
+fn detect_leaks_in(as_path: &[u32], db: &AsRelDb) -> Result<Vec<LeakReport>> {
+    let as_path = as_path.iter().copied().map(Seq).collect();
+    let cmp = Compare::new("10.0.0.0/8".parse()?, as_path);
+    Ok(cmp.detect_leaks(&query(), db))
+}
+
+#[test]
+fn valley_free_paths() -> Result<()> {
+    for as_path in [&[3, 2, 7][..], &[1, 2, 7], &[7, 2, 1], &[10, 2, 7], &[4]] {
+        assert_eq!(detect_leaks(as_path)?, [], "{as_path:?}");
+    }
+    Ok(())
+}
+
+#[test]
+fn leak_permitted_by_rpsl() -> Result<()> {
+    let leaks = detect_leaks(&[3, 2, 1])?;
+    let expected = [LeakReport {
+        from: 1,
+        leaker: 2,
+        to: 3,
+        received: Some(as_rel::Relationship::P2C),
+        sent: Some(as_rel::Relationship::C2P),
+        export: OkExport {
+            from: 2,
+            to: 3,
+            rule: None,
+        },
+    }];
+    assert_eq!(leaks, expected);
+    assert_eq!(leaks[0].permitted_by_rpsl(), Some(true));
+    Ok(())
+}
+
+#[test]
+fn leak_not_permitted_by_rpsl() -> Result<()> {
+    let leaks = detect_leaks(&[5, 4, 6])?;
+    assert_eq!(leaks.len(), 1);
+    let leak = &leaks[0];
+    assert_eq!((leak.from, leak.leaker, leak.to), (6, 4, 5));
+    assert_eq!((leak.received, leak.sent), (Some(P2P), Some(P2P)));
+    assert!(!leak.is_unknown());
+    assert!(matches!(leak.export, BadExport { from: 4, to: 5, .. }));
+    assert_eq!(leak.permitted_by_rpsl(), Some(false));
+    Ok(())
+}
+
+#[test]
+fn leak_by_unrecorded_aut_num() -> Result<()> {
+    let leaks = detect_leaks(&[8, 1, 9])?;
+    let leakers: Vec<_> = leaks.iter().map(|leak| leak.leaker).collect();
+    assert_eq!(leakers, [1]);
+    assert_eq!(leaks[0].permitted_by_rpsl(), None);
+    Ok(())
+}
+
+/// AS10 has no known relationships, and AS8 none with AS4.
+#[test]
+fn unknown_relationships_reported() -> Result<()> {
+    let leaks = detect_leaks(&[3, 2, 10])?;
+    assert_eq!(leaks.len(), 1);
+    let leak = &leaks[0];
+    assert_eq!((leak.from, leak.leaker, leak.to), (10, 2, 3));
+    assert_eq!((leak.received, leak.sent), (None, Some(C2P)));
+    assert!(leak.is_unknown());
+    assert_eq!(leak.permitted_by_rpsl(), Some(true));
+
+    let leaks = detect_leaks(&[5, 4, 8, 6])?;
+    let hops: Vec<_> = leaks
+        .iter()
+        .map(|leak| (leak.from, leak.leaker, leak.to, leak.received, leak.sent))
+        .collect();
+    assert_eq!(hops, [(8, 4, 5, None, Some(P2P)), (6, 8, 4, None, None)]);
+    Ok(())
+}
+
+/// AS2 and AS11 are siblings, so AS2 leaks the route AS11 received from its
+/// provider AS1.
+#[test]
+fn leak_through_siblings() -> Result<()> {
+    let siblings = AsRelDb::from_as2org_lines(["2|x|a|ORG|o|ARIN", "11|x|b|ORG|o|ARIN"])?;
+    let db = AsRelDb::from_lines(DB_FILE.lines())?.overlay(siblings);
+    let leaks = detect_leaks_in(&[3, 2, 11, 1], &db)?;
+    let hops: Vec<_> = leaks
+        .iter()
+        .map(|leak| (leak.from, leak.leaker, leak.to, leak.received, leak.sent))
+        .collect();
+    assert_eq!(hops, [(1, 2, 3, Some(P2C), Some(C2P))]);
+    assert_eq!(leaks[0].permitted_by_rpsl(), Some(true));
+
+    for as_path in [&[7, 2, 11, 1][..], &[3, 2, 11], &[1, 11, 2, 7]] {
+        assert_eq!(detect_leaks_in(as_path, &db)?, [], "{as_path:?}");
+    }
+    Ok(())
+}
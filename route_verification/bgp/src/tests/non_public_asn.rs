@@ -0,0 +1,61 @@
+use super::{set_cycle::*, *};
+
+use {AsPathEntry::Seq, Report::*, ReportItem::*};
+
+#[test]
+fn non_public_asn_in_path() -> Result<()> {
+    let query = query_importing(
+        AsExpr::Single(AsName::Num(64512)),
+        Filter::Any,
+        Ir::default(),
+    );
+    let cmp = Compare {
+        verbosity: Verbosity {
+            show_meh: true,
+            ..Verbosity::minimum_all()
+        },
+        ..Compare::new("10.0.0.0/8".parse()?, vec![Seq(1), Seq(64512)])
+    };
+    // AS1's policy for importing from AS64512 is still checked.
+    let expected = vec![
+        MehExport {
+            from: 64512,
+            to: 1,
+            items: vec![SpecNonPublicAsn(64512, AsnClass::Private)],
+        },
+        OkImport {
+            from: 64512,
+            to: 1,
+            rule: None,
+        },
+    ];
+    assert_eq!(cmp.check(&query), expected);
+    Ok(())
+}
+
+#[test]
+fn non_public_asn_in_peering() -> Result<()> {
+    let query = query_importing(
+        AsExpr::Single(AsName::Num(64500)),
+        Filter::Any,
+        Ir::default(),
+    );
+    let expected = vec![BadImport {
+        from: 2,
+        to: 1,
+        items: vec![
+            MatchRemoteAsNum(64500),
+            RpslNonPublicAsn(64500, AsnClass::Documentation),
+        ],
+    }];
+    assert_eq!(check(&query, "10.0.0.0/8")?, expected);
+
+    let query = query_importing(num(3), Filter::Any, Ir::default());
+    let expected = vec![BadImport {
+        from: 2,
+        to: 1,
+        items: vec![MatchRemoteAsNum(3)],
+    }];
+    assert_eq!(check(&query, "10.0.0.0/8")?, expected);
+    Ok(())
+}
@@ -0,0 +1,119 @@
+use super::{set_cycle::route_set, *};
+
+fn ranges(ranges: &[&str]) -> Result<Vec<AddrPfxRange>> {
+    ranges.iter().map(|range| range.parse()).collect()
+}
+
+fn prefix_set(prefixes: &[&str]) -> Result<Filter> {
+    Ok(Filter::AddrPrefixSet(ranges(prefixes)?))
+}
+
+/// AS3 originates 10.0.0.0/16 and 10.1.0.0/16, AS4 originates 2001:db8::/32,
+/// and AS-A contains both.
+fn ir() -> Result<Ir> {
+    let mut ir = Ir::default();
+    ir.as_routes
+        .insert(3, vec!["10.0.0.0/16".parse()?, "10.1.0.0/16".parse()?]);
+    ir.as_routes.insert(4, vec!["2001:db8::/32".parse()?]);
+    ir.as_sets
+        .insert("AS-A".into(), AsSet::new("".into(), vec![3, 4], vec![]));
+    Ok(ir)
+}
+
+#[test]
+fn aggregates_siblings_and_covered() -> Result<()> {
+    let query = QueryIr::from_ir(Ir::default());
+    let filter = prefix_set(&[
+        "10.0.0.0/9^+",
+        "10.128.0.0/9^+",
+        "10.1.2.0/24",
+        "192.0.2.0/25",
+        "192.0.2.128/25^26",
+    ])?;
+    let expected = PrefixList {
+        ipv4: ranges(&["10.0.0.0/8^-", "192.0.2.0/25", "192.0.2.128/25^26"])?,
+        ipv6: vec![],
+    };
+    assert_eq!(query.prefix_list(&filter, None)?, expected);
+    Ok(())
+}
+
+/// Lengths beyond the address family's maximum match nothing.
+#[test]
+fn drops_out_of_range_lengths() -> Result<()> {
+    let query = QueryIr::from_ir(Ir::default());
+    let filter = prefix_set(&["10.0.0.0/24^33", "10.1.0.0/16^30-40", "2001:db8::/32^129"])?;
+    let expected = PrefixList {
+        ipv4: ranges(&["10.1.0.0/16^30-32"])?,
+        ipv6: vec![],
+    };
+    assert_eq!(query.prefix_list(&filter, None)?, expected);
+    Ok(())
+}
+
+#[test]
+fn resolves_sets_with_range_operators() -> Result<()> {
+    let mut ir = ir()?;
+    ir.route_sets.insert(
+        "RS-A".into(),
+        route_set(vec![
+            "AS-A^24".to_string().into(),
+            "192.0.2.0/24".to_string().into(),
+        ]),
+    );
+    let query = QueryIr::from_ir(ir);
+
+    let filter = Filter::AsSet("AS-A".into(), RangeOperator::NoOp);
+    let expected = PrefixList {
+        ipv4: ranges(&["10.0.0.0/15^16"])?,
+        ipv6: ranges(&["2001:db8::/32"])?,
+    };
+    assert_eq!(query.prefix_list(&filter, None)?, expected);
+
+    // `AS-A^24` in `RS-A^-` stands for `10.0.0.0/16^25-32` and so on,
+    // but nothing for `2001:db8::/32`, longer than 24.
+    let filter = Filter::RouteSet("RS-A".into(), RangeOperator::Minus);
+    let expected = PrefixList {
+        ipv4: ranges(&["10.0.0.0/15^25-32", "192.0.2.0/24^-"])?,
+        ipv6: vec![],
+    };
+    assert_eq!(query.prefix_list(&filter, None)?, expected);
+    Ok(())
+}
+
+#[test]
+fn peer_as_needs_peer() -> Result<()> {
+    let query = QueryIr::from_ir(ir()?);
+    let expected = PrefixList {
+        ipv4: vec![],
+        ipv6: ranges(&["2001:db8::/32"])?,
+    };
+    assert_eq!(query.prefix_list(&Filter::PeerAS, Some(4))?, expected);
+    assert_eq!(
+        query.prefix_list(&Filter::PeerAS, None),
+        Err(PrefixListError::PeerAS)
+    );
+    Ok(())
+}
+
+#[test]
+fn unsupported_filters_fail() -> Result<()> {
+    let query = QueryIr::from_ir(ir()?);
+    let as_num = Filter::AsNum(3, RangeOperator::NoOp);
+    let filter = Filter::Or {
+        left: Box::new(as_num.clone()),
+        right: Box::new(Filter::Not(Box::new(as_num.clone()))),
+    };
+    assert_eq!(query.prefix_list(&filter, None), Err(PrefixListError::Not));
+    let filter = Filter::AsPathRE("<^AS3$>".into());
+    assert_eq!(
+        query.prefix_list(&filter, None),
+        Err(PrefixListError::AsPathRE("<^AS3$>".into()))
+    );
+    let filter = Filter::RouteSet("RS-NONE".into(), RangeOperator::NoOp);
+    assert_eq!(
+        query.prefix_list(&filter, None),
+        Err(PrefixListError::UnrecordedRouteSet("RS-NONE".into()))
+    );
+    Ok(())
+}
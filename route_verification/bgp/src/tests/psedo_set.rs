@@ -16,10 +16,12 @@ fn export_customers() -> Result<()> {
         ..Verbosity::minimum_all()
     };
     let cmp = Compare {
-        prefix: "103.2.88.0/24".parse()?,
-        as_path: vec![Seq(139609), Seq(45891), Seq(134525)],
         recursion_limit: 1,
         verbosity,
+        ..Compare::new(
+            "103.2.88.0/24".parse()?,
+            vec![Seq(139609), Seq(45891), Seq(134525)],
+        )
     };
     let actual = cmp.check(&query);
     assert_eq!(expected_reports_with_customers(), actual);
@@ -34,3 +34,16 @@ fn expected_as_sets() -> HashMap<String, QueryAsSet> {
         }
     }
 }
+
+#[test]
+fn pseudo_customer_cone_set() -> Result<()> {
+    let ir = ir()?;
+    let db = as_relationship_db()?;
+    let query = QueryIr::from_ir_and_as_relationship_with_cones(ir, &db);
+    let members = |aut_num| &query.as_sets[&customer_cone_set(aut_num)].members;
+    assert_eq!(members(9063), &HashSet::from([196763, 60351]));
+    assert_eq!(members(196763), &HashSet::from([60351]));
+    assert_eq!(members(2914), &HashSet::from([4096, 9583]));
+    assert!(query.as_sets.contains_key("c#9063"));
+    Ok(())
+}
@@ -0,0 +1,118 @@
+use std::collections::BTreeSet;
+
+use super::*;
+
+use AsExpr::{Except, Or, Single};
+
+fn set(name: &str) -> AsExpr {
+    Single(AsName::Set(name.into()))
+}
+
+fn peering(remote_as: AsExpr) -> Peering {
+    Peering {
+        remote_as,
+        remote_router: None,
+        local_router: None,
+    }
+}
+
+fn boxed(left: AsExpr, right: AsExpr) -> (Box<AsExpr>, Box<AsExpr>) {
+    (Box::new(left), Box::new(right))
+}
+
+fn only(asns: &[u32]) -> AsnSet {
+    AsnSet::Only(BTreeSet::from_iter(asns.iter().copied()))
+}
+
+/// AS-A has AS1, AS2, and unrecorded AS-MISSING;
+/// PRNG-A peers with AS-A and AS3, and refers back to itself through PRNG-B.
+fn query() -> QueryIr {
+    let mut ir = Ir::default();
+    ir.as_sets.insert(
+        "AS-A".into(),
+        AsSet::new("".into(), vec![1, 2], vec!["AS-MISSING".into()]),
+    );
+    ir.peering_sets.insert(
+        "PRNG-A".into(),
+        ir::PeeringSet {
+            body: "".into(),
+            peerings: vec![
+                peering(set("AS-A")),
+                peering(num(3)),
+                peering(AsExpr::PeeringSet("PRNG-B".into())),
+            ],
+        },
+    );
+    ir.peering_sets.insert(
+        "PRNG-B".into(),
+        ir::PeeringSet {
+            body: "".into(),
+            peerings: vec![peering(AsExpr::PeeringSet("PRNG-A".into()))],
+        },
+    );
+    QueryIr::from_ir(ir)
+}
+
+#[test]
+fn remote_asns() {
+    let query = query();
+
+    let actual = query.remote_asns(&AsExpr::PeeringSet("PRNG-A".into()));
+    let expected = RemoteAsns {
+        asns: only(&[1, 2, 3]),
+        unrecorded: vec![set("AS-MISSING")],
+    };
+    assert_eq!(actual, expected);
+
+    let (left, right) = boxed(set("AS-A"), num(1));
+    let actual = query.remote_asns(&Except { left, right });
+    assert_eq!(actual.asns, only(&[2]));
+
+    let (left, right) = boxed(Single(AsName::Any), set("AS-A"));
+    let any_except = query.remote_asns(&Except { left, right }).asns;
+    assert_eq!(any_except, AsnSet::AnyExcept(BTreeSet::from([1, 2])));
+    assert!(any_except.contains(&3));
+    assert!(!any_except.contains(&1));
+
+    let (left, right) = boxed(num(4), set("AS-UNRECORDED"));
+    let actual = query.remote_asns(&Or { left, right });
+    let expected = RemoteAsns {
+        asns: only(&[4]),
+        unrecorded: vec![set("AS-UNRECORDED")],
+    };
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn asn_set_algebra() {
+    let any_but_1 = AsnSet::AnyExcept(BTreeSet::from([1]));
+    assert_eq!(only(&[1, 2]).union(any_but_1.clone()), AsnSet::any());
+    assert_eq!(only(&[1, 2]).intersection(any_but_1.clone()), only(&[2]));
+    assert_eq!(
+        any_but_1.clone().difference(only(&[2])),
+        only(&[1, 2]).complement()
+    );
+    assert_eq!(
+        any_but_1.union(AsnSet::AnyExcept(BTreeSet::from([1, 2]))),
+        AsnSet::AnyExcept(BTreeSet::from([1]))
+    );
+}
+
+#[test]
+fn declared_neighbors() {
+    let mut query = query();
+    let mut aut_num = AutNum::default();
+    for remote_as in [num(5), AsExpr::PeeringSet("PRNG-A".into())] {
+        aut_num.imports.any.any.push(entry(remote_as, Filter::Any));
+    }
+    query.aut_nums.insert(10, aut_num);
+
+    let imports = query.declared_neighbors(10, false).unwrap();
+    assert_eq!(imports.asns, only(&[1, 2, 3, 5]));
+    assert_eq!(imports.unrecorded, [set("AS-MISSING")]);
+    assert_eq!(
+        query.declared_neighbors(10, true),
+        Some(RemoteAsns::default())
+    );
+    assert_eq!(query.declared_neighbors(11, false), None);
+}
@@ -0,0 +1,63 @@
+use super::*;
+
+use {AsPathEntry::Seq, Report::*, ReportItem::*};
+
+fn query() -> Result<QueryIr> {
+    let mut ir = Ir::default();
+    ir.as_routes
+        .insert(2, vec!["10.0.0.0/8".parse()?, "10.1.0.0/16".parse()?]);
+    ir.as_routes.insert(3, vec!["10.1.0.0/16".parse()?]);
+    ir.as_routes.insert(4, vec!["192.0.2.0/24".parse()?]);
+    ir.as_routes.insert(5, vec!["192.0.0.0/16".parse()?]);
+    Ok(QueryIr::from_ir(ir))
+}
+
+#[test]
+fn route_object_states() -> Result<()> {
+    use RouteObjectState::*;
+    let query = query()?;
+    for (prefix, origin, expected) in [
+        ("10.0.0.0/8", 2, Exact),
+        ("10.1.0.0/16", 2, Moas(vec![3])),
+        ("10.1.0.0/16", 3, Moas(vec![2])),
+        ("10.2.0.0/16", 2, Covered),
+        ("10.1.2.0/24", 3, Moas(vec![2])),
+        ("10.2.0.0/16", 3, OtherOrigins(vec![2])),
+        ("192.0.2.0/24", 3, OtherOrigins(vec![4, 5])),
+        ("198.51.100.0/24", 2, Missing),
+    ] {
+        let actual = query.route_object_state(&prefix.parse()?, origin);
+        assert_eq!(actual, expected, "{prefix} AS{origin}");
+    }
+    Ok(())
+}
+
+/// Reported and counted even if the origin has no aut-num,
+/// but only if enabled.
+#[test]
+fn route_object_stats() -> Result<()> {
+    let query = query()?;
+    let mut cmp = Compare::new("10.2.0.0/16".parse()?, vec![Seq(1), Seq(3)]);
+    let stats = cmp.route_stats(&query, &AsRelDb::default());
+    assert_eq!(stats.route_object_other_origins, 0);
+
+    let verbosity = Verbosity {
+        check_route_object: true,
+        ..Verbosity::default()
+    };
+    let mut cmp = cmp.verbosity(verbosity);
+    let expected = vec![RouteObject {
+        origin: 3,
+        items: vec![RouteObjectOtherOrigins(vec![2])],
+    }];
+    assert_eq!(cmp.check(&query), expected);
+
+    let stats = cmp.route_stats(&query, &AsRelDb::default());
+    assert_eq!(stats.route_object_other_origins, 1);
+    assert_eq!(stats.route_object_moas, 0);
+
+    cmp.prefix = "10.1.0.0/16".parse()?;
+    let stats = cmp.route_stats(&query, &AsRelDb::default());
+    assert_eq!(stats.route_object_moas, 1);
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use super::*;
+
+use {AsPathEntry::Seq, Report::*, ReportItem::*};
+
+const ROUTE_SERVERS: &str = r#"[
+  {"asn": 6695, "member_sets": ["AS-RS-MEMBERS"]},
+  {"asn": 64500, "member_sets": ["AS-RS-UNRECORDED"], "members": [1, 2]}
+]"#;
+
+/// AS1 imports from route server AS6695 at the IXP, where AS1 and AS2 are
+/// members but AS3 is not.
+fn query(remote_as: AsName) -> Result<QueryIr> {
+    let mut query = QueryIr::default();
+    let mut aut_num = AutNum::default();
+    aut_num
+        .imports
+        .any
+        .any
+        .push(entry(AsExpr::Single(remote_as), Filter::Any));
+    query.aut_nums.insert(1, aut_num);
+    query.as_sets.insert(
+        "AS-RS-MEMBERS".into(),
+        QueryAsSet {
+            members: [1, 2].into_iter().collect(),
+            ..Default::default()
+        },
+    );
+    let route_servers: Vec<RouteServer> = serde_json::from_str(ROUTE_SERVERS)?;
+    Ok(query.with_route_servers(route_servers))
+}
+
+fn check(remote_as: AsName, from: u32) -> Result<Vec<Report>> {
+    let cmp = Compare::new("10.0.0.0/8".parse()?, vec![Seq(1), Seq(from)]).verbosity(Verbosity {
+        show_meh: true,
+        all_err: true,
+        ..Verbosity::default()
+    });
+    Ok(cmp.check(&query(remote_as)?))
+}
+
+fn meh(from: u32, asn: u32) -> Report {
+    MehImport {
+        from,
+        to: 1,
+        items: vec![SpecTransparentRouteServer(asn)],
+    }
+}
+
+#[test]
+fn route_server_asn() -> Result<()> {
+    assert_eq!(check(AsName::Num(6695), 2)?, [meh(2, 6695)]);
+    let actual = check(AsName::Num(6695), 3)?;
+    let expected = BadImport {
+        from: 3,
+        to: 1,
+        items: vec![MatchRemoteAsNum(6695)],
+    };
+    assert_eq!(actual, [expected]);
+    Ok(())
+}
+
+#[test]
+fn route_server_member_set() -> Result<()> {
+    // AS2 is directly in the set, so the rule matches as usual.
+    assert_eq!(check(AsName::Set("AS-RS-MEMBERS".into()), 2)?, []);
+    assert_eq!(
+        check(AsName::Set("AS-RS-UNRECORDED".into()), 2)?,
+        [meh(2, 64500)]
+    );
+    Ok(())
+}
+
+#[test]
+fn member_set_case_insensitive() -> Result<()> {
+    let route_server = RouteServer {
+        asn: 64501,
+        member_sets: vec!["as-rs-members".into()],
+        members: vec![],
+    };
+    let query = query(AsName::Num(64501))?.with_route_servers([route_server]);
+    let route_server = query.route_server_by_asn(64501).context("configured")?;
+    assert_eq!(route_server.member_sets, ["AS-RS-MEMBERS"]);
+    assert!(query.is_route_server_member(route_server, 2));
+    assert!(!query.is_route_server_member(route_server, 3));
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use super::{set_cycle::*, *};
+
+use {Report::*, ReportItem::*};
+
+/// AS3, not on the AS path, originates 10.0.0.0/8 and is in AS-THREE.
+fn query_with_member(member: &str, filter_op: RangeOperator) -> Result<QueryIr> {
+    let mut ir = Ir::default();
+    ir.as_routes.insert(3, vec!["10.0.0.0/8".parse()?]);
+    ir.as_sets
+        .insert("AS-THREE".into(), AsSet::new("".into(), vec![3], vec![]));
+    ir.route_sets
+        .insert("RS-A".into(), route_set(vec![member.to_string().into()]));
+    Ok(query_importing(
+        AsExpr::Single(AsName::Num(2)),
+        Filter::RouteSet("RS-A".into(), filter_op),
+        ir,
+    ))
+}
+
+#[test]
+fn as_num_member_matches_as_routes() -> Result<()> {
+    let query = query_with_member("AS3^+", RangeOperator::NoOp)?;
+    assert_eq!(check(&query, "10.1.0.0/16")?, vec![]);
+    Ok(())
+}
+
+#[test]
+fn as_set_member_matches_as_routes() -> Result<()> {
+    let query = query_with_member("AS-THREE", RangeOperator::NoOp)?;
+    assert_eq!(check(&query, "10.0.0.0/8")?, vec![]);
+    Ok(())
+}
+
+#[test]
+fn route_set_op_applies_to_as_member() -> Result<()> {
+    let query = query_with_member("AS3", RangeOperator::Plus)?;
+    assert_eq!(check(&query, "10.1.0.0/16")?, vec![]);
+
+    // `AS3^12` in `RS-A^+` stands for `10.0.0.0/8^12-32`.
+    let query = query_with_member("AS3^12", RangeOperator::Plus)?;
+    assert_eq!(check(&query, "10.1.0.0/16")?, vec![]);
+    let expected = vec![BadImport {
+        from: 2,
+        to: 1,
+        items: vec![MatchFilterRouteSet("RS-A".into()), MatchFilter],
+    }];
+    assert_eq!(check(&query, "10.0.0.0/8")?, expected);
+    Ok(())
+}
+
+#[test]
+fn nested_route_set_ops_compose() -> Result<()> {
+    let mut query = query_with_member("RS-B^-", RangeOperator::Num(24))?;
+    let members = vec![
+        "10.0.0.0/8^16".to_string().into(),
+        "AS-THREE^+".to_string().into(),
+    ];
+    query.route_sets.insert("RS-B".into(), route_set(members));
+    // `10.0.0.0/8^16^-^24` is `10.0.0.0/8^24`.
+    assert_eq!(check(&query, "10.1.2.0/24")?, vec![]);
+    let expected = vec![BadImport {
+        from: 2,
+        to: 1,
+        items: vec![MatchFilterRouteSet("RS-A".into()), MatchFilter],
+    }];
+    assert_eq!(check(&query, "10.1.0.0/16")?, expected);
+    Ok(())
+}
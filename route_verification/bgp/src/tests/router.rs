@@ -0,0 +1,151 @@
+use std::net::IpAddr;
+
+use super::*;
+
+use {
+    AsPathEntry::Seq,
+    Report::*,
+    ReportItem::*,
+    RouterExpr::{And, Except, Ip, Or},
+};
+
+fn ip(s: &str) -> IpAddr {
+    s.parse().unwrap()
+}
+
+fn inet_rtr(ifaddrs: &[&str]) -> InetRtr {
+    InetRtr {
+        ifaddrs: ifaddrs.iter().map(|s| ip(s)).collect(),
+        ..Default::default()
+    }
+}
+
+fn rtr_set(members: Vec<RouterExpr>) -> RtrSet {
+    RtrSet {
+        body: "".into(),
+        members,
+        ..Default::default()
+    }
+}
+
+fn routers() -> QueryIr {
+    let mut ir = Ir::default();
+    ir.inet_rtrs.insert(
+        "a.example.net".into(),
+        inet_rtr(&["192.0.2.1", "192.0.2.2"]),
+    );
+    ir.inet_rtrs.insert(
+        "b.example.net".into(),
+        inet_rtr(&["192.0.2.2", "192.0.2.3"]),
+    );
+    ir.rtr_sets.insert(
+        "RTRS-A".into(),
+        rtr_set(vec![
+            RouterExpr::InetRtr("a.example.net".into()),
+            Ip(ip("192.0.2.9")),
+            RouterExpr::RtrSet("RTRS-B".into()),
+        ]),
+    );
+    ir.rtr_sets.insert(
+        "RTRS-B".into(),
+        rtr_set(vec![RouterExpr::RtrSet("RTRS-A".into())]),
+    );
+    QueryIr::from_ir(ir)
+}
+
+fn expr(left: RouterExpr, right: RouterExpr) -> (Box<RouterExpr>, Box<RouterExpr>) {
+    (Box::new(left), Box::new(right))
+}
+
+#[test]
+fn router_ips() {
+    let query = routers();
+    let a = || RouterExpr::InetRtr("a.example.net".into());
+    let b = || RouterExpr::InetRtr("b.example.net".into());
+
+    let ips = query.router_ips(&RouterExpr::RtrSet("RTRS-A".into()));
+    let expected = [ip("192.0.2.1"), ip("192.0.2.2"), ip("192.0.2.9")];
+    assert_eq!(ips.ips, expected.into());
+    assert!(ips.unrecorded.is_empty());
+
+    let (left, right) = expr(a(), b());
+    let ips = query.router_ips(&And { left, right });
+    assert_eq!(ips.ips, [ip("192.0.2.2")].into());
+
+    let (left, right) = expr(a(), b());
+    let ips = query.router_ips(&Except { left, right });
+    assert_eq!(ips.ips, [ip("192.0.2.1")].into());
+
+    let (left, right) = expr(b(), RouterExpr::InetRtr("c.example.net".into()));
+    let ips = query.router_ips(&Or { left, right });
+    assert_eq!(ips.ips, [ip("192.0.2.2"), ip("192.0.2.3")].into());
+    assert_eq!(
+        ips.unrecorded,
+        vec![RouterExpr::InetRtr("c.example.net".into())]
+    );
+}
+
+/// `ANY` to or from `remote_as`, at `local_router`.
+fn entry_any(remote_as: u32, local_router: Option<RouterExpr>) -> Entry {
+    let mut entry = entry(num(remote_as), Filter::Any);
+    entry.mp_peerings[0].mp_peering.local_router = local_router;
+    entry
+}
+
+/// AS1 imports from AS2 at `local_router`, reported by a collector peering
+/// with AS1 at 192.0.2.1.
+fn check_local_router(local_router: RouterExpr) -> Vec<Report> {
+    let mut query = routers();
+    let mut aut_num = AutNum::default();
+    aut_num
+        .imports
+        .any
+        .any
+        .push(entry_any(2, Some(local_router)));
+    query.aut_nums.insert(1, aut_num);
+    let mut aut_num = AutNum::default();
+    aut_num.exports.any.any.push(entry_any(1, None));
+    query.aut_nums.insert(2, aut_num);
+    let cmp = Compare {
+        verbosity: Verbosity {
+            show_unrec: true,
+            show_skips: true,
+            all_err: true,
+            ..Verbosity::default()
+        },
+        collector_peer: Some(CollectorPeer {
+            asn: 1,
+            ip: ip("192.0.2.1"),
+        }),
+        ..Compare::new("10.0.0.0/8".parse().unwrap(), vec![Seq(1), Seq(2)])
+    };
+    cmp.check(&query)
+}
+
+#[test]
+fn check_router_against_collector_peer() {
+    assert_eq!(
+        check_local_router(RouterExpr::RtrSet("RTRS-A".into())),
+        vec![]
+    );
+
+    let expected = vec![BadImport {
+        from: 2,
+        to: 1,
+        items: vec![MatchRouter(ip("192.0.2.1"))],
+    }];
+    assert_eq!(
+        check_local_router(RouterExpr::InetRtr("b.example.net".into())),
+        expected
+    );
+
+    let expected = vec![UnrecImport {
+        from: 2,
+        to: 1,
+        items: vec![UnrecordedRtrSet("RTRS-C".into())],
+    }];
+    assert_eq!(
+        check_local_router(RouterExpr::RtrSet("RTRS-C".into())),
+        expected
+    );
+}
@@ -0,0 +1,122 @@
+use super::*;
+
+fn prefix_set(prefixes: &[&str]) -> Result<Filter> {
+    let prefixes = prefixes.iter().map(|p| p.parse()).collect::<Result<_>>()?;
+    Ok(Filter::AddrPrefixSet(prefixes))
+}
+
+/// AS1 imports from AS2, also in AS-PEERS, AS-A's routes of all versions,
+/// some IPv4 and IPv6 unicast prefixes, and an IPv4 multicast prefix.
+/// It exports to AS2 routes matching an AS path regex.
+/// AS6 imports from AS2 or the unrecorded AS-UNKNOWN.
+fn query() -> Result<QueryIr> {
+    let mut ir = Ir::default();
+    ir.as_routes
+        .insert(3, vec!["10.0.0.0/16".parse()?, "10.1.0.0/16".parse()?]);
+    ir.as_routes.insert(4, vec!["2001:db8::/32".parse()?]);
+    ir.as_sets
+        .insert("AS-A".into(), AsSet::new("".into(), vec![3, 4], vec![]));
+    ir.as_sets
+        .insert("AS-PEERS".into(), AsSet::new("".into(), vec![2], vec![]));
+
+    let two = || AsExpr::Single(AsName::Num(2));
+    let mut aut_num = AutNum::default();
+    let imports = &mut aut_num.imports;
+    imports.any.any.push(entry(
+        two(),
+        Filter::AsSet("AS-A".into(), RangeOperator::NoOp),
+    ));
+    imports.ipv4.unicast.push(entry(
+        AsExpr::Single(AsName::Set("AS-PEERS".into())),
+        prefix_set(&["192.0.2.0/24^+", "198.51.100.0/24^25-26"])?,
+    ));
+    imports.ipv6.unicast.push(entry(
+        two(),
+        prefix_set(&["2001:db8:1::/48^48-64", "10.9.0.0/16"])?,
+    ));
+    imports
+        .ipv4
+        .multicast
+        .push(entry(two(), prefix_set(&["203.0.113.0/24"])?));
+    imports.any.any.push(entry(
+        AsExpr::Single(AsName::Num(5)),
+        prefix_set(&["203.0.113.0/24"])?,
+    ));
+    aut_num
+        .exports
+        .any
+        .any
+        .push(entry(two(), Filter::AsPathRE("^AS1$".into())));
+    ir.aut_nums.insert(1, aut_num);
+
+    let mut aut_num = AutNum::default();
+    let peers = AsExpr::Or {
+        left: Box::new(two()),
+        right: Box::new(AsExpr::Single(AsName::Set("AS-UNKNOWN".into()))),
+    };
+    aut_num.imports.any.any.push(entry(peers, Filter::Any));
+    ir.aut_nums.insert(6, aut_num);
+    Ok(QueryIr::from_ir(ir))
+}
+
+#[test]
+fn neighbor_policy() -> Result<()> {
+    let query = query()?;
+    let policy = query.neighbor_policy(1, 2, false)?;
+    assert_eq!(policy.name(), "AS1-IMPORT-AS2");
+    let parse = |ranges: &[&str]| ranges.iter().map(|r| r.parse()).collect::<Result<Vec<_>>>();
+    let expected = PrefixList {
+        ipv4: parse(&["10.0.0.0/15^16", "192.0.2.0/24^+", "198.51.100.0/24^25-26"])?,
+        ipv6: parse(&["2001:db8::/32", "2001:db8:1::/48^48-64"])?,
+    };
+    assert_eq!(policy.prefix_list, expected);
+
+    assert!(query.neighbor_policy(1, 9, false)?.prefix_list.is_empty());
+    assert_eq!(
+        query.neighbor_policy(1, 2, true),
+        Err(ConfigError::PrefixList(PrefixListError::AsPathRE(
+            "^AS1$".into()
+        )))
+    );
+    assert_eq!(
+        query.neighbor_policy(9, 2, false),
+        Err(ConfigError::UnrecordedAutNum(9))
+    );
+    for neighbor in [2, 9] {
+        let error = query.neighbor_policy(6, neighbor, false).unwrap_err();
+        assert!(matches!(error, ConfigError::UnrecordedPeering(_)));
+        assert_eq!(
+            error.to_string(),
+            "peering `AS2 OR AS-UNKNOWN` refers to unrecorded sets or invalid AS names"
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn golden_configs() -> Result<()> {
+    let policy = query()?.neighbor_policy(1, 2, false)?;
+    assert_eq!(Bird2.render(&policy), include_str!("golden/bird2.conf"));
+    assert_eq!(Junos.render(&policy), include_str!("golden/junos.conf"));
+    assert_eq!(IosXr.render(&policy), include_str!("golden/iosxr.conf"));
+    Ok(())
+}
+
+#[test]
+fn empty_policy_rejects_all() -> Result<()> {
+    let policy = query()?.neighbor_policy(1, 9, false)?;
+    let expected = "# Routes AS1 imports from AS9, generated from RPSL.
+filter AS1_IMPORT_AS9 {
+    reject;
+}
+";
+    assert_eq!(Bird2.render(&policy), expected);
+    let expected = "! Routes AS1 imports from AS9, generated from RPSL.
+route-policy AS1-IMPORT-AS9
+  drop
+end-policy
+!
+";
+    assert_eq!(IosXr.render(&policy), expected);
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use super::*;
+
+use {
+    AsPathEntry::{Seq, Set},
+    Report::*,
+    ReportItem::*,
+};
+
+fn roa_db() -> Result<RoaDb> {
+    RoaDb::from_lines(["AS2,10.0.0.0/8,16", "AS3,192.0.2.0/24,24"])
+}
+
+fn check(prefix: &str, as_path: Vec<AsPathEntry>) -> Result<Vec<Report>> {
+    let cmp = Compare::new(prefix.parse()?, as_path).validate_origin(&roa_db()?);
+    Ok(cmp.check(&QueryIr::default()))
+}
+
+#[test]
+fn rpki_reports() -> Result<()> {
+    for (prefix, as_path, origin, item) in [
+        ("10.1.0.0/16", vec![Seq(1), Seq(2)], Some(2), RpkiValid),
+        ("10.1.1.0/24", vec![Seq(1), Seq(2)], Some(2), RpkiInvalid),
+        ("192.0.2.0/24", vec![Seq(1), Seq(2)], Some(2), RpkiInvalid),
+        (
+            "198.51.100.0/24",
+            vec![Seq(1), Seq(2)],
+            Some(2),
+            RpkiNotFound,
+        ),
+        ("10.0.0.0/8", vec![Seq(1), Set(vec![2])], None, RpkiInvalid),
+        // Paths with only one entry are only validated.
+        ("192.0.2.0/24", vec![Seq(3)], Some(3), RpkiValid),
+    ] {
+        let expected = vec![Rpki {
+            origin,
+            items: vec![item],
+        }];
+        assert_eq!(check(prefix, as_path)?, expected, "{prefix}");
+    }
+    Ok(())
+}
+
+#[test]
+fn rpki_route_stats() -> Result<()> {
+    let mut stats = RouteStats::default();
+    for report in check("10.1.1.0/24", vec![Seq(1), Seq(2)])? {
+        route::one(&mut stats, &report);
+    }
+    assert_eq!(stats.rpki_invalid, 1);
+    assert_eq!(stats.rpki_valid + stats.rpki_not_found, 0);
+    Ok(())
+}
+
+#[test]
+fn aspa_reports() -> Result<()> {
+    let mut db = AspaDb::default();
+    db.insert(2, [3]);
+    db.insert(3, [0]);
+    for (as_path, from_provider, item) in [
+        (vec![Seq(1), Seq(3), Seq(2)], false, AspaInvalid),
+        (vec![Seq(1), Seq(3), Seq(2)], true, AspaValid),
+        (vec![Seq(3), Seq(2)], false, AspaValid),
+        (vec![Seq(4), Seq(2)], false, AspaInvalid),
+        (vec![Seq(2), Seq(5)], false, AspaUnknown),
+        (vec![Seq(3), Set(vec![2, 5])], true, AspaInvalid),
+    ] {
+        let cmp =
+            Compare::new("10.0.0.0/8".parse()?, as_path.clone()).verify_as_path(&db, from_provider);
+        let actual = cmp.check(&QueryIr::default());
+        assert_eq!(actual, vec![Aspa { items: vec![item] }], "{as_path:?}");
+
+        let mut stats = RouteStats::default();
+        for report in &actual {
+            route::one(&mut stats, report);
+        }
+        let counts = [stats.aspa_valid, stats.aspa_invalid, stats.aspa_unknown];
+        assert_eq!(counts.iter().sum::<u16>(), 1);
+    }
+    Ok(())
+}
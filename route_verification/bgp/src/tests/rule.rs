@@ -0,0 +1,79 @@
+use super::*;
+
+use {AsPathEntry::Seq, Report::*};
+
+/// AS1 imports from AS3, then from AS2 for any address family,
+/// and from AS2 for IPv4 unicast.
+fn query() -> QueryIr {
+    let mut query = QueryIr::default();
+    let mut aut_num = AutNum::default();
+    aut_num.imports.any.any.push(entry(num(3), Filter::Any));
+    aut_num.imports.any.any.push(entry(num(2), Filter::Any));
+    aut_num
+        .imports
+        .ipv4
+        .unicast
+        .push(entry(num(2), Filter::Any));
+    query.aut_nums.insert(1, aut_num);
+    query
+}
+
+const IPV4_UNICAST_0: RuleIndex = RuleIndex {
+    version: PolicyVersion::Ipv4,
+    cast: PolicyCast::Unicast,
+    entry: 0,
+};
+
+const ANY_1: RuleIndex = RuleIndex {
+    version: PolicyVersion::Any,
+    cast: PolicyCast::Any,
+    entry: 1,
+};
+
+fn ok_import(prefix: &str, record_rule: bool) -> Result<Vec<Report>> {
+    let cmp = Compare::new(prefix.parse()?, vec![Seq(1), Seq(2)]).verbosity(Verbosity {
+        show_success: true,
+        record_rule,
+        ..Verbosity::default()
+    });
+    Ok(cmp.check(&query()))
+}
+
+#[test]
+fn matching_rule() -> Result<()> {
+    let expected = |rule| OkImport {
+        from: 2,
+        to: 1,
+        rule,
+    };
+    assert_eq!(ok_import("10.0.0.0/8", false)?, [expected(None)]);
+    // The IPv4 unicast entry is tried before the `any` ones.
+    assert_eq!(
+        ok_import("10.0.0.0/8", true)?,
+        [expected(Some(IPV4_UNICAST_0))]
+    );
+    assert_eq!(ok_import("2001:db8::/32", true)?, [expected(Some(ANY_1))]);
+    Ok(())
+}
+
+#[test]
+fn rule_hits() -> Result<()> {
+    let query = query();
+    let hits = RuleHits::default();
+    for prefix in ["10.0.0.0/8", "192.0.2.0/24", "2001:db8::/32"] {
+        let mut cmp = Compare::new(prefix.parse()?, vec![Seq(1), Seq(2)]);
+        cmp.rule_stats(&query, &hits);
+    }
+    let rule_ref = |rule| RuleRef {
+        aut_num: 1,
+        export: false,
+        rule,
+    };
+    assert_eq!(
+        hits.ranked(),
+        [(rule_ref(IPV4_UNICAST_0), 2), (rule_ref(ANY_1), 1)]
+    );
+    let any_0 = RuleIndex { entry: 0, ..ANY_1 };
+    assert_eq!(hits.dead_rules(&query), [rule_ref(any_0)]);
+    Ok(())
+}
@@ -0,0 +1,166 @@
+use super::*;
+
+use {AsPathEntry::Seq, Report::*, ReportItem::*};
+
+/// AS1 imports from `peering` the routes in `filter`.
+pub(super) fn query_importing(peering: AsExpr, filter: Filter, ir: Ir) -> QueryIr {
+    let entry = entry(peering, filter);
+    let aut_num = AutNum {
+        imports: Versions {
+            any: Casts {
+                any: vec![entry],
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut ir = ir;
+    ir.aut_nums.insert(1, aut_num);
+    QueryIr::from_ir(ir)
+}
+
+pub(super) fn route_set(members: Vec<RouteSetMember>) -> RouteSet {
+    RouteSet {
+        body: "".into(),
+        members,
+        ..Default::default()
+    }
+}
+
+pub(super) fn check(query: &QueryIr, prefix: &str) -> Result<Vec<Report>> {
+    let cmp = Compare {
+        verbosity: Verbosity {
+            stop_at_first: false,
+            per_filter_err: true,
+            all_err: true,
+            ..Verbosity::default()
+        },
+        ..Compare::new(prefix.parse()?, vec![Seq(1), Seq(2)])
+    };
+    Ok(cmp.check(query))
+}
+
+fn cyclic_route_sets() -> Result<QueryIr> {
+    let mut ir = Ir::default();
+    ir.route_sets.insert(
+        "RS-A".into(),
+        route_set(vec![
+            RouteSetMember::NameOp("RS-B".into(), RangeOperator::NoOp),
+            RouteSetMember::RSRange("10.0.0.0/8".parse()?),
+        ]),
+    );
+    ir.route_sets.insert(
+        "RS-B".into(),
+        route_set(vec![RouteSetMember::NameOp(
+            "RS-A".into(),
+            RangeOperator::NoOp,
+        )]),
+    );
+    let filter = Filter::RouteSet("RS-A".into(), RangeOperator::NoOp);
+    Ok(query_importing(AsExpr::Single(AsName::Num(2)), filter, ir))
+}
+
+#[test]
+fn route_set_cycle_still_matches() -> Result<()> {
+    let query = cyclic_route_sets()?;
+    assert_eq!(check(&query, "10.0.0.0/8")?, vec![]);
+    Ok(())
+}
+
+#[test]
+fn route_set_cycle_reported() -> Result<()> {
+    let query = cyclic_route_sets()?;
+    let expected = vec![BadImport {
+        from: 2,
+        to: 1,
+        items: vec![
+            RecSetCycle(vec!["RS-A".into(), "RS-B".into(), "RS-A".into()]),
+            MatchFilterRouteSet("RS-A".into()),
+            MatchFilter,
+        ],
+    }];
+    assert_eq!(check(&query, "192.0.2.0/24")?, expected);
+    Ok(())
+}
+
+#[test]
+fn deep_route_set_beyond_recursion_limit() -> Result<()> {
+    let depth = crate::cmp::RECURSION_LIMIT as usize * 2;
+    let mut ir = Ir::default();
+    for index in 0..depth {
+        let next = RouteSetMember::NameOp(format!("RS-{}", index + 1), RangeOperator::NoOp);
+        ir.route_sets
+            .insert(format!("RS-{index}"), route_set(vec![next]));
+    }
+    ir.route_sets.insert(
+        format!("RS-{depth}"),
+        route_set(vec![RouteSetMember::RSRange("10.0.0.0/8^+".parse()?)]),
+    );
+    let filter = Filter::RouteSet("RS-0".into(), RangeOperator::NoOp);
+    let query = query_importing(AsExpr::Single(AsName::Num(2)), filter, ir);
+    assert_eq!(check(&query, "10.1.0.0/16")?, vec![]);
+    Ok(())
+}
+
+#[test]
+fn peering_set_cycle_reported() -> Result<()> {
+    let mut ir = Ir::default();
+    for (name, other) in [("PRNG-A", "PRNG-B"), ("PRNG-B", "PRNG-A")] {
+        let peering = Peering {
+            remote_as: AsExpr::PeeringSet(other.into()),
+            remote_router: None,
+            local_router: None,
+        };
+        let peering_set = PeeringSet {
+            body: "".into(),
+            peerings: vec![peering],
+        };
+        ir.peering_sets.insert(name.into(), peering_set);
+    }
+    let query = query_importing(AsExpr::PeeringSet("PRNG-A".into()), Filter::Any, ir);
+    let expected = vec![BadImport {
+        from: 2,
+        to: 1,
+        items: vec![RecSetCycle(vec![
+            "PRNG-A".into(),
+            "PRNG-B".into(),
+            "PRNG-A".into(),
+        ])],
+    }];
+    assert_eq!(check(&query, "10.0.0.0/8")?, expected);
+    Ok(())
+}
+
+/// RS-A0 and RS-B0 both contain RS-A1 and RS-B1, and so on, so there are
+/// 2^64 paths to the last level, which is only resolved once.
+#[test]
+fn diamond_route_sets_resolved_once() -> Result<()> {
+    let levels = 64;
+    let mut ir = Ir::default();
+    for index in 0..levels {
+        let next = ["A", "B"].map(|branch| {
+            RouteSetMember::NameOp(format!("RS-{branch}{}", index + 1), RangeOperator::NoOp)
+        });
+        for branch in ["A", "B"] {
+            ir.route_sets
+                .insert(format!("RS-{branch}{index}"), route_set(next.to_vec()));
+        }
+    }
+    for branch in ["A", "B"] {
+        ir.route_sets.insert(
+            format!("RS-{branch}{levels}"),
+            route_set(vec![RouteSetMember::RSRange("10.0.0.0/8".parse()?)]),
+        );
+    }
+    let filter = Filter::RouteSet("RS-A0".into(), RangeOperator::NoOp);
+    let query = query_importing(AsExpr::Single(AsName::Num(2)), filter, ir);
+    assert_eq!(check(&query, "10.0.0.0/8")?, vec![]);
+    let expected = vec![BadImport {
+        from: 2,
+        to: 1,
+        items: vec![MatchFilterRouteSet("RS-A0".into()), MatchFilter],
+    }];
+    assert_eq!(check(&query, "192.0.2.0/24")?, expected);
+    Ok(())
+}
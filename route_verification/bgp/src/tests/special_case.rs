@@ -0,0 +1,115 @@
+use super::*;
+
+use {AsPathEntry::Seq, Report::*, ReportItem::*};
+
+/// Routes through a known route server are not filtered by RPSL.
+struct RouteServer(u32);
+
+impl SpecialCaseRule for RouteServer {
+    fn name(&self) -> &str {
+        "route_server"
+    }
+
+    fn params(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn check(&self, case: &SpecialCase) -> Option<ReportItem> {
+        (case.from == self.0 || case.to == self.0).then(|| SpecCustomRule(self.name().into()))
+    }
+}
+
+/// AS1 only imports from AS3.
+fn query() -> QueryIr {
+    let mut query = QueryIr::default();
+    let mut aut_num = AutNum::default();
+    aut_num.imports.any.any.push(entry(num(3), Filter::Any));
+    query.aut_nums.insert(1, aut_num);
+    query
+}
+
+fn check(cmp: Compare, db_lines: &[&str]) -> Result<Vec<Report>> {
+    let cmp = cmp.verbosity(Verbosity {
+        show_meh: true,
+        ..Verbosity::default()
+    });
+    Ok(cmp.check_with_relationship(&query(), &AsRelDb::from_lines(db_lines)?))
+}
+
+fn compare() -> Result<Compare> {
+    Ok(Compare::new("10.0.0.0/8".parse()?, vec![Seq(1), Seq(2)]))
+}
+
+fn import(items: Vec<ReportItem>) -> Report {
+    MehImport {
+        from: 2,
+        to: 1,
+        items,
+    }
+}
+
+#[test]
+fn custom_rule() -> Result<()> {
+    let actual = check(compare()?, &[])?;
+    let expected = BadImport {
+        from: 2,
+        to: 1,
+        items: vec![],
+    };
+    assert_eq!(actual, [expected]);
+
+    let cmp = compare()?.special_case_rule(RouteServer(2));
+    let expected = import(vec![SpecCustomRule("route_server".into())]);
+    assert_eq!(check(cmp, &[])?, [expected]);
+    Ok(())
+}
+
+#[test]
+fn builtin_rules_first() -> Result<()> {
+    let cmp = compare()?.special_case_rule(RouteServer(2));
+    let db_lines = ["# input clique: 1 2", "1|2|0"];
+    assert_eq!(check(cmp, &db_lines)?, [import(vec![SpecTier1Pair])]);
+
+    let cmp = Compare {
+        special_case_rules: SpecialCaseRules::empty(),
+        ..compare()?
+    };
+    assert!(matches!(check(cmp, &db_lines)?[..], [BadImport { .. }]));
+    Ok(())
+}
+
+#[test]
+fn siblings() -> Result<()> {
+    let db = AsRelDb::from_as2org_lines(["1|x|a|ORG|o|ARIN", "2|x|b|ORG|o|ARIN"])?;
+    let cmp = compare()?.verbosity(Verbosity {
+        show_meh: true,
+        ..Verbosity::default()
+    });
+    let actual = cmp.check_with_relationship(&query(), &db);
+    assert_eq!(actual, [import(vec![SpecSiblings])]);
+
+    let stats = compare()?.up_down_hill_stats(&query(), &db);
+    assert_eq!((stats.bad_sibling_import, stats.bad_other_import), (1, 0));
+    Ok(())
+}
+
+#[test]
+fn registries_by_rules() -> Result<()> {
+    let cmp = compare()?.special_case_rule(RouteServer(2));
+    let names: Vec<_> = cmp.special_case_rules.names().collect();
+    assert_eq!(
+        names,
+        [
+            "only_provider_policies",
+            "uphill",
+            "tier1_pair",
+            "siblings",
+            "route_server"
+        ]
+    );
+    assert_ne!(cmp, compare()?);
+    assert_eq!(cmp, compare()?.special_case_rule(RouteServer(2)));
+    assert_ne!(cmp, compare()?.special_case_rule(RouteServer(3)));
+    assert!(cmp < compare()?.special_case_rule(RouteServer(3)));
+    Ok(())
+}
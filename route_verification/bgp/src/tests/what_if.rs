@@ -0,0 +1,100 @@
+use super::*;
+
+use {Report::*, ReportItem::*};
+
+/// AS1 imports from AS2 routes originated by AS3.
+fn query() -> QueryIr {
+    let mut query = QueryIr::default();
+    let mut aut_num = AutNum::default();
+    aut_num
+        .imports
+        .any
+        .any
+        .push(entry(num(2), Filter::AsPathRE("AS3$".into())));
+    query.aut_nums.insert(1, aut_num);
+    query
+}
+
+#[test]
+fn would_import() -> Result<()> {
+    let query = query();
+    let prefix = "10.0.0.0/8".parse()?;
+
+    let ok = query.would_import(prefix, 2, 1, &[3]);
+    let rule = RuleIndex {
+        version: PolicyVersion::Any,
+        cast: PolicyCast::Any,
+        entry: 0,
+    };
+    let expected = OkImport {
+        from: 2,
+        to: 1,
+        rule: Some(rule),
+    };
+    assert_eq!(ok.report, expected);
+    assert_eq!(ok.explanation.verdict, Verdict::Ok);
+
+    // AS2 originating the route does not match the AS path regex.
+    let bad = query.would_import(prefix, 2, 1, &[]);
+    assert!(matches!(bad.report, BadImport { from: 2, to: 1, .. }));
+    assert_eq!(bad.explanation.verdict, Verdict::Bad);
+
+    let bad = query.would_import(prefix, 4, 1, &[3]);
+    let expected = BadImport {
+        from: 4,
+        to: 1,
+        items: vec![MatchRemoteAsNum(2)],
+    };
+    assert_eq!(bad.report, expected);
+    Ok(())
+}
+
+#[test]
+fn would_export() -> Result<()> {
+    let query = query();
+    let prefix = "10.0.0.0/8".parse()?;
+
+    let unrec = query.would_export(prefix, 2, 1, &[3]);
+    let expected = UnrecExport {
+        from: 2,
+        to: 1,
+        items: vec![UnrecordedAutNum(2)],
+    };
+    assert_eq!(unrec.report, expected);
+    assert_eq!(unrec.explanation.verdict, Verdict::Unrec);
+
+    let empty = query.would_export(prefix, 1, 2, &[]);
+    let expected = UnrecExport {
+        from: 1,
+        to: 2,
+        items: vec![UnrecExportEmpty],
+    };
+    assert_eq!(empty.report, expected);
+    assert_eq!(empty.explanation.items, [UnrecExportEmpty]);
+    Ok(())
+}
+
+#[test]
+fn would_import_special_asn() -> Result<()> {
+    let query = query();
+    let prefix = "10.0.0.0/8".parse()?;
+
+    let skip = query.would_import(prefix, AS_TRANS, 1, &[3]);
+    let expected = SkipImport {
+        from: AS_TRANS,
+        to: 1,
+        items: vec![SkipAsTrans],
+    };
+    assert_eq!(skip.report, expected);
+    assert_eq!(skip.explanation.verdict, Verdict::Skip);
+
+    let meh = query.would_import(prefix, 2, 64512, &[3]);
+    let expected = MehImport {
+        from: 2,
+        to: 64512,
+        items: vec![SpecNonPublicAsn(64512, AsnClass::Private)],
+    };
+    assert_eq!(meh.report, expected);
+    assert_eq!(meh.explanation.verdict, Verdict::Meh);
+    Ok(())
+}
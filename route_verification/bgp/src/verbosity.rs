@@ -32,6 +32,11 @@ pub struct Verbosity {
     pub check_customer: bool,
     /// Check for ASes that only specify policies for providers.
     pub check_only_provider_policies: bool,
+    /// Report [`RouteObject`] for the route's origin.
+    pub check_route_object: bool,
+    /// Record the [`RuleIndex`] of the entry that accepted the route in
+    /// [`OkImport`] and [`OkExport`].
+    pub record_rule: bool,
 }
 
 impl std::fmt::Debug for Verbosity {
@@ -51,6 +56,8 @@ impl std::fmt::Debug for Verbosity {
             special_uphill,
             check_customer,
             check_only_provider_policies,
+            check_route_object,
+            record_rule,
         } = self;
         for (is_true, tag) in [
             (stop_at_first, "stop_at_first"),
@@ -66,6 +73,8 @@ impl std::fmt::Debug for Verbosity {
             (special_uphill, "special_uphill"),
             (check_customer, "check_customer"),
             (check_only_provider_policies, "check_only_provider_policies"),
+            (check_route_object, "check_route_object"),
+            (record_rule, "record_rule"),
         ] {
             if *is_true {
                 result.entry(&tag);
@@ -118,6 +127,8 @@ impl Verbosity {
             special_uphill: false,
             check_customer: false,
             check_only_provider_policies: false,
+            check_route_object: false,
+            record_rule: false,
         }
     }
 }
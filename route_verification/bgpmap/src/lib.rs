@@ -8,11 +8,12 @@ use anyhow::{bail, Context, Result};
 use ipnet::IpNet;
 use lazy_regex::regex_captures;
 use serde::{Deserialize, Serialize};
+use shared_struct::AS_TRANS;
 
 #[cfg(test)]
 mod tests;
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub struct CollectorPeer {
     pub asn: u32,
     pub ip: IpAddr,
@@ -23,29 +24,56 @@ pub struct CollectorPeer {
 pub enum AsPathEntry {
     Seq(u32),
     Set(Vec<u32>),
+    /// `(65001 65002)`: AS_CONFED_SEQUENCE, member ASes inside a
+    /// confederation.
+    ConfedSeq {
+        confed_seq: Vec<u32>,
+    },
+    /// `[65001 65002]`: AS_CONFED_SET.
+    ConfedSet {
+        confed_set: Vec<u32>,
+    },
 }
 
 impl AsPathEntry {
     pub fn contains_num(&self, num: u32) -> bool {
         match self {
             AsPathEntry::Seq(n) => num == *n,
-            AsPathEntry::Set(ns) => ns.contains(&num),
+            AsPathEntry::Set(ns)
+            | AsPathEntry::ConfedSeq { confed_seq: ns }
+            | AsPathEntry::ConfedSet { confed_set: ns } => ns.contains(&num),
         }
     }
+
+    /// If this is a confederation segment, which does not count towards the
+    /// AS path length.
+    pub fn is_confed(&self) -> bool {
+        matches!(
+            self,
+            AsPathEntry::ConfedSeq { .. } | AsPathEntry::ConfedSet { .. }
+        )
+    }
+
+    /// If this contains AS_TRANS (23456), meaning a 4-byte AS number was not
+    /// reconstructed.
+    pub fn contains_as_trans(&self) -> bool {
+        self.contains_num(AS_TRANS)
+    }
 }
 
 impl Display for AsPathEntry {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let join = |asns: &[u32], separator| {
+            asns.iter()
+                .map(|asn| format!("{asn}"))
+                .collect::<Vec<String>>()
+                .join(separator)
+        };
         match self {
             AsPathEntry::Seq(asn) => write!(f, "{asn}"),
-            AsPathEntry::Set(sset) => write!(
-                f,
-                "{}",
-                sset.iter()
-                    .map(|asn| format!("{asn}"))
-                    .collect::<Vec<String>>()
-                    .join(",")
-            ),
+            AsPathEntry::Set(sset) => write!(f, "{}", join(sset, ",")),
+            AsPathEntry::ConfedSeq { confed_seq } => write!(f, "({})", join(confed_seq, " ")),
+            AsPathEntry::ConfedSet { confed_set } => write!(f, "[{}]", join(confed_set, " ")),
         }
     }
 }
@@ -57,7 +85,9 @@ impl IntoIterator for AsPathEntry {
     fn into_iter(self) -> Self::IntoIter {
         match self {
             AsPathEntry::Seq(asn) => vec![asn].into_iter(),
-            AsPathEntry::Set(sset) => sset.into_iter(),
+            AsPathEntry::Set(sset)
+            | AsPathEntry::ConfedSeq { confed_seq: sset }
+            | AsPathEntry::ConfedSet { confed_set: sset } => sset.into_iter(),
         }
     }
 }
@@ -70,6 +100,24 @@ impl FromStr for AsPathEntry {
         if let Ok(n) = asn {
             return Ok(AsPathEntry::Seq(n));
         }
+        if let Some((_, open, members, close)) =
+            regex_captures!(r"^([(\[])(\d+(?:[ ,]\d+)*)([)\]])$", s)
+        {
+            let members = members
+                .split([' ', ','])
+                .map(str::parse)
+                .collect::<Result<_, _>>()
+                .context("as-path-entry-bad-confed-asn")?;
+            return match (open, close) {
+                ("(", ")") => Ok(AsPathEntry::ConfedSeq {
+                    confed_seq: members,
+                }),
+                ("[", "]") => Ok(AsPathEntry::ConfedSet {
+                    confed_set: members,
+                }),
+                _ => bail!("as-path-entry-mismatched-brackets"),
+            };
+        }
         let (_, cap) =
             regex_captures!(r"\{(\d+(?:,\d+)*)\}", s).context("as-path-entry-no-match")?;
         // Regex should guarantee the unwraps never fail:
@@ -78,6 +126,67 @@ impl FromStr for AsPathEntry {
     }
 }
 
+/// Parse AS path `s` as printed by `bgpdump`: entries separated by single
+/// spaces, where confederation segments such as `(65001 65002)` may contain
+/// spaces.
+pub fn parse_as_path(s: &str) -> Result<Vec<AsPathEntry>> {
+    let mut entries = Vec::new();
+    let mut rest = s;
+    loop {
+        let end = match rest.chars().next() {
+            Some(open @ ('(' | '[' | '{')) => {
+                let close = match open {
+                    '(' => ')',
+                    '[' => ']',
+                    _ => '}',
+                };
+                rest.find(close)
+                    .with_context(|| format!("unclosed `{open}` in AS path `{s}`"))?
+                    + 1
+            }
+            _ => rest.find(' ').unwrap_or(rest.len()),
+        };
+        entries.push(rest[..end].parse()?);
+        rest = match &rest[end..] {
+            "" => return Ok(entries),
+            remaining => remaining
+                .strip_prefix(' ')
+                .with_context(|| format!("expected space before `{remaining}` in AS path `{s}`"))?,
+        };
+    }
+}
+
+/// Reconstruct the AS path of a route from a 2-byte BGP speaker from its
+/// AS_PATH `as_path` and AS4_PATH `as4_path`, as in RFC 6793 section 4.2.3.
+/// `as_path` is used as is if `as4_path` is longer.
+/// Confederation segments in `as4_path` are discarded.
+/// AS_TRANS may remain if a 4-byte AS number cannot be reconstructed, which
+/// [`AsPathEntry::contains_as_trans`] detects.
+///
+/// [`parse_table_dump`] does not call this because `bgpdump -m` only prints
+/// the AS path after already merging any AS4_PATH into it;
+/// this is for callers reading the two attributes separately.
+pub fn merge_as4_path(as_path: &[AsPathEntry], as4_path: &[AsPathEntry]) -> Vec<AsPathEntry> {
+    let path_len = |path: &[AsPathEntry]| path.iter().filter(|entry| !entry.is_confed()).count();
+    let (n_as, n_as4) = (path_len(as_path), path_len(as4_path));
+    if n_as < n_as4 {
+        return as_path.to_vec();
+    }
+    let mut merged = Vec::with_capacity(as_path.len());
+    let mut n_kept = 0;
+    for entry in as_path {
+        if n_kept == n_as - n_as4 {
+            break;
+        }
+        if !entry.is_confed() {
+            n_kept += 1;
+        }
+        merged.push(entry.clone());
+    }
+    merged.extend(as4_path.iter().filter(|entry| !entry.is_confed()).cloned());
+    merged
+}
+
 /// Return (IP prefix, AS-path, BGP collector, communities).
 pub fn parse_table_dump(line: &str) -> Result<(IpNet, Vec<AsPathEntry>, CollectorPeer, Vec<&str>)> {
     // TABLE_DUMP2|1619481601|B|94.156.252.18|34224|6.132.0.0/14|34224 6939 8003|IGP|94.156.252.18|0|0|34224:333 34224:334 34224:2040|NAG|||
@@ -96,10 +205,7 @@ pub fn parse_table_dump(line: &str) -> Result<(IpNet, Vec<AsPathEntry>, Collecto
     };
     let prefix = fields[5].parse().context("bad-prefix")?;
 
-    let aspath: Vec<AsPathEntry> = fields[6]
-        .split(' ')
-        .map(|e| e.parse())
-        .collect::<Result<_>>()?;
+    let aspath = parse_as_path(fields[6])?;
 
     let communities = fields[11].split_whitespace().collect();
     Ok((prefix, aspath, vp, communities))
@@ -92,3 +92,78 @@ fn table_dump() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn confed_segments() -> Result<()> {
+    use AsPathEntry::*;
+
+    let line = "TABLE_DUMP2|1619481601|B|94.156.252.18|34224|6.132.0.0/14|34224 (65001 65002) [65003 65004] 6939 {8003,8004}|IGP|94.156.252.18|0|0||NAG|||";
+    let (_pfx, aspath, _vp, _) = parse_table_dump(line)?;
+    assert_eq!(
+        aspath,
+        vec![
+            Seq(34224),
+            ConfedSeq {
+                confed_seq: vec![65001, 65002]
+            },
+            ConfedSet {
+                confed_set: vec![65003, 65004]
+            },
+            Seq(6939),
+            Set(vec![8003, 8004]),
+        ]
+    );
+    assert_eq!(
+        aspath.iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+        [
+            "34224",
+            "(65001 65002)",
+            "[65003 65004]",
+            "6939",
+            "8003,8004"
+        ]
+    );
+    assert!(aspath[1].is_confed() && !aspath[4].is_confed());
+    assert!(aspath[1].contains_num(65002));
+
+    for bad in ["1 (65001 65002", "1 (65001]", "1 [65001,x]", "(1)2"] {
+        assert!(parse_as_path(bad).is_err(), "{bad}");
+    }
+    assert!(parse_as_path("").is_err());
+    Ok(())
+}
+
+#[test]
+fn confed_serde() -> Result<()> {
+    let path = parse_as_path("1 (2 3) [4] {5,6}")?;
+    let json = serde_json::to_string(&path)?;
+    assert_eq!(json, r#"[1,{"confed_seq":[2,3]},{"confed_set":[4]},[5,6]]"#);
+    let deserialized: Vec<AsPathEntry> = serde_json::from_str(&json)?;
+    assert_eq!(deserialized, path);
+    Ok(())
+}
+
+#[test]
+fn as4_path_reconstruction() -> Result<()> {
+    for (as_path, as4_path, expected) in [
+        (
+            "1 23456 23456 3",
+            "4200000000 4200000001 3",
+            "1 4200000000 4200000001 3",
+        ),
+        (
+            "(65001) 1 23456 3",
+            "4200000000 3",
+            "(65001) 1 4200000000 3",
+        ),
+        ("1 23456 3", "(65001) 4200000000 3", "1 4200000000 3"),
+        ("23456 3", "1 4200000000 3", "23456 3"),
+        ("1 {2,23456} 3", "{2,4200000000} 3", "1 {2,4200000000} 3"),
+    ] {
+        let merged = merge_as4_path(&parse_as_path(as_path)?, &parse_as_path(as4_path)?);
+        assert_eq!(merged, parse_as_path(expected)?, "{as_path} + {as4_path}");
+    }
+    let merged = merge_as4_path(&parse_as_path("1 23456 3")?, &parse_as_path("3")?);
+    assert!(merged.iter().any(AsPathEntry::contains_as_trans));
+    Ok(())
+}
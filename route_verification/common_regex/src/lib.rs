@@ -21,4 +21,15 @@ macro_rules! regex {
     }};
 }
 
+/// Parse `AS<asplain>` or, as in RFC 5396, `AS<high>.<low>` (asdot),
+/// case-insensitively; `None` if `name` is neither or out of range.
+pub fn parse_asn(name: &str) -> Option<u32> {
+    if let Some((_, high, low)) = lazy_regex::regex_captures!(r"^AS(\d+)\.(\d+)$"i, name) {
+        let (high, low) = (high.parse::<u16>().ok()?, low.parse::<u16>().ok()?);
+        return Some(((high as u32) << 16) | low as u32);
+    }
+    let (_, num) = lazy_regex::regex_captures!(r"^AS(\d+)$"i, name)?;
+    num.parse().ok()
+}
+
 pub const RANGE_OPERATOR: &str = r"\^(:?\-|\+|[0-9]+(:?\-[0-9]+)?)";
@@ -25,8 +25,8 @@ use super::*;
 /// > the last character of a name must be a letter or a digit.
 pub const OBJECT_NAME: &str = r"[A-Za-z0-9_\-]*[A-Za-z0-9]";
 
-/// AS number.
-pub const ASN: &str = "as[0-9]+";
+/// AS number, in asplain or asdot notation.
+pub const ASN: &str = r"as(?:[0-9]{1,5}\.[0-9]{1,5}|[0-9]+)";
 
 macro_rules! set_of {
     ($base:expr, $or_name:ident, $set_name:ident, $doc:expr) => {
@@ -84,3 +84,13 @@ set_of!(
     PEERING_SET,
     r#"> The peering-set attribute defines the name of the set.  It is an RPSL name that starts with "prng-". "#
 );
+
+/// Base Router Set name.
+pub const RTR_SET_BASE: &str = formatcp!("rtrs-{}", OBJECT_NAME);
+
+set_of!(
+    RTR_SET_BASE,
+    RTR_SET_BASE_OR_ASN,
+    RTR_SET,
+    r#"> The rtr-set attribute defines the name of the set.  It is an RPSL name that starts with "rtrs-"."#
+);
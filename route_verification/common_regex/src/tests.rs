@@ -26,7 +26,18 @@ fn all_names() {
 
 #[test]
 fn range_operator() {
+    let range_operator = regex!(RANGE_OPERATOR);
     for op in ["^-", "^+", "^32", "^20-24"] {
-        assert!(regex!(RANGE_OPERATOR).is_match(op));
+        assert!(range_operator.is_match(op));
     }
 }
+
+#[test]
+fn asn() {
+    assert_eq!(parse_asn("AS3356"), Some(3356));
+    assert_eq!(parse_asn("as1.10"), Some(65546));
+    assert_eq!(parse_asn("AS65535.65535"), Some(u32::MAX));
+    assert_eq!(parse_asn("AS4294967296"), None);
+    assert_eq!(parse_asn("AS65536.0"), None);
+    assert_eq!(parse_asn("AS-A"), None);
+}
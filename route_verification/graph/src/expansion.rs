@@ -0,0 +1,68 @@
+use super::*;
+
+/// Members of an as-set with the sets that brought them in,
+/// generated by [`expand_as_set`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AsSetExpansion {
+    /// Each member AS number with the shortest chain of sets that brought it
+    /// in, from the expanded set to the set directly containing it.
+    pub members: BTreeMap<u32, Vec<String>>,
+    /// Each unrecorded set reached, with the shortest chain of sets
+    /// referencing it, as in `members`.
+    pub unrecorded_members: BTreeMap<String, Vec<String>>,
+    /// Sets in each membership cycle encountered, sorted.
+    pub cycles: Vec<Vec<String>>,
+    /// Number of sets in the longest chain in `members`.
+    pub depth: usize,
+}
+
+/// Expand as-set `name` in `as_sets`, recording for each member the shortest
+/// chain of nested sets that brought it in, unlike flattening the set.
+/// Ties are broken by the lexicographically smallest chain.
+/// Pseudo sets, such as `m#` sets of `mbrs-by-ref` members and `c#` customer
+/// sets, appear in chains like other sets if they are in `as_sets`.
+///
+/// Returns `None` if `name` is not in `as_sets`.
+pub fn expand_as_set(name: &str, as_sets: &BTreeMap<String, AsSet>) -> Option<AsSetExpansion> {
+    as_sets.get(name)?;
+    let (graph, index) = ASSetGraph::from_as_set(name, as_sets);
+    let predecessors = graph.shortest_predecessors(index);
+    let set_name = |index| match &graph.graph[index] {
+        ASNumOrSet::Set(set) => set.clone(),
+        ASNumOrSet::Num(_) => unreachable!("AS numbers do not have members"),
+    };
+    let chain = |mut index| {
+        let mut chain = Vec::new();
+        while let Some(predecessor) = predecessors.get(&index) {
+            chain.push(set_name(*predecessor));
+            index = *predecessor;
+        }
+        chain.reverse();
+        chain
+    };
+
+    let mut expansion = AsSetExpansion::default();
+    for (member, member_index) in &graph.as_num_and_sets {
+        match member {
+            ASNumOrSet::Num(num) => _ = expansion.members.insert(*num, chain(*member_index)),
+            ASNumOrSet::Set(set) if !as_sets.contains_key(set) => {
+                _ = expansion
+                    .unrecorded_members
+                    .insert(set.clone(), chain(*member_index))
+            }
+            ASNumOrSet::Set(_) => {}
+        }
+    }
+    expansion.cycles = graph
+        .cycles()
+        .into_iter()
+        .map(|cycle| {
+            let mut cycle: Vec<_> = cycle.into_iter().map(set_name).collect();
+            cycle.sort_unstable();
+            cycle
+        })
+        .collect();
+    expansion.cycles.sort_unstable();
+    expansion.depth = expansion.members.values().map(Vec::len).max().unwrap_or(0);
+    Some(expansion)
+}
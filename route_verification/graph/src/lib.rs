@@ -1,14 +1,20 @@
 //! The RPSL `as-set` and `as-num` directed graph.
-use hashbrown::HashMap;
+use std::collections::{BTreeMap, VecDeque};
+
+use hashbrown::{HashMap, HashSet};
+use ir::AsSet;
 use petgraph::{
-    algo::{dijkstra, is_cyclic_directed},
+    algo::{dijkstra, is_cyclic_directed, tarjan_scc},
     dot::Dot,
     prelude::{DiGraph, NodeIndex},
 };
 
+mod expansion;
 #[cfg(test)]
 mod tests;
 
+pub use expansion::*;
+
 /// AS Sets and AS Num membership graph.
 /// The display format can be used with Graphviz to visualize the graph.
 /// (Try print one out and paste it into Graphviz online.)
@@ -34,6 +40,34 @@ impl ASSetGraph {
         }
     }
 
+    /// Graph of set `name` and all the sets and AS numbers reachable from it
+    /// in `as_sets`, and the index of `name`.
+    /// Sets not in `as_sets` are included without members.
+    pub fn from_as_set(name: &str, as_sets: &BTreeMap<String, AsSet>) -> (Self, NodeIndex) {
+        let mut graph = Self::default();
+        let index = graph.get_or_insert(ASNumOrSet::set(name));
+        let mut visited: HashSet<_> = [name].into_iter().collect();
+        let mut to_visit = vec![name];
+        while let Some(set_name) = to_visit.pop() {
+            let Some(as_set) = as_sets.get(set_name) else {
+                continue;
+            };
+            let members = as_set
+                .set_members
+                .iter()
+                .cloned()
+                .map(ASNumOrSet::Set)
+                .chain(as_set.members.iter().copied().map(ASNumOrSet::Num));
+            graph.add_members(members, ASNumOrSet::set(set_name));
+            for set_member in &as_set.set_members {
+                if visited.insert(set_member) {
+                    to_visit.push(set_member);
+                }
+            }
+        }
+        (graph, index)
+    }
+
     pub fn get_or_insert(&mut self, as_num_or_set: ASNumOrSet) -> NodeIndex {
         if let Some(index) = self.as_num_and_sets.get(&as_num_or_set) {
             *index
@@ -93,7 +127,37 @@ impl ASSetGraph {
         is_cyclic_directed(&self.graph)
     }
 
-    pub fn to_dot(&self) -> Dot<&DiGraph<ASNumOrSet, u32>> {
+    /// Nodes in each membership cycle, i.e., strongly connected component
+    /// with more than one node or with a set containing itself.
+    pub fn cycles(&self) -> Vec<Vec<NodeIndex>> {
+        tarjan_scc(&self.graph)
+            .into_iter()
+            .filter(|nodes| nodes.len() > 1 || self.graph.contains_edge(nodes[0], nodes[0]))
+            .collect()
+    }
+
+    /// Predecessor of each node reachable from `from` on a shortest path
+    /// from it, counting each edge as 1.
+    /// Ties are broken by the smallest chain of predecessors per
+    /// [`ASNumOrSet`]'s ordering. `from` has no predecessor.
+    pub fn shortest_predecessors(&self, from: NodeIndex) -> HashMap<NodeIndex, NodeIndex> {
+        let mut predecessors = HashMap::new();
+        let mut visited: HashSet<_> = [from].into_iter().collect();
+        let mut to_visit = VecDeque::from([from]);
+        while let Some(index) = to_visit.pop_front() {
+            let mut neighbors: Vec<_> = self.graph.neighbors(index).collect();
+            neighbors.sort_unstable_by_key(|neighbor| &self.graph[*neighbor]);
+            for neighbor in neighbors {
+                if visited.insert(neighbor) {
+                    predecessors.insert(neighbor, index);
+                    to_visit.push_back(neighbor);
+                }
+            }
+        }
+        predecessors
+    }
+
+    pub fn to_dot(&self) -> Dot<'_, &DiGraph<ASNumOrSet, u32>> {
         Dot::new(&self.graph)
     }
 }
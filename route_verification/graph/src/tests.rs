@@ -156,3 +156,83 @@ fn stats() {
     let has_cycle = graph.has_cycle();
     assert!(has_cycle);
 }
+
+#[test]
+fn from_as_set() {
+    let as_sets = BTreeMap::from([
+        (
+            "AS-BC".into(),
+            AsSet::new("".into(), vec![113], vec!["AS-DE".into(), "AS-XY".into()]),
+        ),
+        (
+            "AS-DE".into(),
+            AsSet::new("".into(), vec![114], vec!["AS-BC".into()]),
+        ),
+        ("AS-FG".into(), AsSet::new("".into(), vec![115], vec![])),
+    ]);
+    let (graph, set_index) = ASSetGraph::from_as_set("AS-BC", &as_sets);
+
+    let mut nodes: Vec<_> = graph
+        .as_num_and_sets
+        .keys()
+        .map(|n| n.to_string())
+        .collect();
+    nodes.sort_unstable();
+    assert_eq!(nodes, ["AS-BC", "AS-DE", "AS-XY", "AS113", "AS114"]);
+
+    let index = |node: ASNumOrSet| graph.as_num_and_sets[&node];
+    let predecessors = graph.shortest_predecessors(set_index);
+    assert_eq!(predecessors.get(&set_index), None);
+    assert_eq!(
+        predecessors[&index(ASNumOrSet::Num(114))],
+        index(ASNumOrSet::set("AS-DE"))
+    );
+    assert_eq!(predecessors[&index(ASNumOrSet::Num(113))], set_index);
+
+    let mut cycles = graph.cycles();
+    assert_eq!(cycles.len(), 1);
+    cycles[0].sort_unstable();
+    assert_eq!(cycles[0], vec![set_index, index(ASNumOrSet::set("AS-DE"))]);
+}
+
+fn as_set(members: Vec<u32>, set_members: &[&str]) -> AsSet {
+    let set_members = set_members.iter().map(|s| s.to_string()).collect();
+    AsSet::new("".into(), members, set_members)
+}
+
+fn chain(sets: &[&str]) -> Vec<String> {
+    sets.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn expand_with_provenance() {
+    let as_sets = BTreeMap::from([
+        (
+            "AS-BIG".into(),
+            as_set(vec![1], &["AS-MID", "m#AS-BIG", "AS-GONE"]),
+        ),
+        ("AS-MID".into(), as_set(vec![2, 64500], &["AS-LOOP", "c#5"])),
+        ("AS-LOOP".into(), as_set(vec![3, 64500], &["AS-MID"])),
+        ("c#5".into(), as_set(vec![6], &[])),
+        ("m#AS-BIG".into(), as_set(vec![64500], &["m#AS-BIG#MNT"])),
+        ("m#AS-BIG#MNT".into(), as_set(vec![4], &[])),
+    ]);
+    let expansion = expand_as_set("AS-BIG", &as_sets).unwrap();
+
+    let expected = BTreeMap::from([
+        (1, chain(&["AS-BIG"])),
+        (2, chain(&["AS-BIG", "AS-MID"])),
+        (3, chain(&["AS-BIG", "AS-MID", "AS-LOOP"])),
+        (4, chain(&["AS-BIG", "m#AS-BIG", "m#AS-BIG#MNT"])),
+        (6, chain(&["AS-BIG", "AS-MID", "c#5"])),
+        // Also in `m#AS-BIG` at the same depth.
+        (64500, chain(&["AS-BIG", "AS-MID"])),
+    ]);
+    assert_eq!(expansion.members, expected);
+    let expected = BTreeMap::from([("AS-GONE".into(), chain(&["AS-BIG"]))]);
+    assert_eq!(expansion.unrecorded_members, expected);
+    assert_eq!(expansion.cycles, vec![chain(&["AS-LOOP", "AS-MID"])]);
+    assert_eq!(expansion.depth, 3);
+
+    assert_eq!(expand_as_set("AS-GONE", &as_sets), None);
+}
@@ -47,6 +47,12 @@ impl std::fmt::Debug for AddrPfxRange {
     }
 }
 
+impl std::fmt::Display for AddrPfxRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.address_prefix, self.range_operator)
+    }
+}
+
 impl FromStr for AddrPfxRange {
     type Err = anyhow::Error;
 
@@ -168,6 +174,62 @@ impl RangeOperator {
             RangeOperator::Range(n, m) => (n..=m).contains(&prefix_len),
         }
     }
+
+    /// The range operator standing for `outer` applied to `prefix` followed
+    /// by `self`, or `None` if that stands for no prefix at all.
+    ///
+    /// > Range operators can also be applied to address prefix sets.  In this
+    /// > case, they distribute over the members of the set.
+    ///
+    /// <https://www.rfc-editor.org/rfc/rfc2622#page-20>.
+    /// `outer` is applied to each prefix `self` stands for, so
+    /// `30.0.0.0/8^16` with `^24-32` applied stands for `30.0.0.0/8^24-32`,
+    /// and `5.0.0.0/8^-` with `^-` applied stands for `5.0.0.0/8^10-32`.
+    pub fn compose(self, outer: Self, prefix: &IpNet) -> Option<Self> {
+        use RangeOperator::*;
+        let (len, max_len) = (prefix.prefix_len(), prefix.max_prefix_len());
+        if outer == NoOp {
+            return Some(self);
+        }
+        // Shortest and longest lengths `self` stands for.
+        let (low, high) = match self {
+            NoOp => (len, len),
+            Minus => (len + 1, max_len),
+            Plus => (len, max_len),
+            Num(n) => (n.max(len), n),
+            Range(n, m) => (n.max(len), m.min(max_len)),
+        };
+        if low > high {
+            return None;
+        }
+        // Lengths `outer` stands for when applied to all of them.
+        let (low, high) = match outer {
+            NoOp => unreachable!("returned above"),
+            Minus => (low + 1, max_len),
+            Plus => (low, max_len),
+            Num(n) => (n.max(low), n),
+            Range(n, m) => (n.max(low), m),
+        };
+        let high = high.min(max_len);
+        Some(match (low, high) {
+            (low, high) if low > high => return None,
+            (low, high) if low == len && high == len => NoOp,
+            (low, high) if low == len + 1 && high == max_len => Minus,
+            (low, high) if low == len && high == max_len => Plus,
+            (low, high) if low == high => Num(low),
+            (low, high) => Range(low, high),
+        })
+    }
+
+    /// Apply each of `outers`, innermost first, on top of `self` for `prefix`.
+    pub fn compose_all<'a, I>(self, outers: I, prefix: &IpNet) -> Option<Self>
+    where
+        I: IntoIterator<Item = &'a Self>,
+    {
+        outers
+            .into_iter()
+            .try_fold(self, |op, outer| op.compose(*outer, prefix))
+    }
 }
 
 pub fn get_range_operator_num(s: &str) -> Option<(&str, &str)> {
@@ -184,23 +246,41 @@ pub fn get_range_operator_range(s: &str) -> Option<(&str, &str, &str)> {
 /// contains `ip`.
 /// Stop searching either end when the index do not point to `ip`'s siblings.
 pub fn match_ips(ip: &IpNet, ips: &[IpNet], range_operator: RangeOperator) -> bool {
+    match_ips_composed(ip, ips, range_operator, &[])
+}
+
+/// Same as [`match_ips`], but with each of `outers`, innermost first,
+/// composed on top of `range_operator` for each address prefix in `ips`.
+pub fn match_ips_composed(
+    ip: &IpNet,
+    ips: &[IpNet],
+    range_operator: RangeOperator,
+    outers: &[RangeOperator],
+) -> bool {
     let center = ips.binary_search(ip);
 
     // Exact match.
-    if let RangeOperator::NoOp = range_operator {
+    if let (RangeOperator::NoOp, true) = (
+        range_operator,
+        outers.iter().all(|op| *op == RangeOperator::NoOp),
+    ) {
         return center.is_ok();
     }
 
+    let contains = |value: &IpNet| match range_operator.compose_all(outers, value) {
+        Some(range_operator) => address_prefix_contains(value, range_operator, ip),
+        None => false,
+    };
     let center = center.map_or_else(identity, identity);
     // Check center.
     if let Some(value) = ips.get(center) {
-        if address_prefix_contains(value, range_operator, ip) {
+        if contains(value) {
             return true;
         }
     }
     // Check right.
     for value in &ips[(center + 1).min(ips.len())..] {
-        if address_prefix_contains(value, range_operator, ip) {
+        if contains(value) {
             return true;
         }
         if !ip.is_sibling(value) {
@@ -208,11 +288,8 @@ pub fn match_ips(ip: &IpNet, ips: &[IpNet], range_operator: RangeOperator) -> bo
         }
     }
     // Check left.
-    for value in ips[..(center.saturating_sub(1)).max(ips.len())]
-        .iter()
-        .rev()
-    {
-        if address_prefix_contains(value, range_operator, ip) {
+    for value in ips[..center.min(ips.len())].iter().rev() {
+        if contains(value) {
             return true;
         }
         if !ip.is_sibling(value) {
@@ -1,5 +1,4 @@
 use super::*;
-use lazy_regex::regex_captures;
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub enum AsName {
@@ -9,6 +8,17 @@ pub enum AsName {
     Invalid(String),
 }
 
+impl std::fmt::Display for AsName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Any => f.write_str("AS-ANY"),
+            Self::Num(num) => write!(f, "AS{num}"),
+            Self::Set(name) => f.write_str(name),
+            Self::Invalid(reason) => write!(f, "<invalid: {reason}>"),
+        }
+    }
+}
+
 /// A simple AS field is either a AS number or a AS set.
 /// Otherwise, return `AsExpr::Invalid`.
 pub fn parse_as_name(field: String) -> Result<AsName> {
@@ -22,12 +32,41 @@ pub fn parse_as_name(field: String) -> Result<AsName> {
     })
 }
 
+/// Parse `AS<asplain>` or, as in RFC 5396, `AS<high>.<low>` (asdot).
 pub fn parse_aut_num_name(name: &str) -> Result<u32> {
-    match regex_captures!(r"^AS(\d+)$"i, name) {
-        Some((_, num)) => num
-            .parse()
-            .map_err(|err| Error::new(err).context(format!("parsing {name}"))),
-        None => bail!("AS number name `{name}` does not match pattern"),
+    parse_asn(name).with_context(|| format!("`{name}` is not an asplain or asdot AS number"))
+}
+
+/// What an AS number is reserved for, if anything.
+///
+/// <https://www.iana.org/assignments/iana-as-numbers-special-registry>.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum AsnClass {
+    Public,
+    /// 64512-65534 and 4200000000-4294967294 (RFC 6996).
+    Private,
+    /// 64496-64511 and 65536-65551 (RFC 5398).
+    Documentation,
+    /// 23456 (RFC 6793), stands in for 4-byte ASNs on 2-byte speakers.
+    AsTrans,
+    /// 0 (RFC 7607), 65535 and 4294967295 (RFC 7300),
+    /// and 65552-131071 (RFC 5398).
+    Reserved,
+}
+
+impl AsnClass {
+    pub const fn is_public(self) -> bool {
+        matches!(self, Self::Public)
+    }
+}
+
+pub const fn classify_asn(asn: u32) -> AsnClass {
+    match asn {
+        AS_TRANS => AsnClass::AsTrans,
+        64496..=64511 | 65536..=65551 => AsnClass::Documentation,
+        64512..=65534 | 4200000000..=4294967294 => AsnClass::Private,
+        0 | 65535 | 65552..=131071 | 4294967295 => AsnClass::Reserved,
+        _ => AsnClass::Public,
     }
 }
 
@@ -182,3 +182,39 @@ impl std::fmt::Debug for Filter {
         }
     }
 }
+
+/// `<mp-filter>` as in RPSL.
+impl std::fmt::Display for Filter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use Filter::*;
+        match self {
+            FilterSet(name) | Unknown(name) => f.write_str(name),
+            Any => f.write_str("ANY"),
+            AddrPrefixSet(prefixes) => {
+                f.write_str("{")?;
+                for (index, prefix) in prefixes.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{prefix}")?;
+                }
+                f.write_str("}")
+            }
+            RouteSet(name, op) | AsSet(name, op) => write!(f, "{name}{op}"),
+            AsNum(num, op) => write!(f, "AS{num}{op}"),
+            AsPathRE(expr) => write!(f, "<{expr}>"),
+            PeerAS => f.write_str("PeerAS"),
+            And { left, right } => write!(f, "{left} AND {right}"),
+            Or { left, right } => write!(f, "{left} OR {right}"),
+            Not(filter) => write!(f, "NOT {filter}"),
+            Group(filter) => write!(f, "({filter})"),
+            Community(Call { method, args }) => {
+                f.write_str("community")?;
+                if let Some(method) = method {
+                    write!(f, ".{method}")?;
+                }
+                write!(f, "({})", args.join(", "))
+            }
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use std::net::IpAddr;
+
+use super::*;
+
+/// > Routers are specified using the inet-rtr class.
+///
+/// <https://www.rfc-editor.org/rfc/rfc2622#section-9>
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct InetRtr {
+    pub body: String,
+    /// AS number of the AS that owns or operates this router.
+    pub local_as: u32,
+    /// Addresses of the router's interfaces, from `ifaddr` and `interface`.
+    pub ifaddrs: Vec<IpAddr>,
+    /// From `peer` and `mp-peer`.
+    pub peers: Vec<RtrPeer>,
+}
+
+/// > `<protocol> <ipv4-address> <options>
+/// > | <protocol> <inet-rtr-name> <options>
+/// > | <protocol> <rtr-set-name> <options>
+/// > | <protocol> <peering-set-name> <options>`
+///
+/// <https://www.rfc-editor.org/rfc/rfc2622#section-9>
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct RtrPeer {
+    pub protocol: String,
+    pub peer: RouterExpr,
+    pub options: String,
+}
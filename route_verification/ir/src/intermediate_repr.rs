@@ -24,6 +24,10 @@ pub struct Ir {
     /// <https://www.rfc-editor.org/rfc/rfc2622#section-4>.
     /// Each value should always be sorted.
     pub as_routes: BTreeMap<u32, Vec<IpNet>>,
+    #[serde(default)]
+    pub inet_rtrs: BTreeMap<String, InetRtr>,
+    #[serde(default)]
+    pub rtr_sets: BTreeMap<String, RtrSet>,
 }
 
 pub fn split_n_btreemap<K, V>(mut map: BTreeMap<K, V>, n: usize) -> Vec<BTreeMap<K, V>>
@@ -55,6 +59,8 @@ impl Ir {
             peering_sets,
             filter_sets,
             as_routes,
+            inet_rtrs,
+            rtr_sets,
         } = self;
         let aut_num_splits = split_n_btreemap(aut_nums, n);
         let as_set_splits = split_n_btreemap(as_sets, n);
@@ -62,6 +68,8 @@ impl Ir {
         let peering_set_splits = split_n_btreemap(peering_sets, n);
         let filter_set_splits = split_n_btreemap(filter_sets, n);
         let as_route_splits = split_n_btreemap(as_routes, n);
+        let inet_rtr_splits = split_n_btreemap(inet_rtrs, n);
+        let rtr_set_splits = split_n_btreemap(rtr_sets, n);
 
         izip!(
             aut_num_splits,
@@ -69,16 +77,29 @@ impl Ir {
             route_set_splits,
             peering_set_splits,
             filter_set_splits,
-            as_route_splits
+            as_route_splits,
+            inet_rtr_splits,
+            rtr_set_splits
         )
         .map(
-            |(aut_nums, as_sets, route_sets, peering_sets, filter_sets, as_routes)| Self {
+            |(
                 aut_nums,
                 as_sets,
                 route_sets,
                 peering_sets,
                 filter_sets,
                 as_routes,
+                inet_rtrs,
+                rtr_sets,
+            )| Self {
+                aut_nums,
+                as_sets,
+                route_sets,
+                peering_sets,
+                filter_sets,
+                as_routes,
+                inet_rtrs,
+                rtr_sets,
             },
         )
         .collect()
@@ -112,12 +133,16 @@ impl Ir {
             peering_sets,
             filter_sets,
             as_routes,
+            inet_rtrs,
+            rtr_sets,
         } = other;
         self.aut_nums.extend(aut_nums);
         self.as_sets.extend(as_sets);
         self.route_sets.extend(route_sets);
         self.peering_sets.extend(peering_sets);
         self.filter_sets.extend(filter_sets);
+        self.inet_rtrs.extend(inet_rtrs);
+        self.rtr_sets.extend(rtr_sets);
 
         for (num, as_route) in as_routes {
             match self.as_routes.entry(num) {
@@ -163,16 +188,20 @@ impl std::fmt::Display for Ir {
             peering_sets,
             filter_sets,
             as_routes,
+            inet_rtrs,
+            rtr_sets,
         } = self;
         let n_route: usize = as_routes.values().map(Vec::len).sum();
         f.write_fmt(format_args!(
-            "{} aut_nums, {} as_sets, {} route_sets, {} peering_sets, {} filter_sets, {} as_routes ({n_route} routes)",
+            "{} aut_nums, {} as_sets, {} route_sets, {} peering_sets, {} filter_sets, {} as_routes ({n_route} routes), {} inet_rtrs, {} rtr_sets",
             aut_nums.len(),
             as_sets.len(),
             route_sets.len(),
             peering_sets.len(),
             filter_sets.len(),
             as_routes.len(),
+            inet_rtrs.len(),
+            rtr_sets.len(),
         ))
     }
 }
@@ -2,7 +2,7 @@
 //! the Routing Policy Specification Language (RPSL).
 use std::collections::BTreeMap;
 
-use anyhow::{bail, Context, Error, Result};
+use anyhow::{bail, Context, Result};
 use common_regex::{set::*, *};
 use ipnet::IpNet;
 use rayon::prelude::*;
@@ -12,9 +12,12 @@ pub mod address_prefix;
 pub mod aut_num;
 pub mod aut_sys;
 pub mod filter;
+pub mod inet_rtr;
 pub mod intermediate_repr;
+pub mod lint;
 pub mod mp_import;
 pub mod peering;
+pub mod reference;
 pub mod router_expr;
 pub mod set;
 #[cfg(test)]
@@ -22,14 +25,22 @@ mod tests;
 
 #[doc(inline)]
 pub use {
-    address_prefix::{match_ips, AddrPfxRange, RangeOperator},
+    address_prefix::{match_ips, match_ips_composed, AddrPfxRange, RangeOperator},
     aut_num::AutNum,
-    aut_sys::{is_as_set, is_pseudo_set, parse_as_name, parse_aut_num_name, AsName},
+    aut_sys::{
+        classify_asn, is_as_set, is_pseudo_set, parse_as_name, parse_aut_num_name, AsName, AsnClass,
+    },
     filter::{is_any, is_filter_set, Filter},
+    inet_rtr::{InetRtr, RtrPeer},
     intermediate_repr::{merge_irs, Ir},
-    mp_import::{Casts, Entry, Versions},
+    lint::Lint,
+    mp_import::{Casts, Entry, PolicyCast, PolicyVersion, RuleIndex, Versions},
     peering::{is_peering_set, parse_single_as_expr, AsExpr, Peering, PeeringAction},
+    reference::{ObjectName, ReferenceIndex, Referrer},
     router_expr::{parse_simple_router_expr, RouterExpr},
-    set::{is_route_set_name, AsSet, FilterSet, PeeringSet, RouteSet, RouteSetMember},
-    shared_struct::{action::*, community::Call, stats::Counts},
+    set::{
+        is_route_set_name, is_rtr_set_name, AsSet, FilterSet, PeeringSet, RouteSet, RouteSetMember,
+        RtrSet,
+    },
+    shared_struct::{action::*, community::Call, stats::Counts, SourceSpan, AS_TRANS},
 };
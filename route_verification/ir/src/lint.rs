@@ -0,0 +1,122 @@
+//! Diagnostics about RPSL that parses fine but is likely a mistake.
+use std::collections::BTreeSet;
+
+use itertools::chain;
+
+use super::*;
+
+/// A diagnostic about an object in the [`Ir`].
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum Lint {
+    /// An aut-num object for an AS number that is not public.
+    NonPublicAutNum(u32, AsnClass),
+    /// Imports or exports of `aut_num` refer to `asn`,
+    /// either as a peering or in a filter.
+    NonPublicAsnInPolicy {
+        aut_num: u32,
+        asn: u32,
+        class: AsnClass,
+    },
+    /// `as_set` has `asn` as a member.
+    NonPublicAsnInAsSet {
+        as_set: String,
+        asn: u32,
+        class: AsnClass,
+    },
+    /// `route_set` has `asn` as a member.
+    NonPublicAsnInRouteSet {
+        route_set: String,
+        asn: u32,
+        class: AsnClass,
+    },
+}
+
+impl Ir {
+    /// Find AS numbers that are private, for documentation, AS_TRANS,
+    /// or reserved, where only public AS numbers make sense.
+    pub fn lint(&self) -> Vec<Lint> {
+        let mut lints = Vec::new();
+        for (num, aut_num) in &self.aut_nums {
+            let class = classify_asn(*num);
+            if !class.is_public() {
+                lints.push(Lint::NonPublicAutNum(*num, class));
+            }
+            let mut asns = BTreeSet::new();
+            for entry in chain!(
+                aut_num.imports.entries_iter(),
+                aut_num.exports.entries_iter()
+            ) {
+                for peering_action in &entry.mp_peerings {
+                    as_expr_asns(&peering_action.mp_peering.remote_as, &mut asns);
+                }
+                filter_asns(&entry.mp_filter, &mut asns);
+            }
+            lints.extend(
+                non_public(asns).map(|(asn, class)| Lint::NonPublicAsnInPolicy {
+                    aut_num: *num,
+                    asn,
+                    class,
+                }),
+            );
+        }
+        for (name, as_set) in &self.as_sets {
+            let asns = as_set.members.iter().copied();
+            lints.extend(
+                non_public(asns).map(|(asn, class)| Lint::NonPublicAsnInAsSet {
+                    as_set: name.clone(),
+                    asn,
+                    class,
+                }),
+            );
+        }
+        for (name, route_set) in &self.route_sets {
+            let asns = route_set.members.iter().filter_map(|member| match member {
+                RouteSetMember::AsNum(asn, _) => Some(*asn),
+                _ => None,
+            });
+            lints.extend(
+                non_public(asns).map(|(asn, class)| Lint::NonPublicAsnInRouteSet {
+                    route_set: name.clone(),
+                    asn,
+                    class,
+                }),
+            );
+        }
+        lints
+    }
+}
+
+fn non_public<I>(asns: I) -> impl Iterator<Item = (u32, AsnClass)>
+where
+    I: IntoIterator<Item = u32>,
+{
+    asns.into_iter()
+        .map(|asn| (asn, classify_asn(asn)))
+        .filter(|(_, class)| !class.is_public())
+}
+
+fn as_expr_asns(expr: &AsExpr, asns: &mut BTreeSet<u32>) {
+    match expr {
+        AsExpr::Single(AsName::Num(num)) => _ = asns.insert(*num),
+        AsExpr::Single(_) | AsExpr::PeeringSet(_) => (),
+        AsExpr::And { left, right }
+        | AsExpr::Or { left, right }
+        | AsExpr::Except { left, right } => {
+            as_expr_asns(left, asns);
+            as_expr_asns(right, asns);
+        }
+        AsExpr::Group(expr) => as_expr_asns(expr, asns),
+    }
+}
+
+fn filter_asns(filter: &Filter, asns: &mut BTreeSet<u32>) {
+    match filter {
+        Filter::AsNum(num, _) => _ = asns.insert(*num),
+        Filter::And { left, right } | Filter::Or { left, right } => {
+            filter_asns(left, asns);
+            filter_asns(right, asns);
+        }
+        Filter::Not(filter) | Filter::Group(filter) => filter_asns(filter, asns),
+        _ => (),
+    }
+}
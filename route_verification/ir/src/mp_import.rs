@@ -101,4 +101,78 @@ pub struct Entry {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub mp_peerings: Vec<PeeringAction>,
     pub mp_filter: Filter,
+    /// Where the entry is in the body of its aut-num, if known.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub span: Option<SourceSpan>,
+}
+
+/// `any`, `ipv4`, or `ipv6` policies in [`Versions`].
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum PolicyVersion {
+    Any,
+    Ipv4,
+    Ipv6,
+}
+
+/// `any`, `unicast`, or `multicast` policies in [`Casts`].
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum PolicyCast {
+    Any,
+    Unicast,
+    Multicast,
+}
+
+/// Position of an [`Entry`] in the imports or exports of an aut-num,
+/// stable as long as the aut-num is unchanged.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct RuleIndex {
+    pub version: PolicyVersion,
+    pub cast: PolicyCast,
+    pub entry: usize,
+}
+
+impl RuleIndex {
+    /// All entries in `policy`, with their indexes.
+    pub fn all(policy: &Versions) -> impl Iterator<Item = (Self, &Entry)> {
+        [
+            (PolicyVersion::Any, &policy.any),
+            (PolicyVersion::Ipv4, &policy.ipv4),
+            (PolicyVersion::Ipv6, &policy.ipv6),
+        ]
+        .into_iter()
+        .flat_map(|(version, casts)| {
+            [
+                (PolicyCast::Any, &casts.any),
+                (PolicyCast::Unicast, &casts.unicast),
+                (PolicyCast::Multicast, &casts.multicast),
+            ]
+            .into_iter()
+            .flat_map(move |(cast, entries)| {
+                entries.iter().enumerate().map(move |(entry, e)| {
+                    let index = Self {
+                        version,
+                        cast,
+                        entry,
+                    };
+                    (index, e)
+                })
+            })
+        })
+    }
+
+    /// The entry at this index in `policy`, if any.
+    pub fn get(self, policy: &Versions) -> Option<&Entry> {
+        let casts = match self.version {
+            PolicyVersion::Any => &policy.any,
+            PolicyVersion::Ipv4 => &policy.ipv4,
+            PolicyVersion::Ipv6 => &policy.ipv6,
+        };
+        let entries = match self.cast {
+            PolicyCast::Any => &casts.any,
+            PolicyCast::Unicast => &casts.unicast,
+            PolicyCast::Multicast => &casts.multicast,
+        };
+        entries.get(self.entry)
+    }
 }
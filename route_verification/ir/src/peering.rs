@@ -68,6 +68,20 @@ impl std::fmt::Debug for Peering {
     }
 }
 
+/// `<mp-peering>` as in RPSL.
+impl std::fmt::Display for Peering {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.remote_as)?;
+        if let Some(remote_router) = &self.remote_router {
+            write!(f, " {remote_router}")?;
+        }
+        if let Some(local_router) = &self.local_router {
+            write!(f, " at {local_router}")?;
+        }
+        Ok(())
+    }
+}
+
 /// Representation of `<mp-peering> [<actions>]` in an RPSL rule.
 #[derive(Clone, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct PeeringAction {
@@ -106,3 +120,16 @@ pub enum AsExpr {
     },
     Group(Box<AsExpr>),
 }
+
+impl std::fmt::Display for AsExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Single(as_name) => write!(f, "{as_name}"),
+            Self::PeeringSet(name) => f.write_str(name),
+            Self::And { left, right } => write!(f, "{left} AND {right}"),
+            Self::Or { left, right } => write!(f, "{left} OR {right}"),
+            Self::Except { left, right } => write!(f, "{left} EXCEPT {right}"),
+            Self::Group(inner) => write!(f, "({inner})"),
+        }
+    }
+}
@@ -0,0 +1,190 @@
+//! Reverse index from RPSL objects to the rules and sets referring to them.
+use std::collections::{BTreeSet, VecDeque};
+
+use itertools::chain;
+
+use super::*;
+
+/// An RPSL object referred to by name.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum ObjectName {
+    AsNum(u32),
+    AsSet(String),
+    RouteSet(String),
+    FilterSet(String),
+    PeeringSet(String),
+}
+
+/// Where an [`ObjectName`] is referred to.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum Referrer {
+    /// The entry at `rule` in the exports of `aut_num` if `export`,
+    /// otherwise its imports.
+    Rule {
+        aut_num: u32,
+        export: bool,
+        rule: RuleIndex,
+    },
+    /// The definition of a set; never [`ObjectName::AsNum`].
+    Set(ObjectName),
+}
+
+/// Index from each object to where it is referred to,
+/// generated by [`Ir::reference_index`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ReferenceIndex {
+    pub referrers: BTreeMap<ObjectName, BTreeSet<Referrer>>,
+}
+
+impl ReferenceIndex {
+    /// Rules and sets referring to `name` directly.
+    pub fn direct_referrers(&self, name: &ObjectName) -> impl Iterator<Item = &Referrer> {
+        self.referrers.get(name).into_iter().flatten()
+    }
+
+    /// Rules and sets affected if `name` changes, i.e., referring to it
+    /// directly or through sets referring to it.
+    /// `name` is only included if it refers to itself through a cycle.
+    pub fn affected_by(&self, name: &ObjectName) -> BTreeSet<Referrer> {
+        let mut affected = BTreeSet::new();
+        let mut to_visit = VecDeque::from([name]);
+        while let Some(name) = to_visit.pop_front() {
+            for referrer in self.direct_referrers(name) {
+                if affected.insert(referrer.clone()) {
+                    if let Referrer::Set(set) = referrer {
+                        to_visit.push_back(set);
+                    }
+                }
+            }
+        }
+        affected
+    }
+
+    /// Aut-nums with rules affected if `name` changes,
+    /// per [`affected_by`](#method.affected_by).
+    pub fn affected_aut_nums(&self, name: &ObjectName) -> BTreeSet<u32> {
+        self.affected_by(name)
+            .into_iter()
+            .filter_map(|referrer| match referrer {
+                Referrer::Rule { aut_num, .. } => Some(aut_num),
+                Referrer::Set(_) => None,
+            })
+            .collect()
+    }
+
+    fn add(&mut self, referrer: Referrer, names: BTreeSet<ObjectName>) {
+        for name in names {
+            self.referrers
+                .entry(name)
+                .or_default()
+                .insert(referrer.clone());
+        }
+    }
+}
+
+impl Ir {
+    /// Index the AS numbers, as-sets, route-sets, filter-sets, and
+    /// peering-sets referred to in aut-num rules and set definitions,
+    /// including those in AS path regexes.
+    pub fn reference_index(&self) -> ReferenceIndex {
+        let mut index = ReferenceIndex::default();
+        for (num, aut_num) in &self.aut_nums {
+            for (export, versions) in [(false, &aut_num.imports), (true, &aut_num.exports)] {
+                for (rule, entry) in RuleIndex::all(versions) {
+                    let mut names = BTreeSet::new();
+                    for peering_action in &entry.mp_peerings {
+                        as_expr_refs(&peering_action.mp_peering.remote_as, &mut names);
+                    }
+                    filter_refs(&entry.mp_filter, &mut names);
+                    let referrer = Referrer::Rule {
+                        aut_num: *num,
+                        export,
+                        rule,
+                    };
+                    index.add(referrer, names);
+                }
+            }
+        }
+        for (name, as_set) in &self.as_sets {
+            let names = chain!(
+                as_set.members.iter().copied().map(ObjectName::AsNum),
+                as_set.set_members.iter().cloned().map(ObjectName::AsSet)
+            );
+            let referrer = Referrer::Set(ObjectName::AsSet(name.clone()));
+            index.add(referrer, names.collect());
+        }
+        for (name, route_set) in &self.route_sets {
+            let names = route_set.members.iter().filter_map(|member| match member {
+                RouteSetMember::RSRange(_) => None,
+                RouteSetMember::NameOp(name, _) => Some(ObjectName::RouteSet(name.clone())),
+                RouteSetMember::AsNum(num, _) => Some(ObjectName::AsNum(*num)),
+                RouteSetMember::AsSet(name, _) => Some(ObjectName::AsSet(name.clone())),
+            });
+            let referrer = Referrer::Set(ObjectName::RouteSet(name.clone()));
+            index.add(referrer, names.collect());
+        }
+        for (name, peering_set) in &self.peering_sets {
+            let mut names = BTreeSet::new();
+            for peering in &peering_set.peerings {
+                as_expr_refs(&peering.remote_as, &mut names);
+            }
+            index.add(Referrer::Set(ObjectName::PeeringSet(name.clone())), names);
+        }
+        for (name, filter_set) in &self.filter_sets {
+            let mut names = BTreeSet::new();
+            for filter in &filter_set.filters {
+                filter_refs(filter, &mut names);
+            }
+            index.add(Referrer::Set(ObjectName::FilterSet(name.clone())), names);
+        }
+        index
+    }
+}
+
+fn as_expr_refs(expr: &AsExpr, names: &mut BTreeSet<ObjectName>) {
+    match expr {
+        AsExpr::Single(AsName::Num(num)) => _ = names.insert(ObjectName::AsNum(*num)),
+        AsExpr::Single(AsName::Set(set)) => _ = names.insert(ObjectName::AsSet(set.clone())),
+        AsExpr::Single(AsName::Any | AsName::Invalid(_)) => (),
+        AsExpr::PeeringSet(set) => _ = names.insert(ObjectName::PeeringSet(set.clone())),
+        AsExpr::And { left, right }
+        | AsExpr::Or { left, right }
+        | AsExpr::Except { left, right } => {
+            as_expr_refs(left, names);
+            as_expr_refs(right, names);
+        }
+        AsExpr::Group(expr) => as_expr_refs(expr, names),
+    }
+}
+
+fn filter_refs(filter: &Filter, names: &mut BTreeSet<ObjectName>) {
+    match filter {
+        Filter::FilterSet(set) => _ = names.insert(ObjectName::FilterSet(set.clone())),
+        Filter::RouteSet(set, _) => _ = names.insert(ObjectName::RouteSet(set.clone())),
+        Filter::AsNum(num, _) => _ = names.insert(ObjectName::AsNum(*num)),
+        Filter::AsSet(set, _) => _ = names.insert(ObjectName::AsSet(set.clone())),
+        Filter::AsPathRE(expr) => as_path_regex_refs(expr, names),
+        Filter::And { left, right } | Filter::Or { left, right } => {
+            filter_refs(left, names);
+            filter_refs(right, names);
+        }
+        Filter::Not(filter) | Filter::Group(filter) => filter_refs(filter, names),
+        _ => (),
+    }
+}
+
+/// AS sets first, so AS numbers in hierarchical set names are not counted.
+fn as_path_regex_refs(expr: &str, names: &mut BTreeSet<ObjectName>) {
+    let without_sets = regex!(AS_SET).replace_all(expr, |caps: &Captures| {
+        let set = &caps[0];
+        if !set.eq_ignore_ascii_case(PEERAS) {
+            names.insert(ObjectName::AsSet(set.into()));
+        }
+        ""
+    });
+    for asn in regex!(ASN).find_iter(&without_sets) {
+        if let Ok(num) = parse_aut_num_name(asn.as_str()) {
+            names.insert(ObjectName::AsNum(num));
+        }
+    }
+}
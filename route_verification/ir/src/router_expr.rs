@@ -5,22 +5,23 @@ use super::*;
 pub fn parse_simple_router_expr(field: String) -> RouterExpr {
     if let Ok(ip) = field.parse() {
         RouterExpr::Ip(ip)
+    } else if is_rtr_set_name(&field) || is_pseudo_set(&field) {
+        RouterExpr::RtrSet(field)
     } else {
-        RouterExpr::InetRtrOrRtrSet(field)
+        RouterExpr::InetRtr(field)
     }
 }
 
 /// Expressions over router IP addresses, inet-rtr names, and rtr-set names
 /// using operators AND, OR, and EXCEPT.
 /// <https://www.rfc-editor.org/rfc/rfc2622#page-25>
-///
-/// Currently, we don't have a means to check router expressions.
-/// <https://github.com/SichangHe/parse_rpsl_policy/issues/13>.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub enum RouterExpr {
     Ip(IpAddr),
-    // Enhancement: distinguish between inet-rtr and rtr-set.
-    InetRtrOrRtrSet(String),
+    /// DNS name of an [`InetRtr`].
+    InetRtr(String),
+    /// Name of a [`RtrSet`].
+    RtrSet(String),
     And {
         left: Box<RouterExpr>,
         right: Box<RouterExpr>,
@@ -35,3 +36,16 @@ pub enum RouterExpr {
     },
     Group(Box<RouterExpr>),
 }
+
+impl std::fmt::Display for RouterExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ip(ip) => write!(f, "{ip}"),
+            Self::InetRtr(name) | Self::RtrSet(name) => f.write_str(name),
+            Self::And { left, right } => write!(f, "{left} AND {right}"),
+            Self::Or { left, right } => write!(f, "{left} OR {right}"),
+            Self::Except { left, right } => write!(f, "{left} EXCEPT {right}"),
+            Self::Group(inner) => write!(f, "({inner})"),
+        }
+    }
+}
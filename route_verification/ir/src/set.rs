@@ -10,6 +10,16 @@ pub struct AsSet {
     pub members: Vec<u32>,
     pub set_members: Vec<String>,
     pub is_any: bool,
+    /// Spans of the attributes listing each of `members` in `body`, in the
+    /// same order; empty if unknown.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub member_spans: Vec<SourceSpan>,
+    /// Spans of the attributes listing each of `set_members` in `body`, in
+    /// the same order; empty if unknown.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub set_member_spans: Vec<SourceSpan>,
 }
 
 impl AsSet {
@@ -23,6 +33,8 @@ impl AsSet {
             members,
             set_members,
             is_any: false,
+            member_spans: Vec::new(),
+            set_member_spans: Vec::new(),
         }
     }
 
@@ -33,6 +45,8 @@ impl AsSet {
             members: vec![],
             set_members: vec![],
             is_any: true,
+            member_spans: Vec::new(),
+            set_member_spans: Vec::new(),
         }
     }
 }
@@ -52,9 +66,14 @@ pub fn is_route_set_name(attr: &str) -> bool {
 #[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct RouteSet {
     pub body: String,
-    /// List of `<address-prefix-range>` or `<route-set-name>` or
-    /// `<route-set-name><range-operator>`.
+    /// List of `<address-prefix-range>`, `<route-set-name>`, `<as-set-name>`,
+    /// or `<as-number>`, each optionally followed by `<range-operator>`.
     pub members: Vec<RouteSetMember>,
+    /// Spans of the attributes listing each of `members` in `body`, in the
+    /// same order; empty if unknown.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub member_spans: Vec<SourceSpan>,
 }
 
 #[derive(Clone, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
@@ -63,6 +82,11 @@ pub enum RouteSetMember {
     RSRange(AddrPfxRange),
     /// `<route-set-name><range-operator>`
     NameOp(String, RangeOperator),
+    /// `<as-number><range-operator>`, denoting the routes originated by the AS.
+    AsNum(u32, RangeOperator),
+    /// `<as-set-name><range-operator>`, denoting the routes originated by
+    /// the ASes in the set.
+    AsSet(String, RangeOperator),
 }
 
 impl std::fmt::Debug for RouteSetMember {
@@ -70,26 +94,43 @@ impl std::fmt::Debug for RouteSetMember {
         use RouteSetMember::*;
         match self {
             RSRange(arg0) => f.debug_tuple("RSRange").field(arg0).finish(),
-            NameOp(arg0, arg1) => {
-                let mut r = f.debug_tuple("NameOp");
-                r.field(arg0);
-                if *arg1 != RangeOperator::NoOp {
-                    r.field(arg1);
-                }
-                r.finish()
-            }
+            NameOp(arg0, arg1) => debug_name_op(f, "NameOp", arg0, arg1),
+            AsNum(arg0, arg1) => debug_name_op(f, "AsNum", arg0, arg1),
+            AsSet(arg0, arg1) => debug_name_op(f, "AsSet", arg0, arg1),
         }
     }
 }
 
+/// Omit the range operator if it is [`RangeOperator::NoOp`].
+fn debug_name_op(
+    f: &mut std::fmt::Formatter<'_>,
+    name: &str,
+    arg0: &dyn std::fmt::Debug,
+    arg1: &RangeOperator,
+) -> std::fmt::Result {
+    let mut r = f.debug_tuple(name);
+    r.field(arg0);
+    if *arg1 != RangeOperator::NoOp {
+        r.field(arg1);
+    }
+    r.finish()
+}
+
 impl From<String> for RouteSetMember {
     fn from(value: String) -> Self {
         if let Ok(range) = value.parse() {
-            Self::RSRange(range)
-        } else if let Ok((name, op)) = try_parse_name_operator(&value) {
-            Self::NameOp(name.into(), op)
+            return Self::RSRange(range);
+        }
+        let (name, op) = match try_parse_name_operator(&value) {
+            Ok((name, op)) => (name, op),
+            Err(_) => (value.as_str(), RangeOperator::NoOp),
+        };
+        if let Ok(num) = parse_aut_num_name(name) {
+            Self::AsNum(num, op)
+        } else if is_as_set(name) {
+            Self::AsSet(name.into(), op)
         } else {
-            Self::NameOp(value, RangeOperator::NoOp)
+            Self::NameOp(name.into(), op)
         }
     }
 }
@@ -116,3 +157,22 @@ pub struct FilterSet {
     pub body: String,
     pub filters: Vec<Filter>,
 }
+
+pub fn is_rtr_set_name(attr: &str) -> bool {
+    regex!(formatcp!("^{}$", RTR_SET)).is_match(attr)
+}
+
+/// > The rtr-set class is used to define a set of routers.
+///
+/// <https://www.rfc-editor.org/rfc/rfc2622#section-9.1>
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct RtrSet {
+    pub body: String,
+    /// IP addresses, inet-rtr names, or rtr-set names.
+    pub members: Vec<RouterExpr>,
+    /// Spans of the attributes listing each of `members` in `body`, in the
+    /// same order; empty if unknown.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub member_spans: Vec<SourceSpan>,
+}
@@ -13,6 +13,130 @@ fn ipv6_addr_prefix_range() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn compose_range_operators() -> Result<()> {
+    use RangeOperator::*;
+    let prefix = "128.9.0.0/16".parse()?;
+    let inners = [NoOp, Minus, Plus, Num(24), Range(20, 24)];
+    // Each row is an outer operator and the results for each of `inners`.
+    let table = [
+        (
+            NoOp,
+            [
+                Some(NoOp),
+                Some(Minus),
+                Some(Plus),
+                Some(Num(24)),
+                Some(Range(20, 24)),
+            ],
+        ),
+        (
+            Minus,
+            [
+                Some(Minus),
+                Some(Range(18, 32)),
+                Some(Minus),
+                Some(Range(25, 32)),
+                Some(Range(21, 32)),
+            ],
+        ),
+        (
+            Plus,
+            [
+                Some(Plus),
+                Some(Minus),
+                Some(Plus),
+                Some(Range(24, 32)),
+                Some(Range(20, 32)),
+            ],
+        ),
+        (
+            Num(22),
+            [
+                Some(Num(22)),
+                Some(Num(22)),
+                Some(Num(22)),
+                None,
+                Some(Num(22)),
+            ],
+        ),
+        (
+            Range(18, 26),
+            [
+                Some(Range(18, 26)),
+                Some(Range(18, 26)),
+                Some(Range(18, 26)),
+                Some(Range(24, 26)),
+                Some(Range(20, 26)),
+            ],
+        ),
+    ];
+    for (outer, expected) in table {
+        for (inner, expected) in inners.into_iter().zip(expected) {
+            assert_eq!(
+                inner.compose(outer, &prefix),
+                expected,
+                "{prefix}{inner} composed with {outer}"
+            );
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn compose_range_operators_at_bounds() -> Result<()> {
+    use RangeOperator::*;
+    let v4: IpNet = "128.9.0.0/16".parse()?;
+    assert_eq!(NoOp.compose(Num(16), &v4), Some(NoOp));
+    assert_eq!(NoOp.compose(Num(8), &v4), None);
+    assert_eq!(NoOp.compose(Range(8, 12), &v4), None);
+    assert_eq!(NoOp.compose(Range(8, 20), &v4), Some(Range(16, 20)));
+    assert_eq!(NoOp.compose(Range(24, 40), &v4), Some(Range(24, 32)));
+    assert_eq!(Num(8).compose(Plus, &v4), None);
+    assert_eq!(Range(8, 12).compose(Plus, &v4), None);
+    assert_eq!(Num(32).compose(Minus, &v4), None);
+    assert_eq!(Minus.compose(Minus, &"10.0.0.1/32".parse()?), None);
+
+    let v6: IpNet = "2001:db8::/32".parse()?;
+    assert_eq!(Plus.compose(Minus, &v6), Some(Minus));
+    assert_eq!(Num(48).compose(Plus, &v6), Some(Range(48, 128)));
+    assert_eq!(
+        NoOp.compose_all(&[Plus, Num(48), Minus], &v6),
+        Some(Range(49, 128))
+    );
+    assert_eq!(NoOp.compose_all(&[Num(48), Num(40)], &v6), None);
+    Ok(())
+}
+
+#[test]
+fn match_composed_ips() -> Result<()> {
+    use RangeOperator::*;
+    let ips = ["10.0.0.0/8".parse()?, "192.0.2.0/24".parse()?];
+    let ip = "10.1.0.0/16".parse()?;
+    assert!(!match_ips_composed(&ip, &ips, NoOp, &[]));
+    assert!(match_ips_composed(&ip, &ips, NoOp, &[Plus]));
+    assert!(match_ips_composed(&ip, &ips, Num(12), &[Range(14, 16)]));
+    assert!(!match_ips_composed(&ip, &ips, Num(20), &[Plus]));
+    Ok(())
+}
+
+#[test]
+fn parse_route_set_as_members() -> Result<()> {
+    use RouteSetMember as M;
+    let members: Vec<RouteSetMember> = ["AS65000", "as65000^+", "AS-FOO^24-32", "RS-FOO^-"]
+        .into_iter()
+        .map(|member| member.to_string().into())
+        .collect();
+    let expected = vec![
+        M::AsNum(65000, RangeOperator::NoOp),
+        M::AsNum(65000, RangeOperator::Plus),
+        M::AsSet("AS-FOO".into(), RangeOperator::Range(24, 32)),
+        M::NameOp("RS-FOO".into(), RangeOperator::Minus),
+    ];
+    assert_eq!(expected, members);
+    Ok(())
+}
+
 #[test]
 fn merge_as_routes() -> Result<()> {
     let actual = Ir {
@@ -34,6 +158,7 @@ fn merge_as_routes() -> Result<()> {
             (3, vec!["10.3.1.0/24".parse()?]),
         ]
         .into(),
+        ..Default::default()
     }
     .merge(Ir {
         aut_nums: BTreeMap::new(),
@@ -56,6 +181,7 @@ fn merge_as_routes() -> Result<()> {
             ),
         ]
         .into(),
+        ..Default::default()
     });
 
     let expected = Ir {
@@ -88,9 +214,256 @@ fn merge_as_routes() -> Result<()> {
             (3, vec!["10.3.1.0/24".parse()?]),
         ]
         .into(),
+        ..Default::default()
     };
 
     assert_eq!(expected, actual);
 
     Ok(())
 }
+
+#[test]
+fn parse_asdot_names() -> Result<()> {
+    assert_eq!(parse_aut_num_name("AS1.10")?, 65546);
+    assert_eq!(parse_aut_num_name("as0.3356")?, 3356);
+    assert_eq!(parse_aut_num_name("AS65535.65535")?, u32::MAX);
+    assert!(parse_aut_num_name("AS65536.0").is_err());
+    assert!(parse_aut_num_name("AS1.").is_err());
+    assert_eq!(parse_as_name("AS1.10".into())?, AsName::Num(65546));
+    assert_eq!(
+        parse_as_name("AS1.10:AS-CUSTOMERS".into())?,
+        AsName::Set("AS1.10:AS-CUSTOMERS".into())
+    );
+    Ok(())
+}
+
+#[test]
+fn render_rpsl() -> Result<()> {
+    let filter = Filter::Or {
+        left: Box::new(Filter::AddrPrefixSet(vec![
+            "10.0.0.0/8^+".parse()?,
+            "192.0.2.0/24".parse()?,
+        ])),
+        right: Box::new(Filter::Group(Box::new(Filter::And {
+            left: Box::new(Filter::AsSet("AS-FOO".into(), RangeOperator::Minus)),
+            right: Box::new(Filter::Not(Box::new(Filter::AsPathRE("^AS1+$".into())))),
+        }))),
+    };
+    assert_eq!(
+        filter.to_string(),
+        "{10.0.0.0/8^+, 192.0.2.0/24} OR (AS-FOO^- AND NOT <^AS1+$>)"
+    );
+
+    let peering = Peering {
+        remote_as: AsExpr::Except {
+            left: Box::new(AsExpr::Single(AsName::Any)),
+            right: Box::new(AsExpr::Single(AsName::Num(65546))),
+        },
+        remote_router: Some(RouterExpr::Ip("192.0.2.1".parse()?)),
+        local_router: Some(RouterExpr::RtrSet("RTRS-FOO".into())),
+    };
+    assert_eq!(
+        peering.to_string(),
+        "AS-ANY EXCEPT AS65546 192.0.2.1 at RTRS-FOO"
+    );
+    Ok(())
+}
+
+#[test]
+fn classify_asns() {
+    use AsnClass::*;
+    for (asn, class) in [
+        (0, Reserved),
+        (3356, Public),
+        (AS_TRANS, AsTrans),
+        (64496, Documentation),
+        (64512, Private),
+        (65534, Private),
+        (65535, Reserved),
+        (65551, Documentation),
+        (65552, Reserved),
+        (131072, Public),
+        (4200000000, Private),
+        (u32::MAX, Reserved),
+    ] {
+        assert_eq!(classify_asn(asn), class, "AS{asn}");
+    }
+}
+
+#[test]
+fn lint_non_public_asns() {
+    use AsnClass::*;
+    let mut ir = Ir::default();
+    let entry = Entry {
+        mp_peerings: vec![PeeringAction {
+            mp_peering: Peering {
+                remote_as: AsExpr::Single(AsName::Num(64512)),
+                remote_router: None,
+                local_router: None,
+            },
+            actions: Default::default(),
+        }],
+        mp_filter: Filter::Or {
+            left: Box::new(Filter::AsNum(AS_TRANS, RangeOperator::NoOp)),
+            right: Box::new(Filter::AsNum(3356, RangeOperator::NoOp)),
+        },
+        span: None,
+    };
+    let mut aut_num = AutNum::default();
+    aut_num.imports.any.any.push(entry);
+    ir.aut_nums.insert(65535, aut_num);
+    ir.as_sets.insert(
+        "AS-A".into(),
+        AsSet::new("".into(), vec![3356, 64496], vec![]),
+    );
+    ir.route_sets.insert(
+        "RS-A".into(),
+        RouteSet {
+            body: "".into(),
+            members: vec!["AS0^+".to_string().into(), "AS3356".to_string().into()],
+            ..Default::default()
+        },
+    );
+    let expected = vec![
+        Lint::NonPublicAutNum(65535, Reserved),
+        Lint::NonPublicAsnInPolicy {
+            aut_num: 65535,
+            asn: AS_TRANS,
+            class: AsTrans,
+        },
+        Lint::NonPublicAsnInPolicy {
+            aut_num: 65535,
+            asn: 64512,
+            class: Private,
+        },
+        Lint::NonPublicAsnInAsSet {
+            as_set: "AS-A".into(),
+            asn: 64496,
+            class: Documentation,
+        },
+        Lint::NonPublicAsnInRouteSet {
+            route_set: "RS-A".into(),
+            asn: 0,
+            class: Reserved,
+        },
+    ];
+    assert_eq!(ir.lint(), expected);
+}
+
+#[test]
+fn reference_index() {
+    use std::collections::BTreeSet;
+    let entry = |remote_as, filter| Entry {
+        mp_peerings: vec![PeeringAction {
+            mp_peering: Peering {
+                remote_as,
+                remote_router: None,
+                local_router: None,
+            },
+            actions: Default::default(),
+        }],
+        mp_filter: filter,
+        span: None,
+    };
+    let mut ir = Ir::default();
+    let mut aut_num = AutNum::default();
+    aut_num.imports.any.any.push(entry(
+        AsExpr::PeeringSet("PRNG-A".into()),
+        Filter::FilterSet("FLTR-A".into()),
+    ));
+    aut_num.exports.ipv6.any.push(entry(
+        AsExpr::Single(AsName::Num(2)),
+        Filter::AsPathRE("^AS1:AS-C AS4+$".into()),
+    ));
+    ir.aut_nums.insert(1, aut_num);
+    ir.as_sets.insert(
+        "AS-B".into(),
+        AsSet::new("".into(), vec![3], vec!["AS-C".into()]),
+    );
+    ir.route_sets.insert(
+        "RS-A".into(),
+        RouteSet {
+            body: "".into(),
+            members: vec!["AS-B^+".to_string().into(), "RS-A".to_string().into()],
+            ..Default::default()
+        },
+    );
+    ir.filter_sets.insert(
+        "FLTR-A".into(),
+        FilterSet {
+            body: "".into(),
+            filters: vec![Filter::RouteSet("RS-A".into(), RangeOperator::NoOp)],
+        },
+    );
+    ir.peering_sets.insert(
+        "PRNG-A".into(),
+        PeeringSet {
+            body: "".into(),
+            peerings: vec![Peering {
+                remote_as: AsExpr::Single(AsName::Set("AS-B".into())),
+                remote_router: None,
+                local_router: None,
+            }],
+        },
+    );
+    let index = ir.reference_index();
+
+    let import = Referrer::Rule {
+        aut_num: 1,
+        export: false,
+        rule: RuleIndex {
+            version: PolicyVersion::Any,
+            cast: PolicyCast::Any,
+            entry: 0,
+        },
+    };
+    let export = Referrer::Rule {
+        aut_num: 1,
+        export: true,
+        rule: RuleIndex {
+            version: PolicyVersion::Ipv6,
+            cast: PolicyCast::Any,
+            entry: 0,
+        },
+    };
+    let direct = |name| index.direct_referrers(&name).collect::<Vec<_>>();
+    assert_eq!(direct(ObjectName::AsNum(2)), [&export]);
+    assert_eq!(direct(ObjectName::AsNum(4)), [&export]);
+    // Part of `AS1:AS-C`.
+    assert!(direct(ObjectName::AsNum(1)).is_empty());
+    assert_eq!(direct(ObjectName::AsSet("AS1:AS-C".into())), [&export]);
+    assert_eq!(
+        direct(ObjectName::AsSet("AS-C".into())),
+        [&Referrer::Set(ObjectName::AsSet("AS-B".into()))]
+    );
+    assert_eq!(
+        direct(ObjectName::AsSet("AS-B".into())),
+        [
+            &Referrer::Set(ObjectName::RouteSet("RS-A".into())),
+            &Referrer::Set(ObjectName::PeeringSet("PRNG-A".into())),
+        ]
+    );
+
+    let expected = BTreeSet::from([
+        import.clone(),
+        Referrer::Set(ObjectName::AsSet("AS-B".into())),
+        Referrer::Set(ObjectName::RouteSet("RS-A".into())),
+        Referrer::Set(ObjectName::FilterSet("FLTR-A".into())),
+        Referrer::Set(ObjectName::PeeringSet("PRNG-A".into())),
+    ]);
+    assert_eq!(index.affected_by(&ObjectName::AsNum(3)), expected);
+    assert_eq!(
+        index.affected_aut_nums(&ObjectName::AsNum(3)),
+        BTreeSet::from([1])
+    );
+    // `RS-A` refers to itself.
+    let expected = BTreeSet::from([
+        import,
+        Referrer::Set(ObjectName::RouteSet("RS-A".into())),
+        Referrer::Set(ObjectName::FilterSet("FLTR-A".into())),
+    ]);
+    assert_eq!(
+        index.affected_by(&ObjectName::RouteSet("RS-A".into())),
+        expected
+    );
+}
@@ -24,11 +24,16 @@ pub mod worker;
 use mbrs::*;
 use worker::{spawn_aut_num_worker, spawn_filter_set_worker, spawn_peering_set_worker};
 
-/// Gather `members` and `mp-members` expressions.
+/// Gather `members` and `mp-members` expressions, with the span of the
+/// attribute listing each member.
 /// Translate `mbrs-by-ref` expressions to pseudo sets.
-pub fn gather_members(obj: &RPSLObject) -> Vec<String> {
+pub fn gather_members(obj: &RPSLObject) -> (Vec<String>, Vec<SourceSpan>) {
     let mut members = Vec::new();
-    for RpslExpr { key, expr } in expressions(lines_continued(obj.body.lines())) {
+    let mut spans = Vec::new();
+    for (span, line) in attributes(obj.body.lines()) {
+        let Some(RpslExpr { key, expr }) = expressions([line]).next() else {
+            continue;
+        };
         match key.as_str() {
             "members" | "mp-members" => {
                 members.extend(split_commas(&expr).map(Into::into));
@@ -40,8 +45,20 @@ pub fn gather_members(obj: &RPSLObject) -> Vec<String> {
             },
             _ => (),
         }
+        spans.resize(members.len(), span);
+    }
+    (members, spans)
+}
+
+/// Lex an as-set, route-set, or rtr-set.
+pub fn lex_set(obj: RPSLObject) -> AsOrRouteSet {
+    let (members, member_spans) = gather_members(&obj);
+    AsOrRouteSet {
+        name: obj.name,
+        body: obj.body,
+        members,
+        member_spans,
     }
-    members
 }
 
 pub fn read_line_wait(reader: &mut BufReader<ChildStdout>) -> Result<String> {
@@ -63,6 +80,8 @@ pub fn parse_object(obj: RPSLObject, pa: &mut PreAst) -> Result<()> {
         "as-set" => parse_as_set(obj, &mut pa.as_sets),
         "route" | "route6" => parse_route(obj, pa),
         "route-set" => parse_route_set(obj, &mut pa.route_sets),
+        "inet-rtr" => parse_inet_rtr(obj, pa),
+        "rtr-set" => parse_rtr_set(obj, &mut pa.rtr_sets),
         "filter-set" => pa.send_filter_set.send(obj).context("sending filter-set")?,
         "peering-set" => pa
             .send_peering_set
@@ -74,8 +93,7 @@ pub fn parse_object(obj: RPSLObject, pa: &mut PreAst) -> Result<()> {
 }
 
 fn parse_as_set(obj: RPSLObject, as_sets: &mut Vec<AsOrRouteSet>) {
-    let members = gather_members(&obj);
-    as_sets.push(AsOrRouteSet::new(obj.name, obj.body, members));
+    as_sets.push(lex_set(obj));
     match as_sets.len() {
         l if l % 0xFF == 0 => debug!("Parsed {l} as_sets."),
         _ => (),
@@ -102,14 +120,48 @@ fn parse_route(obj: RPSLObject, pa: &mut PreAst) {
 }
 
 fn parse_route_set(obj: RPSLObject, route_sets: &mut Vec<AsOrRouteSet>) {
-    let members = gather_members(&obj);
-    route_sets.push(AsOrRouteSet::new(obj.name, obj.body, members));
+    route_sets.push(lex_set(obj));
     match route_sets.len() {
         l if l % 0xFF == 0 => debug!("Parsed {l} route_sets."),
         _ => (),
     }
 }
 
+fn parse_inet_rtr(obj: RPSLObject, pa: &mut PreAst) {
+    gather_ref(&obj, &mut pa.pseudo_rtr_sets);
+    pa.inet_rtrs.push(lex_inet_rtr(obj));
+    match pa.inet_rtrs.len() {
+        l if l % 0xFF == 0 => debug!("Parsed {l} inet_rtrs."),
+        _ => (),
+    }
+}
+
+/// Gather `local-as`, interface addresses, and peers of an inet-rtr.
+pub fn lex_inet_rtr(obj: RPSLObject) -> InetRtr {
+    let mut inet_rtr = InetRtr::default();
+    for RpslExpr { key, expr } in expressions(lines_continued(obj.body.lines())) {
+        match key.as_str() {
+            "local-as" => inet_rtr.local_as = expr,
+            "ifaddr" | "interface" => inet_rtr.ifaddrs.push(expr),
+            "peer" | "mp-peer" => inet_rtr.peers.push(expr),
+            _ => (),
+        }
+    }
+    InetRtr {
+        name: obj.name,
+        body: obj.body,
+        ..inet_rtr
+    }
+}
+
+fn parse_rtr_set(obj: RPSLObject, rtr_sets: &mut Vec<AsOrRouteSet>) {
+    rtr_sets.push(lex_set(obj));
+    match rtr_sets.len() {
+        l if l % 0xFF == 0 => debug!("Parsed {l} rtr_sets."),
+        _ => (),
+    }
+}
+
 /// Read and lex RPSL database.
 pub fn read_db(db: impl BufRead) -> Result<(Ast, Counts)> {
     let (as_sets, route_sets, pseudo_route_sets, as_routes) =
@@ -128,6 +180,9 @@ pub fn read_db(db: impl BufRead) -> Result<(Ast, Counts)> {
         send_peering_set,
         send_filter_set,
         as_routes,
+        inet_rtrs: Vec::new(),
+        rtr_sets: Vec::new(),
+        pseudo_rtr_sets: BTreeMap::new(),
         counts: Default::default(),
     };
     let process_output = process_db(db, &mut pa);
@@ -150,6 +205,7 @@ pub fn read_db(db: impl BufRead) -> Result<(Ast, Counts)> {
 
     pa.route_sets.extend(conclude_set(pa.pseudo_route_sets));
     pa.as_sets.extend(an_out.pseudo_as_sets);
+    pa.rtr_sets.extend(conclude_set(pa.pseudo_rtr_sets));
 
     let counts = pa.counts + an_out.counts;
     debug!("read_db counts: {counts}.");
@@ -162,6 +218,8 @@ pub fn read_db(db: impl BufRead) -> Result<(Ast, Counts)> {
             peering_sets,
             filter_sets,
             as_routes: pa.as_routes,
+            inet_rtrs: pa.inet_rtrs,
+            rtr_sets: pa.rtr_sets,
         },
         counts,
     ))
@@ -183,6 +241,9 @@ pub struct PreAst {
     pub send_peering_set: Sender<RPSLObject>,
     pub send_filter_set: Sender<RPSLObject>,
     pub as_routes: BTreeMap<String, Vec<String>>,
+    pub inet_rtrs: Vec<InetRtr>,
+    pub rtr_sets: Vec<AsOrRouteSet>,
+    pub pseudo_rtr_sets: Map2DStringVec,
     pub counts: Counts,
 }
 
@@ -12,7 +12,10 @@ pub fn gather_ref(obj: &RPSLObject, set: &mut Map2DStringVec) {
         }
     }
     if !member_ofs.is_empty() && mnt_by.is_empty() {
-        warn!("AutNum {} with `member-of` without `mnt-by`.", obj.name);
+        warn!(
+            "{} {} with `member-of` without `mnt-by`.",
+            obj.class, obj.name
+        );
         return;
     }
     for member_of in &member_ofs {
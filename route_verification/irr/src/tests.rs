@@ -6,7 +6,7 @@ fn pseudo_set_member_w_mntner() {
         .into_iter()
         .map(Into::into)
         .collect();
-    let actual = gather_members(&RPSLObject {
+    let (actual, spans) = gather_members(&RPSLObject {
         class: "as-set".into(),
         name: "as-foo".into(),
         body: "members: AS1, AS2
@@ -15,6 +15,8 @@ mbrs-by-ref: MNTR-ME
         .into(),
     });
     assert_eq!(actual, expected);
+    let lines: Vec<_> = spans.iter().map(|s| (s.attr, s.start_line)).collect();
+    assert_eq!(lines, [(0, 0), (0, 0), (1, 1)]);
 }
 
 #[test]
@@ -24,16 +26,19 @@ fn set_ref_w_mntner() {
             name: "m#as-foo".into(),
             body: "".into(),
             members: vec!["m#as-foo#MNTR-ME".into(), "m#as-foo#MNTR-OTHER".into()],
+            member_spans: vec![],
         },
         AsOrRouteSet {
             name: "m#as-foo#MNTR-ME".into(),
             body: "".into(),
             members: vec!["AS3".into()],
+            member_spans: vec![],
         },
         AsOrRouteSet {
             name: "m#as-foo#MNTR-OTHER".into(),
             body: "".into(),
             members: vec!["AS4".into()],
+            member_spans: vec![],
         },
     ];
     let mut set = BTreeMap::new();
@@ -30,7 +30,7 @@ fn aut_num_worker(recv: Receiver<RPSLObject>) -> Result<AutNumWorkerOutput> {
     let mut pseduo_as_sets = BTreeMap::new();
     let mut counts = Counts::default();
     while let Ok(obj) = recv.recv() {
-        obj.write_to(&mut aut_num_child.stdin)?;
+        let spans = obj.write_to(&mut aut_num_child.stdin)?;
         gather_ref(&obj, &mut pseduo_as_sets);
         let mut aut_num: AutNum = loop {
             let line = read_line_wait(&mut aut_num_child.stdout)?;
@@ -56,7 +56,7 @@ fn aut_num_worker(recv: Receiver<RPSLObject>) -> Result<AutNumWorkerOutput> {
                 }
             }
         };
-        (aut_num.name, aut_num.body) = (obj.name, obj.body);
+        (aut_num.name, aut_num.body, aut_num.spans) = (obj.name, obj.body, spans);
         aut_nums.push(aut_num);
         match aut_nums.len() {
             l if l % 0xFFF == 0 => debug!("Parsed {l} aut_nums."),
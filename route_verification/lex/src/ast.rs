@@ -9,6 +9,10 @@ pub struct Ast {
     pub filter_sets: Vec<FilterSet>,
     /// The AS in uppercase with Vec of their routes.
     pub as_routes: BTreeMap<String, Vec<String>>,
+    #[serde(default)]
+    pub inet_rtrs: Vec<InetRtr>,
+    #[serde(default)]
+    pub rtr_sets: Vec<AsOrRouteSet>,
 }
 
 impl Ast {
@@ -20,15 +24,19 @@ impl Ast {
             peering_sets,
             filter_sets,
             as_routes,
+            inet_rtrs,
+            rtr_sets,
         } = self;
         debug!(
-            "Parsed {} aut_nums, {} as_sets, {} route_sets, {} peering_sets, {} filter_sets, {} as_routes.",
+            "Parsed {} aut_nums, {} as_sets, {} route_sets, {} peering_sets, {} filter_sets, {} as_routes, {} inet_rtrs, {} rtr_sets.",
             aut_nums.len(),
             as_sets.len(),
             route_sets.len(),
             peering_sets.len(),
             filter_sets.len(),
             as_routes.len(),
+            inet_rtrs.len(),
+            rtr_sets.len(),
         )
     }
 }
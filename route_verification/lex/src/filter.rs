@@ -1,8 +1,9 @@
 use super::*;
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Filter {
+    #[default]
     Any,
     And {
         left: Box<Filter>,
@@ -19,9 +20,3 @@ pub enum Filter {
     AddrPrefixSet(Vec<String>),
     Regex(String),
 }
-
-impl Default for Filter {
-    fn default() -> Self {
-        Self::Any
-    }
-}
@@ -19,9 +19,12 @@ pub mod tests;
 pub use {
     ast::Ast,
     filter::Filter,
-    lines::{expressions, io_wrapper_lines, lines_continued, rpsl_objects, RPSLObject, RpslExpr},
+    lines::{
+        attributes, expressions, io_wrapper_lines, lines_continued, rpsl_objects, RPSLObject,
+        RpslExpr,
+    },
     mp_import::{Casts, Entry, PeeringAction, Versions},
     peering::{AsExpr, ComplexAsExpr, Peering},
-    rpsl_object::{AsOrRouteSet, AutNum, FilterSet, PeeringSet},
-    shared_struct::{action::*, community::Call, stats::Counts},
+    rpsl_object::{AsOrRouteSet, AutNum, FilterSet, InetRtr, PeeringSet},
+    shared_struct::{action::*, community::Call, stats::Counts, SourceSpan},
 };
@@ -15,6 +15,7 @@
 
 use std::borrow::Cow;
 use std::io::{Result, *};
+use std::iter::Enumerate;
 
 use lazy_regex::regex_replace_all;
 
@@ -33,8 +34,12 @@ where
     I: Iterator<Item = S>,
     S: AsRef<str>,
 {
-    lines: I,
+    lines: Enumerate<I>,
     last_line: String,
+    /// 0-based indexes of the first and last raw lines of `last_line`.
+    last_lines: (usize, usize),
+    /// Number of attributes yielded so far.
+    n_attr: u32,
 }
 
 impl<I, S> Iterator for LinesContinued<I, S>
@@ -42,25 +47,49 @@ where
     I: Iterator<Item = S>,
     S: AsRef<str>,
 {
-    type Item = String;
+    type Item = (SourceSpan, String);
 
     fn next(&mut self) -> Option<Self::Item> {
-        for line in self.lines.by_ref() {
+        for (n, line) in self.lines.by_ref() {
             // Remove comments.
             let line = line.as_ref().split('#').next().unwrap();
             // Handle continuation lines.
             if CONTINUATION_CHARS.iter().any(|&ch| line.starts_with(ch)) {
+                if self.last_line.is_empty() {
+                    self.last_lines.0 = n;
+                }
                 self.last_line.push(' ');
                 self.last_line.push_str(line[1..].trim());
+                self.last_lines.1 = n;
                 continue;
             }
             // Not a continuation line.
-            if !self.last_line.is_empty() {
-                return Some(mem::replace(&mut self.last_line, line.trim().into()));
+            let last = mem::replace(&mut self.last_line, line.trim().into());
+            let last_lines = mem::replace(&mut self.last_lines, (n, n));
+            if !last.is_empty() {
+                return Some((self.span(last_lines), last));
             }
-            self.last_line = line.trim().into();
         }
-        (!self.last_line.is_empty()).then(|| mem::take(&mut self.last_line))
+        (!self.last_line.is_empty()).then(|| {
+            let last = mem::take(&mut self.last_line);
+            (self.span(self.last_lines), last)
+        })
+    }
+}
+
+impl<I, S> LinesContinued<I, S>
+where
+    I: Iterator<Item = S>,
+    S: AsRef<str>,
+{
+    /// Span of the next attribute, on raw lines `start_line..=end_line`.
+    fn span(&mut self, (start_line, end_line): (usize, usize)) -> SourceSpan {
+        self.n_attr += 1;
+        SourceSpan {
+            attr: self.n_attr - 1,
+            start_line: start_line as u32,
+            end_line: end_line as u32,
+        }
     }
 }
 
@@ -69,25 +98,37 @@ where
 /// Strip and ignore comments. Ignore empty lines.
 /// Lines produced are *not* followed by `\n`.
 pub fn lines_continued<I, S>(raw_lines: I) -> impl Iterator<Item = String>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    attributes(raw_lines).map(|(_, line)| line)
+}
+
+/// Like [`lines_continued`], but also yield the span of each attribute,
+/// with `attr` counting the attributes yielded before it.
+pub fn attributes<I, S>(raw_lines: I) -> LinesContinued<I::IntoIter, S>
 where
     I: IntoIterator<Item = S>,
     S: AsRef<str>,
 {
     LinesContinued {
-        lines: raw_lines.into_iter(),
+        lines: raw_lines.into_iter().enumerate(),
         last_line: String::new(),
+        last_lines: (0, 0),
+        n_attr: 0,
     }
 }
 
-pub fn dedup_whitespace(string: &str) -> Cow<str> {
+pub fn dedup_whitespace(string: &str) -> Cow<'_, str> {
     regex_replace_all!(r"\s+", string, |_| " ")
 }
 
-pub fn cleanup_right_whitespace(string: &str) -> Cow<str> {
+pub fn cleanup_right_whitespace(string: &str) -> Cow<'_, str> {
     dedup_whitespace(string.trim_end())
 }
 
-pub fn cleanup_whitespace(string: &str) -> Cow<str> {
+pub fn cleanup_whitespace(string: &str) -> Cow<'_, str> {
     dedup_whitespace(string.trim())
 }
 
@@ -103,10 +144,14 @@ impl RPSLObject {
         Self { class, name, body }
     }
 
-    pub fn write_to<W: Write>(&self, writer: &mut W) -> Result<()> {
+    /// Write the attributes of `self.body` as continued lines, followed by
+    /// an empty line, and return the spans of the attributes written.
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> Result<Vec<SourceSpan>> {
         let limit = self.body.len() >> 3;
         let mut buf = String::with_capacity(limit << 1);
-        for line in lines_continued(self.body.lines()) {
+        let mut spans = Vec::new();
+        for (span, line) in attributes(self.body.lines()) {
+            spans.push(span);
             buf.push_str(&line);
             buf.push('\n');
             if buf.len() >= limit {
@@ -116,7 +161,7 @@ impl RPSLObject {
         }
         buf.push('\n');
         writer.write_all(buf.as_bytes())?;
-        Ok(())
+        Ok(spans)
     }
 }
 
@@ -21,6 +21,10 @@ pub struct Entry {
     pub mp_peerings: Vec<PeeringAction>,
     #[serde(default)]
     pub mp_filter: Filter,
+    /// Index of the attribute the entry is lexed from, among the attributes
+    /// of the aut-num, if known.
+    #[serde(default)]
+    pub attr: Option<u32>,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
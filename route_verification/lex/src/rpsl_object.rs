@@ -8,6 +8,10 @@ pub struct AutNum {
     pub n_export: u32,
     pub imports: Versions,
     pub exports: Versions,
+    /// Spans of the attributes in `body`, recorded when they are split for
+    /// lexing; [`Entry::attr`](crate::Entry::attr) indexes into it.
+    #[serde(default)]
+    pub spans: Vec<SourceSpan>,
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
@@ -15,6 +19,10 @@ pub struct AsOrRouteSet {
     pub name: String,
     pub body: String,
     pub members: Vec<String>,
+    /// Spans of the attributes listing each of `members`, in the same order;
+    /// empty if unknown, e.g., for pseudo sets.
+    #[serde(default)]
+    pub member_spans: Vec<SourceSpan>,
 }
 
 impl AsOrRouteSet {
@@ -23,6 +31,7 @@ impl AsOrRouteSet {
             name,
             body,
             members,
+            member_spans: Vec::new(),
         }
     }
 }
@@ -40,3 +49,14 @@ pub struct FilterSet {
     pub body: String,
     pub filters: Vec<Filter>,
 }
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct InetRtr {
+    pub name: String,
+    pub body: String,
+    pub local_as: String,
+    /// `ifaddr` and `interface` expressions.
+    pub ifaddrs: Vec<String>,
+    /// `peer` and `mp-peer` expressions.
+    pub peers: Vec<String>,
+}
@@ -5,5 +5,6 @@ use super::*;
 mod action;
 mod ast;
 mod filter;
+mod lines;
 mod mp_import;
 mod peering;
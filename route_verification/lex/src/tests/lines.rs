@@ -0,0 +1,46 @@
+use super::*;
+
+const BODY: &str = "\
+descr: Example
+mp-import: afi ipv6.unicast, ipv4 from AS1
+ accept ANY # Comment.
+import: from AS2 accept AS2
+mp-export: afi ipv6.unicast to AS1
++ announce AS3
+members: AS1, AS-FOO
+";
+
+fn span(attr: u32, start_line: u32, end_line: u32) -> SourceSpan {
+    SourceSpan {
+        attr,
+        start_line,
+        end_line,
+    }
+}
+
+#[test]
+fn attribute_spans() {
+    let (spans, lines): (Vec<_>, Vec<_>) = attributes(BODY.lines()).unzip();
+    let expected = [
+        span(0, 0, 0),
+        span(1, 1, 2),
+        span(2, 3, 3),
+        span(3, 4, 5),
+        span(4, 6, 6),
+    ];
+    assert_eq!(spans, expected);
+    assert_eq!(lines, lines_continued(BODY.lines()).collect::<Vec<_>>());
+    assert_eq!(lines[3], "mp-export: afi ipv6.unicast to AS1 announce AS3");
+    assert_eq!(
+        spans[1].quote(BODY),
+        "mp-import: afi ipv6.unicast, ipv4 from AS1\n accept ANY # Comment."
+    );
+
+    let object = RPSLObject {
+        class: "aut-num".into(),
+        name: "AS1".into(),
+        body: BODY.into(),
+    };
+    let mut written = Vec::new();
+    assert_eq!(object.write_to(&mut written).unwrap(), expected);
+}
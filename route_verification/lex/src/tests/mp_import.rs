@@ -27,7 +27,8 @@ const MP_IMPORT_EXAMPLE: &str = r#"{
                         "left": {"path_attr": "ANY"},
                         "right": {"not": {"path_attr": "AS3344:fltr-filterlist"}}
                     }
-                }
+                },
+                "attr": 3
             }
         ]
     }
@@ -72,6 +73,7 @@ fn expected_mp_import() -> Versions {
                     left: Box::new(PathAttr("ANY".into())),
                     right: Box::new(Not(Box::new(PathAttr("AS3344:fltr-filterlist".into())))),
                 },
+                attr: Some(3),
             }],
             multicast: vec![],
         },
@@ -10,6 +10,8 @@ pub fn parse_lexed(lexed: lex::Ast) -> (Ir, Counts) {
         peering_sets,
         filter_sets,
         as_routes,
+        inet_rtrs,
+        rtr_sets,
     } = lexed;
     let (aut_nums, an_counts) = parse_lexed_aut_nums(aut_nums);
     let (as_sets, as_counts) = parse_lexed_as_sets(as_sets);
@@ -17,6 +19,8 @@ pub fn parse_lexed(lexed: lex::Ast) -> (Ir, Counts) {
     let (peering_sets, ps_counts) = parse_lexed_peering_sets(peering_sets);
     let (filter_sets, fs_counts) = parse_lexed_filter_sets(filter_sets);
     let (as_routes, ar_counts) = parse_lexed_as_routes(as_routes);
+    let (inet_rtrs, ir_counts) = parse_lexed_inet_rtrs(inet_rtrs);
+    let (rtr_sets, rts_counts) = parse_lexed_rtr_sets(rtr_sets);
     let ir = Ir {
         aut_nums,
         as_sets,
@@ -24,8 +28,17 @@ pub fn parse_lexed(lexed: lex::Ast) -> (Ir, Counts) {
         peering_sets,
         filter_sets,
         as_routes,
+        inet_rtrs,
+        rtr_sets,
     };
-    let counts = an_counts + as_counts + rs_counts + ps_counts + fs_counts + ar_counts;
+    let counts = an_counts
+        + as_counts
+        + rs_counts
+        + ps_counts
+        + fs_counts
+        + ar_counts
+        + ir_counts
+        + rts_counts;
     (ir, counts)
 }
 
@@ -55,9 +68,10 @@ pub fn parse_lexed_aut_num(aut_num: lex::AutNum, counts: &mut Counts) -> Result<
         n_export,
         imports,
         exports,
+        spans,
     } = aut_num;
-    let imports = parse_imports(imports, counts);
-    let exports = parse_imports(exports, counts);
+    let imports = parse_imports(imports, &spans, counts);
+    let exports = parse_imports(exports, &spans, counts);
     Ok((
         num,
         AutNum {
@@ -94,19 +108,28 @@ pub fn parse_lexed_as_set(lexed: lex::AsOrRouteSet) -> Result<(String, AsSet)> {
     let max_length = lexed.members.len();
     let mut members = Vec::with_capacity(max_length);
     let mut set_members = Vec::with_capacity(max_length);
-    for member in lexed.members {
+    for (index, member) in lexed.members.into_iter().enumerate() {
+        let span = lexed.member_spans.get(index).copied();
         let member = parse_as_name(member)
             .with_context(|| format!("parsing AS Set {}\n{}", lexed.name, lexed.body))?;
         match member {
             AsName::Any => return Ok((lexed.name, AsSet::new_any(lexed.body))),
-            AsName::Num(n) => members.push(n),
-            AsName::Set(set) => set_members.push(set),
+            AsName::Num(n) => members.push((n, span)),
+            AsName::Set(set) => set_members.push((set, span)),
             AsName::Invalid(reason) => {
                 bail!("{reason} parsing AS Set {}\n{}", lexed.name, lexed.body)
             }
         }
     }
-    let as_set = AsSet::new(lexed.body, members, set_members);
+    // Sorted here so that the spans stay parallel to the members.
+    members.sort_unstable_by_key(|(n, _)| *n);
+    let (members, member_spans) = unzip_spans(members);
+    let (set_members, set_member_spans) = unzip_spans(set_members);
+    let as_set = AsSet {
+        member_spans,
+        set_member_spans,
+        ..AsSet::new(lexed.body, members, set_members)
+    };
     Ok((lexed.name, as_set))
 }
 
@@ -147,6 +170,7 @@ pub fn parse_lexed_route_set(lexed: lex::AsOrRouteSet) -> Result<(String, RouteS
         RouteSet {
             body: lexed.body,
             members,
+            member_spans: lexed.member_spans,
         },
     ))
 }
@@ -245,6 +269,122 @@ pub fn parse_lexed_as_routes(
     )
 }
 
+pub fn parse_lexed_inet_rtrs(lexed: Vec<lex::InetRtr>) -> (BTreeMap<String, InetRtr>, Counts) {
+    par_process_map_counts(
+        lexed,
+        |(mut acc, mut counts), lexed| match parse_lexed_inet_rtr(lexed) {
+            Ok((name, inet_rtr)) => {
+                acc.insert(name, inet_rtr);
+                (acc, counts)
+            }
+            Err(e) => {
+                counts.parse_inet_rtr += 1;
+                error!("{e:?}");
+                (acc, counts)
+            }
+        },
+    )
+}
+
+/// Unparsable interface addresses and peers are logged and skipped.
+pub fn parse_lexed_inet_rtr(lexed: lex::InetRtr) -> Result<(String, InetRtr)> {
+    let local_as = parse_aut_num_name(&lexed.local_as)
+        .with_context(|| format!("parsing local-as of {lexed:?}"))?;
+    let lex::InetRtr {
+        name,
+        body,
+        local_as: _,
+        ifaddrs,
+        peers,
+    } = lexed;
+    let ifaddrs = ifaddrs
+        .iter()
+        .filter_map(|ifaddr| {
+            let address = ifaddr.split_whitespace().next().unwrap_or_default();
+            address
+                .parse()
+                .map_err(|e| error!("parsing `{ifaddr}` as ifaddr of {name}: {e:?}"))
+                .ok()
+        })
+        .collect();
+    let peers = peers
+        .into_iter()
+        .filter_map(|peer| {
+            parse_rtr_peer(&peer)
+                .map_err(|e| error!("parsing `{peer}` as peer of {name}: {e:?}"))
+                .ok()
+        })
+        .collect();
+    Ok((
+        name,
+        InetRtr {
+            body,
+            local_as,
+            ifaddrs,
+            peers,
+        },
+    ))
+}
+
+/// Parse `<protocol> <peer> <options>`.
+pub fn parse_rtr_peer(peer: &str) -> Result<RtrPeer> {
+    let mut parts = peer.splitn(3, char::is_whitespace);
+    let (Some(protocol), Some(address)) = (parts.next(), parts.next()) else {
+        bail!("{peer} does not have both a protocol and a peer address");
+    };
+    Ok(RtrPeer {
+        protocol: protocol.into(),
+        peer: parse_simple_router_expr(address.into()),
+        options: parts.next().unwrap_or_default().trim().into(),
+    })
+}
+
+pub fn parse_lexed_rtr_sets(lexed: Vec<lex::AsOrRouteSet>) -> (BTreeMap<String, RtrSet>, Counts) {
+    par_process_map_counts(
+        lexed,
+        |(mut acc, mut counts), lexed| match parse_lexed_rtr_set(lexed) {
+            Ok((name, rtr_set)) => {
+                acc.insert(name, rtr_set);
+                (acc, counts)
+            }
+            Err(e) => {
+                counts.parse_rtr_set += 1;
+                error!("{e:?}");
+                (acc, counts)
+            }
+        },
+    )
+}
+
+pub fn parse_lexed_rtr_set(lexed: lex::AsOrRouteSet) -> Result<(String, RtrSet)> {
+    if !is_rtr_set_name(&lexed.name) && !is_pseudo_set(&lexed.name) {
+        bail!(
+            "{} is an invalid rtr set name—parsing {lexed:?}",
+            lexed.name
+        );
+    }
+    let members = lexed
+        .members
+        .into_iter()
+        .map(parse_simple_router_expr)
+        .collect();
+    Ok((
+        lexed.name,
+        RtrSet {
+            body: lexed.body,
+            members,
+            member_spans: lexed.member_spans,
+        },
+    ))
+}
+
+/// Split `members` from their spans, which are empty unless all known.
+fn unzip_spans<T>(members: Vec<(T, Option<SourceSpan>)>) -> (Vec<T>, Vec<SourceSpan>) {
+    let (members, spans): (Vec<_>, Vec<_>) = members.into_iter().unzip();
+    let spans = spans.into_iter().collect::<Option<_>>().unwrap_or_default();
+    (members, spans)
+}
+
 pub fn par_process_map_counts<I, In, F, K, V>(input: I, transform: F) -> (BTreeMap<K, V>, Counts)
 where
     I: IntoParallelIterator<Item = In>,
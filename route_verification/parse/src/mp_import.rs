@@ -2,23 +2,29 @@ use ::lex::{mp_import, Counts};
 
 use super::*;
 
-pub fn parse_imports(imports: mp_import::Versions, counts: &mut Counts) -> Versions {
+/// `spans` are the spans of the attributes of the aut-num, as in
+/// [`lex::AutNum::spans`](::lex::AutNum::spans).
+pub fn parse_imports(
+    imports: mp_import::Versions,
+    spans: &[SourceSpan],
+    counts: &mut Counts,
+) -> Versions {
     let mp_import::Versions { any, ipv4, ipv6 } = imports;
-    let any = parse_casts(any, counts);
-    let ipv4 = parse_casts(ipv4, counts);
-    let ipv6 = parse_casts(ipv6, counts);
+    let any = parse_casts(any, spans, counts);
+    let ipv4 = parse_casts(ipv4, spans, counts);
+    let ipv6 = parse_casts(ipv6, spans, counts);
     Versions { any, ipv4, ipv6 }
 }
 
-pub fn parse_casts(casts: mp_import::Casts, counts: &mut Counts) -> Casts {
+pub fn parse_casts(casts: mp_import::Casts, spans: &[SourceSpan], counts: &mut Counts) -> Casts {
     let mp_import::Casts {
         any,
         unicast,
         multicast,
     } = casts;
-    let any = parse_entries(any, counts);
-    let unicast = parse_entries(unicast, counts);
-    let multicast = parse_entries(multicast, counts);
+    let any = parse_entries(any, spans, counts);
+    let unicast = parse_entries(unicast, spans, counts);
+    let multicast = parse_entries(multicast, spans, counts);
     Casts {
         any,
         unicast,
@@ -26,22 +32,28 @@ pub fn parse_casts(casts: mp_import::Casts, counts: &mut Counts) -> Casts {
     }
 }
 
-pub fn parse_entries(entries: Vec<mp_import::Entry>, counts: &mut Counts) -> Vec<Entry> {
+pub fn parse_entries(
+    entries: Vec<mp_import::Entry>,
+    spans: &[SourceSpan],
+    counts: &mut Counts,
+) -> Vec<Entry> {
     entries
         .into_iter()
-        .map(|e| parse_entry(e, counts))
+        .map(|e| parse_entry(e, spans, counts))
         .collect()
 }
 
-pub fn parse_entry(entry: mp_import::Entry, counts: &mut Counts) -> Entry {
+pub fn parse_entry(entry: mp_import::Entry, spans: &[SourceSpan], counts: &mut Counts) -> Entry {
     let mp_import::Entry {
         mp_peerings,
         mp_filter,
+        attr,
     } = entry;
     let mp_peerings = parse_mp_peerings(mp_peerings);
     let mp_filter = parse_filter(mp_filter, counts);
     Entry {
         mp_peerings,
         mp_filter,
+        span: attr.and_then(|attr| spans.get(attr as usize)).copied(),
     }
 }
@@ -3,6 +3,7 @@ use maplit::btreemap;
 use net_literals::ip;
 
 use super::*;
+use crate::lex::{parse_lexed_inet_rtr, parse_lexed_rtr_set};
 use {
     Action::Assigned,
     AsExpr::Single,
@@ -32,6 +33,8 @@ fn parse_ast() {
             peering_sets,
             filter_sets,
             as_routes,
+            inet_rtrs,
+            rtr_sets,
         },
         counts,
     ) = parse_lexed(lexed);
@@ -41,10 +44,77 @@ fn parse_ast() {
     assert_eq!(peering_sets, expected_peering_sets());
     assert_eq!(filter_sets, expected_filter_sets());
     assert_eq!(as_routes, expected_as_routes());
+    assert!(inet_rtrs.is_empty() && rtr_sets.is_empty());
     let expected_counts = Counts::default();
     assert_eq!(counts, expected_counts);
 }
 
+#[test]
+fn parse_inet_rtr_and_rtr_set() -> Result<()> {
+    let inet_rtr = ::lex::InetRtr {
+        name: "rtr1.example.net".into(),
+        body: "".into(),
+        local_as: "AS65000".into(),
+        ifaddrs: vec![
+            "192.0.2.1 masklen 30".into(),
+            "2001:db8::1 masklen 64".into(),
+            "not-an-ip masklen 30".into(),
+        ],
+        peers: vec![
+            "BGP4 192.0.2.2 asno(AS65001)".into(),
+            "BGP4 rtrs-peers".into(),
+        ],
+    };
+    let expected = ir::InetRtr {
+        body: "".into(),
+        local_as: 65000,
+        ifaddrs: vec!["192.0.2.1".parse()?, "2001:db8::1".parse()?],
+        peers: vec![
+            RtrPeer {
+                protocol: "BGP4".into(),
+                peer: Ip("192.0.2.2".parse()?),
+                options: "asno(AS65001)".into(),
+            },
+            RtrPeer {
+                protocol: "BGP4".into(),
+                peer: RouterExpr::RtrSet("rtrs-peers".into()),
+                options: "".into(),
+            },
+        ],
+    };
+    assert_eq!(
+        parse_lexed_inet_rtr(inet_rtr)?,
+        ("rtr1.example.net".into(), expected)
+    );
+
+    let rtr_set = ::lex::AsOrRouteSet::new(
+        "AS65000:RTRS-CORE".into(),
+        "".into(),
+        vec![
+            "rtr1.example.net".into(),
+            "RTRS-EDGE".into(),
+            "192.0.2.5".into(),
+            "m#AS65000:RTRS-CORE".into(),
+        ],
+    );
+    let expected = ir::RtrSet {
+        body: "".into(),
+        members: vec![
+            RouterExpr::InetRtr("rtr1.example.net".into()),
+            RouterExpr::RtrSet("RTRS-EDGE".into()),
+            Ip("192.0.2.5".parse()?),
+            RouterExpr::RtrSet("m#AS65000:RTRS-CORE".into()),
+        ],
+        member_spans: vec![],
+    };
+    assert_eq!(
+        parse_lexed_rtr_set(rtr_set)?,
+        ("AS65000:RTRS-CORE".into(), expected)
+    );
+    assert!(parse_lexed_rtr_set(::lex::AsOrRouteSet::default()).is_err());
+    Ok(())
+}
+
 fn expected_aut_nums() -> BTreeMap<u32, AutNum> {
     let body = "remarks:\nremarks: This aut-num has been transfered as part of the ERX.\nremarks: It was present in both the ARIN and RIPE databases, so\nremarks: the information from both databases has been merged.\nremarks: If you are the mntner of this object, please update it\nremarks: to reflect the correct information.\nremarks:\nremarks: Please see the FAQ for this process:\nremarks: http://www.ripe.net/db/erx/erx-asn/group3-faq.html\nremarks:\nremarks: **** INFORMATION FROM ARIN OBJECT ****\nremarks: as-name: EASINET-AS1\ndescr: EASInet Operations Center\n Riemenschneiderstrasse 11\n D-5300 Bonn 2\n DE\nadmin-c: DUMY-RIPE\ntech-c: DUMY-RIPE\nremarks: changed: hostmaster@arin.net 19900302\nremarks: changed: hostmaster@arin.net 19910416\nremarks:\nremarks: **** INFORMATION FROM RIPE OBJECT ****\nas-name: UNSPECIFIED\ndescr: EASInet\nimport: from AS690\n action pref=100;\n accept ANY\nimport: from AS513\n action pref=100;\n accept ANY\nimport: from AS559\n action pref=100;\n accept AS559\nimport: from AS697\n action pref=100;\n accept AS697\nexport: to AS690\n announce AS590\nexport: to AS513\n announce AS590\nexport: to AS559\n announce AS590\nexport: to AS697\n announce AS590\ndefault: to AS690\n action pref=100;\n networks ANY\ndefault: to AS513\n action pref=200;\n networks ANY\nstatus: LEGACY\nnotify: stf@easi.net\nmnt-by: RIPE-NCC-AN-MNT # WARNING: maintainer added to protect object\ncreated: 2002-09-19T15:23:42Z\nlast-modified: 2017-11-15T09:12:37Z\nsource: RIPE\nremarks: ****************************\nremarks: * THIS OBJECT IS MODIFIED\nremarks: * Please note that all data that is generally regarded as personal\nremarks: * data has been removed from this object.\nremarks: * To view the original object, please query the RIPE Database at:\nremarks: * http://www.ripe.net/whois\nremarks: ****************************\n".into();
     let imports = Versions {
@@ -60,6 +130,7 @@ fn expected_aut_nums() -> BTreeMap<u32, AutNum> {
                         actions: btreemap! {"pref".into() => Assigned("100".into())},
                     }],
                     mp_filter: Any,
+                    span: span(21, 24, 26),
                 },
                 Entry {
                     mp_peerings: vec![PeeringAction {
@@ -71,6 +142,7 @@ fn expected_aut_nums() -> BTreeMap<u32, AutNum> {
                         actions: btreemap! {"pref".into() => Assigned("100".into())},
                     }],
                     mp_filter: Any,
+                    span: span(22, 27, 29),
                 },
                 Entry {
                     mp_peerings: vec![PeeringAction {
@@ -82,6 +154,7 @@ fn expected_aut_nums() -> BTreeMap<u32, AutNum> {
                         actions: btreemap! {"pref".into() => Assigned("100".into())},
                     }],
                     mp_filter: AsNum(559, NoOp),
+                    span: span(23, 30, 32),
                 },
                 Entry {
                     mp_peerings: vec![PeeringAction {
@@ -93,6 +166,7 @@ fn expected_aut_nums() -> BTreeMap<u32, AutNum> {
                         actions: btreemap! {"pref".into() => Assigned("100".into())},
                     }],
                     mp_filter: AsNum(697, NoOp),
+                    span: span(24, 33, 35),
                 },
             ],
             unicast: vec![],
@@ -123,6 +197,7 @@ fn expected_aut_nums() -> BTreeMap<u32, AutNum> {
                         actions: btreemap! {},
                     }],
                     mp_filter: AsNum(590, NoOp),
+                    span: span(25, 36, 37),
                 },
                 Entry {
                     mp_peerings: vec![PeeringAction {
@@ -134,6 +209,7 @@ fn expected_aut_nums() -> BTreeMap<u32, AutNum> {
                         actions: btreemap! {},
                     }],
                     mp_filter: AsNum(590, NoOp),
+                    span: span(26, 38, 39),
                 },
                 Entry {
                     mp_peerings: vec![PeeringAction {
@@ -145,6 +221,7 @@ fn expected_aut_nums() -> BTreeMap<u32, AutNum> {
                         actions: btreemap! {},
                     }],
                     mp_filter: AsNum(590, NoOp),
+                    span: span(27, 40, 41),
                 },
                 Entry {
                     mp_peerings: vec![PeeringAction {
@@ -156,6 +233,7 @@ fn expected_aut_nums() -> BTreeMap<u32, AutNum> {
                         actions: btreemap! {},
                     }],
                     mp_filter: AsNum(590, NoOp),
+                    span: span(28, 42, 43),
                 },
             ],
             unicast: vec![],
@@ -178,11 +256,11 @@ fn expected_aut_nums() -> BTreeMap<u32, AutNum> {
 }
 
 fn expected_as_sets() -> BTreeMap<String, AsSet> {
-    btreemap! {"AS-RESTENA".into()=> AsSet { body: "descr: Reseau Teleinformatique de l'Education Nationale\ndescr: Educational and research network for Luxembourg\nmembers: AS2602\nmembers: AS42909\nmembers: AS51966\nmembers: AS-LXP\nmembers: AS-VDL\ntech-c: DUMY-RIPE\nadmin-c: DUMY-RIPE\nnotify: noc@restena.lu\nmnt-by: AS2602-MNT\ncreated: 1970-01-01T00:00:00Z\nlast-modified: 2022-09-08T09:11:41Z\nsource: RIPE\nremarks: ****************************\nremarks: * THIS OBJECT IS MODIFIED\nremarks: * Please note that all data that is generally regarded as personal\nremarks: * data has been removed from this object.\nremarks: * To view the original object, please query the RIPE Database at:\nremarks: * http://www.ripe.net/whois\nremarks: ****************************\n".into(), members: vec![2602, 42909, 51966], set_members: vec!["AS-LXP".into(), "AS-VDL".into()], is_any: false }}
+    btreemap! {"AS-RESTENA".into()=> AsSet { body: "descr: Reseau Teleinformatique de l'Education Nationale\ndescr: Educational and research network for Luxembourg\nmembers: AS2602\nmembers: AS42909\nmembers: AS51966\nmembers: AS-LXP\nmembers: AS-VDL\ntech-c: DUMY-RIPE\nadmin-c: DUMY-RIPE\nnotify: noc@restena.lu\nmnt-by: AS2602-MNT\ncreated: 1970-01-01T00:00:00Z\nlast-modified: 2022-09-08T09:11:41Z\nsource: RIPE\nremarks: ****************************\nremarks: * THIS OBJECT IS MODIFIED\nremarks: * Please note that all data that is generally regarded as personal\nremarks: * data has been removed from this object.\nremarks: * To view the original object, please query the RIPE Database at:\nremarks: * http://www.ripe.net/whois\nremarks: ****************************\n".into(), members: vec![2602, 42909, 51966], set_members: vec!["AS-LXP".into(), "AS-VDL".into()], is_any: false, member_spans: single_line_spans(&[2, 3, 4]), set_member_spans: single_line_spans(&[5, 6]) }}
 }
 
 fn expected_route_sets() -> BTreeMap<String, RouteSet> {
-    btreemap! {"AS13646:RS-PEERLANS".into()=> RouteSet { body: "descr: Internet Exchange Peering LAN Routes\nmembers: 195.66.224.0/23\nmembers: 194.68.129.0/24\nmembers: 217.29.66.0/23\nmembers: 193.149.1.0/25\nmembers: 193.149.1.128/25\nmembers: 193.148.15.0/24\nmembers: 194.31.232.0/24\nmembers: 194.42.48.0/25\nmembers: 194.53.172.0/26\nmembers: 193.203.0.0/24\nadmin-c: DUMY-RIPE\ntech-c: DUMY-RIPE\nmnt-by: ZIGGO-SERVICES-MNT\ncreated: 1970-01-01T00:00:00Z\nlast-modified: 2020-01-21T15:43:54Z\nsource: RIPE\nremarks: ****************************\nremarks: * THIS OBJECT IS MODIFIED\nremarks: * Please note that all data that is generally regarded as personal\nremarks: * data has been removed from this object.\nremarks: * To view the original object, please query the RIPE Database at:\nremarks: * http://www.ripe.net/whois\nremarks: ****************************\n".into(), members: vec![RSRange(AddrPfxRange { address_prefix: ipn("195.66.224.0/23"), range_operator: NoOp }), RSRange(AddrPfxRange { address_prefix: ipn("194.68.129.0/24"), range_operator: NoOp }), RSRange(AddrPfxRange { address_prefix: ipn("217.29.66.0/23"), range_operator: NoOp }), RSRange(AddrPfxRange { address_prefix: ipn("193.149.1.0/25"), range_operator: NoOp }), RSRange(AddrPfxRange { address_prefix: ipn("193.149.1.128/25"), range_operator: NoOp }), RSRange(AddrPfxRange { address_prefix: ipn("193.148.15.0/24"), range_operator: NoOp }), RSRange(AddrPfxRange { address_prefix: ipn("194.31.232.0/24"), range_operator: NoOp }), RSRange(AddrPfxRange { address_prefix: ipn("194.42.48.0/25"), range_operator: NoOp }), RSRange(AddrPfxRange { address_prefix: ipn("194.53.172.0/26"), range_operator: NoOp }), RSRange(AddrPfxRange { address_prefix: ipn("193.203.0.0/24"), range_operator: NoOp })] }, "RS-PEERING-TESTBED".into()=> RouteSet { body: "".into(), members: vec![RSRange(AddrPfxRange { address_prefix: ipn("184.164.224.0/19"), range_operator: Range(19, 24) }), RSRange(AddrPfxRange { address_prefix: ipn("204.9.168.0/22"), range_operator: Range(22, 24) }), RSRange(AddrPfxRange { address_prefix: ipn("138.185.228.0/22"), range_operator: Range(22, 24) }), RSRange(AddrPfxRange { address_prefix: ipn("2804:269c::/32"), range_operator: Range(32, 32) }), RSRange(AddrPfxRange { address_prefix: ipn("2804:269c::/42"), range_operator: Range(44, 48) }), RSRange(AddrPfxRange { address_prefix: ipn("2804:269c:fe00::/43"), range_operator: Range(48, 48) }), RSRange(AddrPfxRange { address_prefix: ipn("2804:269c:fe40::/43"), range_operator: Range(48, 48) }), RSRange(AddrPfxRange { address_prefix: ipn("66.180.190.0/23"), range_operator: Range(23, 24) }), RSRange(AddrPfxRange { address_prefix: ipn("208.87.172.0/23"), range_operator: Range(23, 24) }), RSRange(AddrPfxRange { address_prefix: ipn("199.36.157.0/24"), range_operator: Range(24, 24) }), RSRange(AddrPfxRange { address_prefix: ipn("103.171.218.0/23"), range_operator: Range(23, 24) }), RSRange(AddrPfxRange { address_prefix: ipn("151.216.4.0/23"), range_operator: Range(23, 24) }), RSRange(AddrPfxRange { address_prefix: ipn("102.218.96.0/23"), range_operator: Range(23, 24) }), RSRange(AddrPfxRange { address_prefix: ipn("165.140.104.0/23"), range_operator: Range(23, 24) }), RSRange(AddrPfxRange { address_prefix: ipn("201.219.252.0/23"), range_operator: Range(23, 24) }), RSRange(AddrPfxRange { address_prefix: ipn("2604:4540::/48"), range_operator: Range(48, 48) }), RSRange(AddrPfxRange { address_prefix: ipn("2604:4540:80::/44"), range_operator: Range(44, 48) }), RSRange(AddrPfxRange { address_prefix: ipn("2620:33:c000::/48"), range_operator: Range(48, 48) }), RSRange(AddrPfxRange { address_prefix: ipn("2001:df7:5380::/47"), range_operator: Range(47, 48) }), RSRange(AddrPfxRange { address_prefix: ipn("2001:7fc:2::/47"), range_operator: Range(47, 48) }), RSRange(AddrPfxRange { address_prefix: ipn("2001:43f8:df0::/47"), range_operator: Range(47, 48) }), RSRange(AddrPfxRange { address_prefix: ipn("2620:9e:6000::/47"), range_operator: Range(47, 48) }), RSRange(AddrPfxRange { address_prefix: ipn("2801:1e:1800::/47"), range_operator: Range(47, 48) })] }}
+    btreemap! {"AS13646:RS-PEERLANS".into()=> RouteSet { body: "descr: Internet Exchange Peering LAN Routes\nmembers: 195.66.224.0/23\nmembers: 194.68.129.0/24\nmembers: 217.29.66.0/23\nmembers: 193.149.1.0/25\nmembers: 193.149.1.128/25\nmembers: 193.148.15.0/24\nmembers: 194.31.232.0/24\nmembers: 194.42.48.0/25\nmembers: 194.53.172.0/26\nmembers: 193.203.0.0/24\nadmin-c: DUMY-RIPE\ntech-c: DUMY-RIPE\nmnt-by: ZIGGO-SERVICES-MNT\ncreated: 1970-01-01T00:00:00Z\nlast-modified: 2020-01-21T15:43:54Z\nsource: RIPE\nremarks: ****************************\nremarks: * THIS OBJECT IS MODIFIED\nremarks: * Please note that all data that is generally regarded as personal\nremarks: * data has been removed from this object.\nremarks: * To view the original object, please query the RIPE Database at:\nremarks: * http://www.ripe.net/whois\nremarks: ****************************\n".into(), members: vec![RSRange(AddrPfxRange { address_prefix: ipn("195.66.224.0/23"), range_operator: NoOp }), RSRange(AddrPfxRange { address_prefix: ipn("194.68.129.0/24"), range_operator: NoOp }), RSRange(AddrPfxRange { address_prefix: ipn("217.29.66.0/23"), range_operator: NoOp }), RSRange(AddrPfxRange { address_prefix: ipn("193.149.1.0/25"), range_operator: NoOp }), RSRange(AddrPfxRange { address_prefix: ipn("193.149.1.128/25"), range_operator: NoOp }), RSRange(AddrPfxRange { address_prefix: ipn("193.148.15.0/24"), range_operator: NoOp }), RSRange(AddrPfxRange { address_prefix: ipn("194.31.232.0/24"), range_operator: NoOp }), RSRange(AddrPfxRange { address_prefix: ipn("194.42.48.0/25"), range_operator: NoOp }), RSRange(AddrPfxRange { address_prefix: ipn("194.53.172.0/26"), range_operator: NoOp }), RSRange(AddrPfxRange { address_prefix: ipn("193.203.0.0/24"), range_operator: NoOp })], member_spans: single_line_spans(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]) }, "RS-PEERING-TESTBED".into()=> RouteSet { body: "".into(), members: vec![RSRange(AddrPfxRange { address_prefix: ipn("184.164.224.0/19"), range_operator: Range(19, 24) }), RSRange(AddrPfxRange { address_prefix: ipn("204.9.168.0/22"), range_operator: Range(22, 24) }), RSRange(AddrPfxRange { address_prefix: ipn("138.185.228.0/22"), range_operator: Range(22, 24) }), RSRange(AddrPfxRange { address_prefix: ipn("2804:269c::/32"), range_operator: Range(32, 32) }), RSRange(AddrPfxRange { address_prefix: ipn("2804:269c::/42"), range_operator: Range(44, 48) }), RSRange(AddrPfxRange { address_prefix: ipn("2804:269c:fe00::/43"), range_operator: Range(48, 48) }), RSRange(AddrPfxRange { address_prefix: ipn("2804:269c:fe40::/43"), range_operator: Range(48, 48) }), RSRange(AddrPfxRange { address_prefix: ipn("66.180.190.0/23"), range_operator: Range(23, 24) }), RSRange(AddrPfxRange { address_prefix: ipn("208.87.172.0/23"), range_operator: Range(23, 24) }), RSRange(AddrPfxRange { address_prefix: ipn("199.36.157.0/24"), range_operator: Range(24, 24) }), RSRange(AddrPfxRange { address_prefix: ipn("103.171.218.0/23"), range_operator: Range(23, 24) }), RSRange(AddrPfxRange { address_prefix: ipn("151.216.4.0/23"), range_operator: Range(23, 24) }), RSRange(AddrPfxRange { address_prefix: ipn("102.218.96.0/23"), range_operator: Range(23, 24) }), RSRange(AddrPfxRange { address_prefix: ipn("165.140.104.0/23"), range_operator: Range(23, 24) }), RSRange(AddrPfxRange { address_prefix: ipn("201.219.252.0/23"), range_operator: Range(23, 24) }), RSRange(AddrPfxRange { address_prefix: ipn("2604:4540::/48"), range_operator: Range(48, 48) }), RSRange(AddrPfxRange { address_prefix: ipn("2604:4540:80::/44"), range_operator: Range(44, 48) }), RSRange(AddrPfxRange { address_prefix: ipn("2620:33:c000::/48"), range_operator: Range(48, 48) }), RSRange(AddrPfxRange { address_prefix: ipn("2001:df7:5380::/47"), range_operator: Range(47, 48) }), RSRange(AddrPfxRange { address_prefix: ipn("2001:7fc:2::/47"), range_operator: Range(47, 48) }), RSRange(AddrPfxRange { address_prefix: ipn("2001:43f8:df0::/47"), range_operator: Range(47, 48) }), RSRange(AddrPfxRange { address_prefix: ipn("2620:9e:6000::/47"), range_operator: Range(47, 48) }), RSRange(AddrPfxRange { address_prefix: ipn("2801:1e:1800::/47"), range_operator: Range(47, 48) })], member_spans: vec![] }}
 }
 
 fn ipn(s: &str) -> IpNet {
@@ -202,3 +280,19 @@ fn expected_filter_sets() -> BTreeMap<String, FilterSet> {
 fn expected_as_routes() -> BTreeMap<u32, Vec<IpNet>> {
     btreemap! {10=> vec![ipn("45.143.208.0/22")]}
 }
+
+fn span(attr: u32, start_line: u32, end_line: u32) -> Option<SourceSpan> {
+    Some(SourceSpan {
+        attr,
+        start_line,
+        end_line,
+    })
+}
+
+/// Spans of members each listed on line `attr`, the same as the attribute.
+fn single_line_spans(attrs: &[u32]) -> Vec<SourceSpan> {
+    attrs
+        .iter()
+        .map(|&attr| span(attr, attr, attr).unwrap())
+        .collect()
+}
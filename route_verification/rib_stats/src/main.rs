@@ -255,6 +255,7 @@ fn process_rib_file(query: &QueryIr, db: &AsRelDb, rib_file: &Path) -> Result<()
                 Some(Relationship::P2C) => b"down",
                 Some(Relationship::P2P) => b"peer",
                 Some(Relationship::C2P) => b"up",
+                Some(Relationship::S2S) => b"sibling",
                 None => b"other",
             })?;
             as_pair_stats_file.write_all(b"\n")?;
@@ -0,0 +1,181 @@
+//! AS path verification with Autonomous System Provider Authorization
+//! (ASPA), following
+//! <https://datatracker.ietf.org/doc/draft-ietf-sidrops-aspa-verification/>.
+use std::collections::{HashMap, HashSet};
+
+use super::*;
+
+/// Result of checking if an AS is a provider of another.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum Hop {
+    /// The ASPA of the customer lists the AS as a provider.
+    ProviderPlus,
+    /// The ASPA of the customer does not list the AS.
+    NotProviderPlus,
+    /// The customer has no ASPA.
+    NoAttestation,
+}
+
+/// ASPA verification verdict for an AS path.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum AspaState {
+    Valid,
+    Invalid,
+    Unknown,
+}
+
+/// # ASPA database
+/// **Verify** AS paths using [`verify_upstream`](#method.verify_upstream)
+/// for routes from customers or peers,
+/// and [`verify_downstream`](#method.verify_downstream) for routes from
+/// providers.
+///
+/// **Construct** from a RPKI validator JSON export using
+/// [`load`](#method.load).
+#[derive(Clone, Debug, Default)]
+pub struct AspaDb {
+    /// Providers of each customer AS that has an ASPA.
+    pub customer2providers: HashMap<u32, HashSet<u32>>,
+}
+
+impl AspaDb {
+    /// Load from JSON file `path`.
+    pub fn load<P>(path: P) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        debug!("Loading ASPA database `{path:?}`.");
+        Self::do_load(path).with_context(|| format!("loading `{path:?}` for AspaDb"))
+    }
+
+    fn do_load(path: &Path) -> Result<Self> {
+        Self::from_json(BufReader::new(File::open(path)?))
+    }
+
+    /// Construct from the JSON export of RPKI validators such as Routinator
+    /// or rpki-client.
+    /// # Expected format
+    /// `{"aspas": [{"customer": "AS64496", "providers": ["AS64497"]}]}`,
+    /// where `customer` can also be `customer_asid`, AS numbers can also be
+    /// numbers, and other fields are ignored.
+    pub fn from_json<R: Read>(reader: R) -> Result<Self> {
+        let export: JsonExport = serde_json::from_reader(reader)?;
+        let mut db = Self::default();
+        for JsonAspa {
+            customer,
+            providers,
+        } in export.aspas
+        {
+            let providers = providers
+                .into_iter()
+                .map(JsonAsn::try_into_asn)
+                .collect::<Result<Vec<_>>>()?;
+            db.insert(customer.try_into_asn()?, providers);
+        }
+        Ok(db)
+    }
+
+    /// Add `providers` to the ASPA of `customer`.
+    pub fn insert<I>(&mut self, customer: u32, providers: I)
+    where
+        I: IntoIterator<Item = u32>,
+    {
+        self.customer2providers
+            .entry(customer)
+            .or_default()
+            .extend(providers);
+    }
+
+    /// Check if `provider` is a provider of `customer`.
+    pub fn hop(&self, customer: u32, provider: u32) -> Hop {
+        match self.customer2providers.get(&customer) {
+            Some(providers) if providers.contains(&provider) => Hop::ProviderPlus,
+            Some(_) => Hop::NotProviderPlus,
+            None => Hop::NoAttestation,
+        }
+    }
+
+    /// Verify `path` of a route received from a customer or a peer.
+    /// `path` is ordered as in BGP, the neighbor first and the origin last,
+    /// and must not contain AS sets.
+    pub fn verify_upstream(&self, path: &[u32]) -> AspaState {
+        let path = origin_first(path);
+        let hops: Vec<_> = path
+            .windows(2)
+            .map(|pair| self.hop(pair[0], pair[1]))
+            .collect();
+        if hops.contains(&Hop::NotProviderPlus) {
+            AspaState::Invalid
+        } else if hops.iter().all(|hop| *hop == Hop::ProviderPlus) {
+            AspaState::Valid
+        } else {
+            AspaState::Unknown
+        }
+    }
+
+    /// Verify `path` of a route received from a provider.
+    /// `path` is ordered as in [`verify_upstream`](#method.verify_upstream).
+    pub fn verify_downstream(&self, path: &[u32]) -> AspaState {
+        let path = origin_first(path);
+        let n_ases = path.len();
+        let up_hops: Vec<_> = path
+            .windows(2)
+            .map(|pair| self.hop(pair[0], pair[1]))
+            .collect();
+        let down_hops: Vec<_> = path
+            .windows(2)
+            .rev()
+            .map(|pair| self.hop(pair[1], pair[0]))
+            .collect();
+        let (max_up_ramp, min_up_ramp) = ramps(&up_hops, n_ases);
+        let (max_down_ramp, min_down_ramp) = ramps(&down_hops, n_ases);
+        if max_up_ramp + max_down_ramp < n_ases {
+            AspaState::Invalid
+        } else if min_up_ramp + min_down_ramp < n_ases {
+            AspaState::Unknown
+        } else {
+            AspaState::Valid
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.customer2providers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.customer2providers.is_empty()
+    }
+}
+
+/// Reverse `path` to start from the origin, with prepending removed.
+fn origin_first(path: &[u32]) -> Vec<u32> {
+    let mut path: Vec<_> = path.iter().rev().copied().collect();
+    path.dedup();
+    path
+}
+
+/// Maximum and minimum number of ASes on a ramp of `n_ases` starting from one
+/// end, given `hops` going from that end towards the other.
+fn ramps(hops: &[Hop], n_ases: usize) -> (usize, usize) {
+    let first = |predicate: fn(&Hop) -> bool| {
+        hops.iter()
+            .position(predicate)
+            .map_or(n_ases, |index| index + 1)
+    };
+    let max_ramp = first(|hop| *hop == Hop::NotProviderPlus);
+    let min_ramp = first(|hop| *hop != Hop::ProviderPlus);
+    (max_ramp, min_ramp)
+}
+
+#[derive(Deserialize)]
+struct JsonExport {
+    aspas: Vec<JsonAspa>,
+}
+
+#[derive(Deserialize)]
+struct JsonAspa {
+    #[serde(alias = "customer_asid")]
+    customer: JsonAsn,
+    providers: Vec<JsonAsn>,
+}
@@ -0,0 +1,276 @@
+//! Route Origin Authorizations (ROAs) from the RPKI,
+//! for route origin validation as in RFC 6811.
+//!
+//! See [`RoaDb`] for usage.
+use std::{
+    error::Error,
+    fs::File,
+    io::{prelude::*, BufReader},
+    path::Path,
+};
+
+use anyhow::{bail, Context, Result};
+use ipnet::IpNet;
+use log::debug;
+use serde::{Deserialize, Serialize};
+
+pub mod aspa;
+#[cfg(test)]
+mod tests;
+mod trie;
+
+#[doc(inline)]
+pub use {
+    aspa::{AspaDb, AspaState, Hop},
+    trie::PrefixTrie,
+};
+
+/// A validated ROA payload: `asn` may originate `prefix` and its more
+/// specifics up to length `max_length`.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct Roa {
+    pub asn: u32,
+    pub prefix: IpNet,
+    pub max_length: u8,
+}
+
+impl Roa {
+    pub fn new(asn: u32, prefix: IpNet, max_length: u8) -> Result<Self> {
+        if max_length < prefix.prefix_len() || max_length > prefix.max_prefix_len() {
+            bail!("max length {max_length} does not fit prefix {prefix}");
+        }
+        Ok(Self {
+            asn,
+            prefix,
+            max_length,
+        })
+    }
+
+    /// If this ROA authorizes `origin` to originate `prefix`.
+    /// AS0 ROAs authorize no one.
+    pub fn matches(&self, prefix: &IpNet, origin: u32) -> bool {
+        self.asn != 0
+            && self.asn == origin
+            && prefix.prefix_len() <= self.max_length
+            && self.prefix.contains(prefix)
+    }
+}
+
+/// Route origin validation state, as in RFC 6811.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum RovState {
+    /// Some covering ROA matches the origin and length.
+    Valid,
+    /// Covering ROAs exist, but none matches.
+    Invalid,
+    /// No ROA covers the prefix.
+    NotFound,
+}
+
+/// # ROA database
+/// **Validate** a route's origin using [`validate`](#method.validate).
+///
+/// **Construct** from a RPKI validator export using [`load`](#method.load),
+/// which reads JSON ([`from_json`](#method.from_json)) if the file name ends
+/// in `.json`, and CSV ([`from_lines`](#method.from_lines)) otherwise.
+#[derive(Clone, Debug, Default)]
+pub struct RoaDb {
+    pub v4: PrefixTrie<Roa>,
+    pub v6: PrefixTrie<Roa>,
+}
+
+impl RoaDb {
+    /// Load from JSON or CSV file `path`.
+    pub fn load<P>(path: P) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        debug!("Loading ROA database `{path:?}`.");
+        Self::do_load(path).with_context(|| format!("loading `{path:?}` for RoaDb"))
+    }
+
+    fn do_load(path: &Path) -> Result<Self> {
+        let reader = BufReader::new(File::open(path)?);
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some(extension) if extension.eq_ignore_ascii_case("json") => Self::from_json(reader),
+            _ => Self::from_maybe_lines(reader.lines()),
+        }
+    }
+
+    /// Construct from the JSON export of RPKI validators such as Routinator
+    /// or rpki-client.
+    /// # Expected format
+    /// `{"roas": [{"asn": "AS13335", "prefix": "1.0.0.0/24", "maxLength": 24}]}`,
+    /// where `asn` can also be a number, `maxLength` defaults to the prefix
+    /// length, and other fields are ignored.
+    pub fn from_json<R: Read>(reader: R) -> Result<Self> {
+        let export: JsonExport = serde_json::from_reader(reader)?;
+        let mut db = Self::default();
+        for roa in export.roas {
+            db.insert(roa.try_into()?);
+        }
+        Ok(db)
+    }
+
+    /// Construct from `lines` of a CSV export.
+    /// # Errors
+    /// One of the lines is not in the expected format.
+    /// # Expected format
+    /// Empty lines, lines prefixed with `#`, and the header line starting
+    /// with `ASN` are ignored.
+    ///
+    /// Each line is `asn,prefix,max_length` followed by optional fields
+    /// such as the trust anchor, e.g., `AS13335,1.0.0.0/24,24,apnic`.
+    pub fn from_lines<I, S>(lines: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut db = Self::default();
+        for line in lines {
+            db.add_line(line.as_ref())?;
+        }
+        Ok(db)
+    }
+
+    /// Same as [`from_lines`](#method.from_lines) but `maybe_lines` can contain
+    /// errors, in which case the error is returned.
+    pub fn from_maybe_lines<I, S, E>(maybe_lines: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = Result<S, E>>,
+        S: AsRef<str>,
+        E: Error + Sync + Send + 'static,
+    {
+        let mut db = Self::default();
+        for maybe_line in maybe_lines {
+            db.add_line(maybe_line?.as_ref())?;
+        }
+        Ok(db)
+    }
+
+    fn add_line(&mut self, line: &str) -> Result<()> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || starts_with_ignore_case(line, "ASN,") {
+            return Ok(());
+        }
+        let roa = try_parse_roa(line).with_context(|| format!("parsing ROA line `{line}`"))?;
+        self.insert(roa);
+        Ok(())
+    }
+
+    pub fn insert(&mut self, roa: Roa) {
+        match roa.prefix {
+            IpNet::V4(_) => self.v4.insert(&roa.prefix, roa),
+            IpNet::V6(_) => self.v6.insert(&roa.prefix, roa),
+        }
+    }
+
+    /// ROAs whose prefixes cover `prefix`, least specific first.
+    pub fn covering(&self, prefix: &IpNet) -> Vec<&Roa> {
+        match prefix {
+            IpNet::V4(_) => self.v4.covering(prefix),
+            IpNet::V6(_) => self.v6.covering(prefix),
+        }
+    }
+
+    /// Validate `origin` originating `prefix`.
+    /// `origin` is `None` if it cannot be determined, e.g., the path ends in
+    /// an AS set, in which case no ROA matches.
+    pub fn validate(&self, prefix: &IpNet, origin: Option<u32>) -> RovState {
+        let covering = self.covering(prefix);
+        if covering.is_empty() {
+            RovState::NotFound
+        } else if origin
+            .is_some_and(|origin| covering.iter().any(|roa| roa.matches(prefix, origin)))
+        {
+            RovState::Valid
+        } else {
+            RovState::Invalid
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.v4.len() + self.v6.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[derive(Deserialize)]
+struct JsonExport {
+    roas: Vec<JsonRoa>,
+}
+
+#[derive(Deserialize)]
+struct JsonRoa {
+    asn: JsonAsn,
+    prefix: IpNet,
+    #[serde(rename = "maxLength")]
+    max_length: Option<u8>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum JsonAsn {
+    Num(u32),
+    Name(String),
+}
+
+impl JsonAsn {
+    fn try_into_asn(self) -> Result<u32> {
+        match self {
+            Self::Num(asn) => Ok(asn),
+            Self::Name(name) => parse_asn(&name),
+        }
+    }
+}
+
+impl TryFrom<JsonRoa> for Roa {
+    type Error = anyhow::Error;
+
+    fn try_from(
+        JsonRoa {
+            asn,
+            prefix,
+            max_length,
+        }: JsonRoa,
+    ) -> Result<Self> {
+        Self::new(
+            asn.try_into_asn()?,
+            prefix,
+            max_length.unwrap_or(prefix.prefix_len()),
+        )
+    }
+}
+
+pub fn try_parse_roa(line: &str) -> Result<Roa> {
+    let mut fields = line.split(',').map(str::trim);
+    let (Some(asn), Some(prefix), Some(max_length)) = (fields.next(), fields.next(), fields.next())
+    else {
+        bail!("expected at least 3 fields");
+    };
+    let prefix: IpNet = prefix.parse()?;
+    let max_length = match max_length {
+        "" => prefix.prefix_len(),
+        max_length => max_length.parse()?,
+    };
+    Roa::new(parse_asn(asn)?, prefix, max_length)
+}
+
+/// Parse `AS13335` or `13335`.
+pub fn parse_asn(name: &str) -> Result<u32> {
+    let digits = match starts_with_ignore_case(name, "AS") {
+        true => &name[2..],
+        false => name,
+    };
+    digits
+        .parse()
+        .with_context(|| format!("parsing `{name}` as AS number"))
+}
+
+fn starts_with_ignore_case(s: &str, prefix: &str) -> bool {
+    s.len() >= prefix.len() && s.as_bytes()[..prefix.len()].eq_ignore_ascii_case(prefix.as_bytes())
+}
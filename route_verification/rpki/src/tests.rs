@@ -0,0 +1,135 @@
+use super::*;
+
+use RovState::*;
+
+const CSV: &str = "ASN,IP Prefix,Max Length,Trust Anchor
+AS13335,1.0.0.0/24,24,apnic
+# comment
+
+AS64496,10.0.0.0/8,16,ripe
+AS0,10.9.0.0/16,16,ripe
+AS64497,2001:db8::/32,48,arin
+";
+
+const JSON: &str = r#"{
+  "metadata": {"generated": 1690000000},
+  "roas": [
+    {"asn": "AS13335", "prefix": "1.0.0.0/24", "maxLength": 24, "ta": "apnic"},
+    {"asn": 64496, "prefix": "10.0.0.0/8", "maxLength": 16, "ta": "ripe"},
+    {"asn": "AS0", "prefix": "10.9.0.0/16", "ta": "ripe"},
+    {"asn": "AS64497", "prefix": "2001:db8::/32", "maxLength": 48, "ta": "arin"}
+  ]
+}"#;
+
+fn validate(db: &RoaDb, prefix: &str, origin: Option<u32>) -> Result<RovState> {
+    Ok(db.validate(&prefix.parse()?, origin))
+}
+
+fn check_db(db: &RoaDb) -> Result<()> {
+    assert_eq!(db.len(), 4);
+    for (prefix, origin, expected) in [
+        ("1.0.0.0/24", Some(13335), Valid),
+        ("1.0.0.0/24", Some(64496), Invalid),
+        ("1.0.0.0/25", Some(13335), Invalid),
+        ("1.0.1.0/24", Some(13335), NotFound),
+        ("10.1.0.0/16", Some(64496), Valid),
+        ("10.1.1.0/24", Some(64496), Invalid),
+        ("10.1.0.0/16", None, Invalid),
+        ("10.9.0.0/16", Some(64496), Valid),
+        ("10.9.0.0/16", Some(0), Invalid),
+        ("2001:db8:1::/48", Some(64497), Valid),
+        ("2001:db9::/32", Some(64497), NotFound),
+    ] {
+        assert_eq!(
+            validate(db, prefix, origin)?,
+            expected,
+            "{prefix} {origin:?}"
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn csv() -> Result<()> {
+    check_db(&RoaDb::from_lines(CSV.lines())?)
+}
+
+#[test]
+fn json() -> Result<()> {
+    check_db(&RoaDb::from_json(JSON.as_bytes())?)
+}
+
+#[test]
+fn bad_lines() {
+    for line in [
+        "AS13335,1.0.0.0/24",
+        "AS13335,1.0.0.0/24,23",
+        "AS13335,1.0.0.0/24,33",
+        "ASX,1.0.0.0/24,24",
+    ] {
+        assert!(RoaDb::from_lines([line]).is_err(), "{line}");
+    }
+}
+
+#[test]
+fn covering_least_specific_first() -> Result<()> {
+    let db = RoaDb::from_lines(CSV.lines())?;
+    let asns: Vec<_> = db
+        .covering(&"10.9.1.0/24".parse()?)
+        .into_iter()
+        .map(|roa| roa.asn)
+        .collect();
+    assert_eq!(asns, [64496, 0]);
+    Ok(())
+}
+
+const ASPA_JSON: &str = r#"{
+  "aspas": [
+    {"customer": "AS1", "providers": ["AS2"]},
+    {"customer_asid": 2, "expires": 1690000000, "providers": [3]},
+    {"customer": "AS3", "providers": ["AS0"]},
+    {"customer": "AS4", "providers": ["AS3"]}
+  ]
+}"#;
+
+#[test]
+fn aspa_hops() -> Result<()> {
+    let db = AspaDb::from_json(ASPA_JSON.as_bytes())?;
+    assert_eq!(db.len(), 4);
+    assert_eq!(db.hop(1, 2), Hop::ProviderPlus);
+    assert_eq!(db.hop(3, 4), Hop::NotProviderPlus);
+    assert_eq!(db.hop(5, 2), Hop::NoAttestation);
+    Ok(())
+}
+
+#[test]
+fn aspa_upstream() -> Result<()> {
+    use AspaState::*;
+    let db = AspaDb::from_json(ASPA_JSON.as_bytes())?;
+    for (path, expected) in [
+        (&[3, 2, 1][..], Valid),
+        (&[2, 2, 1, 1], Valid),
+        (&[1], Valid),
+        (&[4, 3, 2, 1], Invalid),
+        (&[5, 2, 1], Invalid),
+        (&[2, 5], Unknown),
+    ] {
+        assert_eq!(db.verify_upstream(path), expected, "{path:?}");
+    }
+    Ok(())
+}
+
+#[test]
+fn aspa_downstream() -> Result<()> {
+    use AspaState::*;
+    let db = AspaDb::from_json(ASPA_JSON.as_bytes())?;
+    for (path, expected) in [
+        (&[4, 3, 2, 1][..], Valid),
+        (&[3, 2, 1], Valid),
+        (&[4, 5, 3, 2, 1], Invalid),
+        (&[5, 6, 2, 1], Unknown),
+    ] {
+        assert_eq!(db.verify_downstream(path), expected, "{path:?}");
+    }
+    Ok(())
+}
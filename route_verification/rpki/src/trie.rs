@@ -0,0 +1,82 @@
+use super::*;
+
+/// Binary trie keyed by the bits of IP prefixes of one address family.
+#[derive(Clone, Debug)]
+pub struct PrefixTrie<T> {
+    root: Node<T>,
+    len: usize,
+}
+
+#[derive(Clone, Debug)]
+struct Node<T> {
+    children: [Option<Box<Node<T>>>; 2],
+    values: Vec<T>,
+}
+
+impl<T> Default for Node<T> {
+    fn default() -> Self {
+        Self {
+            children: [None, None],
+            values: Vec::new(),
+        }
+    }
+}
+
+impl<T> Default for PrefixTrie<T> {
+    fn default() -> Self {
+        Self {
+            root: Node::default(),
+            len: 0,
+        }
+    }
+}
+
+impl<T> PrefixTrie<T> {
+    /// Add `value` at `prefix`, alongside any values already there.
+    pub fn insert(&mut self, prefix: &IpNet, value: T) {
+        let bits = prefix_bits(prefix);
+        let mut node = &mut self.root;
+        for index in 0..prefix.prefix_len() {
+            node = node.children[bit(bits, index)].get_or_insert_with(Default::default);
+        }
+        node.values.push(value);
+        self.len += 1;
+    }
+
+    /// Values at `prefix` and at all prefixes covering it,
+    /// least specific first.
+    pub fn covering(&self, prefix: &IpNet) -> Vec<&T> {
+        let bits = prefix_bits(prefix);
+        let mut node = &self.root;
+        let mut values: Vec<_> = node.values.iter().collect();
+        for index in 0..prefix.prefix_len() {
+            node = match &node.children[bit(bits, index)] {
+                Some(child) => child,
+                None => break,
+            };
+            values.extend(&node.values);
+        }
+        values
+    }
+
+    /// Number of values.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// Bits of the network address of `prefix`, aligned to the left.
+fn prefix_bits(prefix: &IpNet) -> u128 {
+    match prefix {
+        IpNet::V4(prefix) => (u32::from(prefix.network()) as u128) << 96,
+        IpNet::V6(prefix) => u128::from(prefix.network()),
+    }
+}
+
+fn bit(bits: u128, index: u8) -> usize {
+    ((bits >> (127 - index)) & 1) as usize
+}
@@ -2,4 +2,10 @@ use serde::{Deserialize, Serialize};
 
 pub mod action;
 pub mod community;
+pub mod span;
 pub mod stats;
+
+pub use span::SourceSpan;
+
+/// AS number used in place of 4-byte AS numbers by 2-byte BGP speakers.
+pub const AS_TRANS: u32 = 23456;
@@ -0,0 +1,29 @@
+use super::*;
+
+/// Where an attribute is in the `body` of an RPSL object.
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
+)]
+pub struct SourceSpan {
+    /// Index of the attribute among the attributes in the body,
+    /// i.e., the continued lines with comments and empty lines removed.
+    pub attr: u32,
+    /// 0-based index of the first line of the attribute in the body.
+    pub start_line: u32,
+    /// 0-based index of the last line of the attribute in the body,
+    /// including continuation lines.
+    pub end_line: u32,
+}
+
+impl SourceSpan {
+    /// The raw lines of the attribute in `body`, joined by `\n`.
+    pub fn quote(&self, body: &str) -> String {
+        let n_lines = (self.end_line - self.start_line) as usize + 1;
+        let lines: Vec<_> = body
+            .lines()
+            .skip(self.start_line as usize)
+            .take(n_lines)
+            .collect();
+        lines.join("\n")
+    }
+}
@@ -17,6 +17,10 @@ pub struct Counts {
     pub parse_filter_set: usize,
     /// Invalid routes when parsing AS Routes.
     pub parse_as_route: usize,
+    /// Invalid local AS when parsing Inet Rtr.
+    pub parse_inet_rtr: usize,
+    /// Invalid name when parsing Rtr Set.
+    pub parse_rtr_set: usize,
 }
 
 impl std::ops::Add for Counts {
@@ -34,6 +38,8 @@ impl std::ops::Add for Counts {
             parse_peering_set: self.parse_peering_set + rhs.parse_peering_set,
             parse_filter_set: self.parse_filter_set + rhs.parse_filter_set,
             parse_as_route: self.parse_as_route + rhs.parse_as_route,
+            parse_inet_rtr: self.parse_inet_rtr + rhs.parse_inet_rtr,
+            parse_rtr_set: self.parse_rtr_set + rhs.parse_rtr_set,
         }
     }
 }
@@ -51,6 +57,8 @@ impl std::fmt::Display for Counts {
             parse_peering_set,
             parse_filter_set,
             parse_as_route,
+            parse_inet_rtr,
+            parse_rtr_set,
         } = self;
         [
             (lex_skip, "skips during lexing"),
@@ -63,6 +71,8 @@ impl std::fmt::Display for Counts {
             (parse_peering_set, "invalid Peering Set names"),
             (parse_filter_set, "invalid Filter Set names"),
             (parse_as_route, "invalid AS Route"),
+            (parse_inet_rtr, "invalid Inet Rtr local AS"),
+            (parse_rtr_set, "invalid Rtr Set names"),
         ]
         .into_iter()
         .filter(|(field, _)| **field > 0)
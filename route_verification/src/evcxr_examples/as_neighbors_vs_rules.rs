@@ -22,7 +22,7 @@ fn as_neighbors_vs_rules(query: QueryIr, db: AsRelDb) {
     }
 
     let map: DashMap<u32, NeighborRuleStats> = DashMap::new();
-    db.source2dest
+    db.source2dest()
         .par_iter()
         .for_each(|((as1, as2), relationship)| {
             let (provider, customer) = match relationship {
@@ -33,6 +33,7 @@ fn as_neighbors_vs_rules(query: QueryIr, db: AsRelDb) {
                 }
                 Relationship::P2C => (as1, as2),
                 Relationship::C2P => (as2, as1),
+                Relationship::S2S => return,
             };
             map.entry(*provider)
                 .or_insert(init_neighbor_stats())
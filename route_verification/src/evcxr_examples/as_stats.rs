@@ -33,6 +33,7 @@ fn gen_as_pair_stats(query: QueryIr, mut bgp_lines: Vec<Line>, db: AsRelDb) {
             Some(Relationship::P2C) => b"down",
             Some(Relationship::P2P) => b"peer",
             Some(Relationship::C2P) => b"up",
+            Some(Relationship::S2S) => b"sibling",
             None => b"other",
         });
         file.write_all(b"\n");
@@ -59,25 +60,26 @@ fn gen_up_down_hill_stats(query: QueryIr, mut bgp_lines: Vec<Line>, db: AsRelDb)
         Series::new(
             "quality",
             vec![
-                "ok", "ok", "ok", "ok", "ok", "ok", "ok", "ok", "skip", "skip", "skip", "skip",
-                "skip", "skip", "skip", "skip", "bad", "bad", "bad", "bad", "bad", "bad", "bad",
-                "bad",
+                "ok", "ok", "ok", "ok", "ok", "ok", "ok", "ok", "ok", "ok", "skip", "skip", "skip",
+                "skip", "skip", "skip", "skip", "skip", "skip", "skip", "bad", "bad", "bad", "bad",
+                "bad", "bad", "bad", "bad", "bad", "bad",
             ],
         ),
         Series::new(
             "hill",
             vec![
-                "up", "down", "peer", "other", "up", "down", "peer", "other", "up", "down", "peer",
-                "other", "up", "down", "peer", "other", "up", "down", "peer", "other", "up",
-                "down", "peer", "other",
+                "up", "down", "peer", "sibling", "other", "up", "down", "peer", "sibling", "other",
+                "up", "down", "peer", "sibling", "other", "up", "down", "peer", "sibling", "other",
+                "up", "down", "peer", "sibling", "other", "up", "down", "peer", "sibling", "other",
             ],
         ),
         Series::new(
             "port",
             vec![
-                "import", "import", "import", "import", "export", "export", "export", "export",
-                "import", "import", "import", "import", "export", "export", "export", "export",
-                "import", "import", "import", "import", "export", "export", "export", "export",
+                "import", "import", "import", "import", "import", "export", "export", "export",
+                "export", "export", "import", "import", "import", "import", "import", "export",
+                "export", "export", "export", "export", "import", "import", "import", "import",
+                "import", "export", "export", "export", "export", "export",
             ],
         ),
         Series::new(
@@ -86,26 +88,32 @@ fn gen_up_down_hill_stats(query: QueryIr, mut bgp_lines: Vec<Line>, db: AsRelDb)
                 up_down_hill_stats.ok_up_import,
                 up_down_hill_stats.ok_down_import,
                 up_down_hill_stats.ok_peer_import,
+                up_down_hill_stats.ok_sibling_import,
                 up_down_hill_stats.ok_other_import,
                 up_down_hill_stats.ok_up_export,
                 up_down_hill_stats.ok_down_export,
                 up_down_hill_stats.ok_peer_export,
+                up_down_hill_stats.ok_sibling_export,
                 up_down_hill_stats.ok_other_export,
                 up_down_hill_stats.skip_up_import,
                 up_down_hill_stats.skip_down_import,
                 up_down_hill_stats.skip_peer_import,
+                up_down_hill_stats.skip_sibling_import,
                 up_down_hill_stats.skip_other_import,
                 up_down_hill_stats.skip_up_export,
                 up_down_hill_stats.skip_down_export,
                 up_down_hill_stats.skip_peer_export,
+                up_down_hill_stats.skip_sibling_export,
                 up_down_hill_stats.skip_other_export,
                 up_down_hill_stats.bad_up_import,
                 up_down_hill_stats.bad_down_import,
                 up_down_hill_stats.bad_peer_import,
+                up_down_hill_stats.bad_sibling_import,
                 up_down_hill_stats.bad_other_import,
                 up_down_hill_stats.bad_up_export,
                 up_down_hill_stats.bad_down_export,
                 up_down_hill_stats.bad_peer_export,
+                up_down_hill_stats.bad_sibling_export,
                 up_down_hill_stats.bad_other_export,
             ],
         ),
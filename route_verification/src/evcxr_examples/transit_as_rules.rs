@@ -8,11 +8,11 @@ use super::*;
 /// ```
 fn transit_as_rules(query: &QueryIr, db: &AsRelDb) {
     let mut transit_ases: Vec<u32> = db
-        .source2dest
+        .source2dest()
         .iter()
         .filter_map(|((from, to), relationship)| match relationship {
             Relationship::P2C => Some(*from),
-            Relationship::P2P => None,
+            Relationship::P2P | Relationship::S2S => None,
             Relationship::C2P => Some(*to),
         })
         .collect();
@@ -155,7 +155,7 @@ fn transit_as_rules(query: &QueryIr, db: &AsRelDb) {
                     Some(Relationship::C2P) => import_provider += 1,
                     Some(Relationship::P2P) => import_peer += 1,
                     Some(Relationship::P2C) => import_customer += 1,
-                    None => import_other += 1,
+                    Some(Relationship::S2S) | None => import_other += 1,
                 }
             }
 
@@ -165,7 +165,7 @@ fn transit_as_rules(query: &QueryIr, db: &AsRelDb) {
                         Some(Relationship::C2P) => import_both_provider += 1,
                         Some(Relationship::P2P) => import_both_peer += 1,
                         Some(Relationship::P2C) => import_both_customer += 1,
-                        None => import_peering_other += 1,
+                        Some(Relationship::S2S) | None => import_peering_other += 1,
                     }
                 }
             }
@@ -175,7 +175,7 @@ fn transit_as_rules(query: &QueryIr, db: &AsRelDb) {
                     Some(Relationship::C2P) => import_peering_provider += 1,
                     Some(Relationship::P2P) => import_peering_peer += 1,
                     Some(Relationship::P2C) => import_peering_customer += 1,
-                    None => import_peering_other += 1,
+                    Some(Relationship::S2S) | None => import_peering_other += 1,
                 }
             }
 
@@ -184,7 +184,7 @@ fn transit_as_rules(query: &QueryIr, db: &AsRelDb) {
                     Some(Relationship::C2P) => import_filter_provider += 1,
                     Some(Relationship::P2P) => import_filter_peer += 1,
                     Some(Relationship::P2C) => import_filter_customer += 1,
-                    None => import_filter_other += 1,
+                    Some(Relationship::S2S) | None => import_filter_other += 1,
                 }
             }
 
@@ -240,7 +240,7 @@ fn transit_as_rules(query: &QueryIr, db: &AsRelDb) {
                     Some(Relationship::C2P) => export_provider += 1,
                     Some(Relationship::P2P) => export_peer += 1,
                     Some(Relationship::P2C) => export_customer += 1,
-                    None => export_other += 1,
+                    Some(Relationship::S2S) | None => export_other += 1,
                 }
             }
 
@@ -252,7 +252,7 @@ fn transit_as_rules(query: &QueryIr, db: &AsRelDb) {
                     Some(Relationship::C2P) => export_peering_provider += 1,
                     Some(Relationship::P2P) => export_peering_peer += 1,
                     Some(Relationship::P2C) => export_peering_customer += 1,
-                    None => export_peering_other += 1,
+                    Some(Relationship::S2S) | None => export_peering_other += 1,
                 }
             }
 
@@ -264,7 +264,7 @@ fn transit_as_rules(query: &QueryIr, db: &AsRelDb) {
                     Some(Relationship::C2P) => export_filter_provider += 1,
                     Some(Relationship::P2P) => export_filter_peer += 1,
                     Some(Relationship::P2C) => export_filter_customer += 1,
-                    None => export_filter_other += 1,
+                    Some(Relationship::S2S) | None => export_filter_other += 1,
                 }
             }
 
@@ -184,6 +184,59 @@ pub fn report(parsed_dir: &str, mrt_dir: &str) -> Result<()> {
     Ok(())
 }
 
+/// Print the routes in MRT file `mrt_file` whose verdicts change from
+/// the IR in `old_dir` to that in `new_dir`, and the changes per AS.
+pub fn impact(old_dir: &str, new_dir: &str, mrt_file: &str) -> Result<()> {
+    let old = Ir::pal_read(old_dir)?;
+    debug!("impact: Parsed old {old}.");
+    let new = Ir::pal_read(new_dir)?;
+    debug!("impact: Parsed new {new}.");
+
+    let routes: Vec<_> = parse_mrt(mrt_file)?
+        .into_iter()
+        .map(|line| line.compare)
+        .collect();
+    debug!("Read {} routes from {mrt_file}", routes.len());
+
+    let impact = Impact::analyze(old, new, &routes);
+    println!(
+        "{} aut-nums and {} other objects changed, affecting {} aut-nums.",
+        impact.diff.aut_nums.len(),
+        impact.diff.objects.len(),
+        impact.affected_aut_nums.len()
+    );
+    println!(
+        "Re-verified {} of {} routes; {} changed verdicts.",
+        impact.n_checked,
+        routes.len(),
+        impact.routes.len()
+    );
+    for route in &impact.routes {
+        println!("{} {:?}", route.prefix, route.as_path);
+        for Transition {
+            from,
+            to,
+            export,
+            old,
+            new,
+        } in &route.transitions
+        {
+            match export {
+                true => println!("\texport AS{from} -> AS{to}: {old:?} -> {new:?}"),
+                false => println!("\timport AS{to} <- AS{from}: {old:?} -> {new:?}"),
+            }
+        }
+    }
+    for (aut_num, counts) in &impact.per_as {
+        println!("AS{aut_num}");
+        for ((old, new), count) in counts {
+            println!("\t{old:?} -> {new:?}: {count}");
+        }
+    }
+
+    Ok(())
+}
+
 pub fn pack_n_lines(mut output_child: OutputChild, limit: usize) -> Result<Vec<Line>> {
     let mut lines = Vec::new();
     let mut line = String::new();
@@ -88,5 +88,20 @@ pub fn report(args: Vec<String>) -> Result<()> {
     fs::report(parsed_dir, mrt_dir)
 }
 
+pub fn impact(args: Vec<String>) -> Result<()> {
+    if args.len() < 5 {
+        bail!("Specify directories to read the old and new parsed IR from and a MRT file to read from!");
+    }
+
+    let old_dir = &args[2];
+    let new_dir = &args[3];
+    debug!("Will read parsed IR from {old_dir} and {new_dir}.");
+
+    let mrt_file = &args[4];
+    debug!("Will read MRT file from {mrt_file}.");
+
+    fs::impact(old_dir, new_dir, mrt_file)
+}
+
 #[cfg(test)]
 mod evcxr_examples;
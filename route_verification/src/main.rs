@@ -17,6 +17,7 @@ fn main() -> Result<()> {
         "parse_ordered" => parse_ordered(args),
         "read" => read(args),
         "report" => report(args),
+        "impact" => impact(args),
         other => bail!("Unknown command {other}!"),
     }
 }
@@ -8,11 +8,14 @@ from rpsl_lexer.rpsl_object import AutNum
 
 
 def parse_mp_import(
-    expr: str, imports: dict[str, dict[str, list[dict]]], is_mp: bool = False
+    expr: str,
+    imports: dict[str, dict[str, list[dict]]],
+    attr: int,
+    is_mp: bool = False,
 ):
     try:
         lexed = lex_with(mp_import, expr)
-        import_export(lexed, imports, is_mp)
+        import_export(lexed, imports, is_mp, attr)
     except Exception as err:
         print(f"{err} parsing `{expr}`.")
 
@@ -21,18 +24,19 @@ def parse_aut_num():
     n_import, n_export = 0, 0
     imports: dict[str, dict[str, list[dict]]] = {}
     exports: dict[str, dict[str, list[dict]]] = {}
-    for key, expr in expressions(stdin_lines()):
+    # `attr` indexes the attributes in the order the Rust side sends them.
+    for attr, (key, expr) in enumerate(expressions(stdin_lines())):
         if key == "import":
-            parse_mp_import(expr, imports)
+            parse_mp_import(expr, imports, attr)
             n_import += 1
         elif key == "mp-import":
-            parse_mp_import(expr, imports, is_mp=True)
+            parse_mp_import(expr, imports, attr, is_mp=True)
             n_import += 1
         elif key == "export":
-            parse_mp_import(expr, exports)
+            parse_mp_import(expr, exports, attr)
             n_export += 1
         elif key == "mp-export":
-            parse_mp_import(expr, exports, is_mp=True)
+            parse_mp_import(expr, exports, attr, is_mp=True)
             n_export += 1
     return AutNum("", "", n_import, n_export, imports, exports).__dict__
 
@@ -390,8 +390,14 @@ def parse_afi_import_expression(
     return []
 
 
-def import_export(lexed: dict, result: dict[str, dict[str, list]], is_mp: bool = False):
-    """Parse lexed <mp-import> or <mp-export>."""
+def import_export(
+    lexed: dict,
+    result: dict[str, dict[str, list]],
+    is_mp: bool = False,
+    attr: int | None = None,
+):
+    """Parse lexed <mp-import> or <mp-export>.
+    Tag the entries with `attr`, the index of the attribute, if given."""
     if is_mp:
         if protocol_1 := lexed.get("protocol-1"):
             print(f"Ignore: Ignoring protocol-1: {protocol_1}.")
@@ -401,6 +407,9 @@ def import_export(lexed: dict, result: dict[str, dict[str, list]], is_mp: bool =
     afi_entries = set([("any", "any") if is_mp else ("ipv4", "unicast")])
     parsed_list = parse_afi_import_expression(lexed, afi_entries)
     for afi_entries, parsed in parsed_list:
+        if attr is not None:
+            for entry in parsed:
+                entry["attr"] = attr
         for version, cast in afi_entries:
             version_entry = result.get(version, {})
             cast_entry = version_entry.get(cast, [])